@@ -0,0 +1,68 @@
+//! Compare the multiplication and division strategies around their crossover points to pick
+//! the [`tuning`] defaults for a coefficient type
+//!
+//! `cargo bench --bench thresholds`
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_polynomial::{tuning, Polynomial};
+
+fn dense_poly(len: usize, seed: i64) -> Polynomial<i64> {
+    let mut state = seed;
+    let coeffs: Vec<i64> = (0..len)
+        .map(|_| {
+            state = (state * 1103515245 + 12345) % 2147483648;
+            state % 2001 - 1000
+        })
+        .collect();
+
+    Polynomial::from(coeffs)
+}
+
+fn mul_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+
+    for len in [8, 16, 24, 32, 48, 64, 96, 128, 256] {
+        let p1 = dense_poly(len, 1);
+        let p2 = dense_poly(len, 2);
+
+        #[rustfmt::skip]
+        let strategies = [
+            ("naive", usize::MAX, usize::MAX),
+            ("toom3", 0, usize::MAX),
+            ("kronecker", usize::MAX, 0),
+        ];
+
+        for (name, toom3, kronecker) in strategies {
+            group.bench_with_input(BenchmarkId::new(name, len), &len, |b, _| {
+                tuning::set_toom3_threshold(toom3);
+                tuning::set_kronecker_threshold(kronecker);
+                b.iter(|| p1.clone() * p2.clone());
+            });
+        }
+    }
+
+    tuning::reset();
+    group.finish();
+}
+
+fn div_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("div");
+    let divider = Polynomial::<i64>::try_from("x^8 - 3x^5 + x^2 - 1").unwrap();
+
+    for len in [16, 32, 64, 128, 256] {
+        let dividend = dense_poly(len, 3);
+
+        for (name, newton) in [("classical", usize::MAX), ("newton", 0)] {
+            group.bench_with_input(BenchmarkId::new(name, len), &len, |b, _| {
+                tuning::set_newton_division_threshold(newton);
+                b.iter(|| dividend.clone() / divider.clone());
+            });
+        }
+    }
+
+    tuning::reset();
+    group.finish();
+}
+
+criterion_group!(benches, mul_strategies, div_strategies);
+criterion_main!(benches);
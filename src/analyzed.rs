@@ -0,0 +1,108 @@
+use std::cell::OnceCell;
+
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Caching wrapper around a [`Polynomial`]
+///
+/// Derivative, roots, extrema and factorization are computed lazily the first time they are
+/// requested and kept until the polynomial is mutated through the wrapper.
+#[derive(Debug, Clone)]
+pub struct AnalyzedPolynomial<T> {
+    poly: Polynomial<T>,
+    derivative: OnceCell<Polynomial<T>>,
+    roots: OnceCell<Option<Vec<T>>>,
+    extrema: OnceCell<Option<Vec<(T, T)>>>,
+    factors: OnceCell<Vec<Polynomial<T>>>,
+}
+
+impl<T: MonomialValue> AnalyzedPolynomial<T> {
+    /// Constructs a new `AnalyzedPolynomial<T>` with empty caches
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{AnalyzedPolynomial, Polynomial};
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 4").unwrap();
+    /// let analyzed = AnalyzedPolynomial::new(poly);
+    ///
+    /// assert_eq!(analyzed.roots(), Some(&vec![-2, 2]));
+    /// ```
+    pub fn new(poly: Polynomial<T>) -> AnalyzedPolynomial<T> {
+        AnalyzedPolynomial {
+            poly,
+            derivative: OnceCell::new(),
+            roots: OnceCell::new(),
+            extrema: OnceCell::new(),
+            factors: OnceCell::new(),
+        }
+    }
+
+    /// Returns the wrapped polynomial
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.poly
+    }
+
+    /// Consumes the wrapper returning the polynomial
+    pub fn into_inner(self) -> Polynomial<T> {
+        self.poly
+    }
+
+    /// Cached [`Polynomial::derivative`]
+    pub fn derivative(&self) -> &Polynomial<T> {
+        self.derivative.get_or_init(|| self.poly.derivative())
+    }
+
+    /// Cached [`Polynomial::roots`]
+    pub fn roots(&self) -> Option<&Vec<T>> {
+        self.roots.get_or_init(|| self.poly.roots()).as_ref()
+    }
+
+    /// Cached [`Polynomial::extrema`], reusing the cached derivative
+    pub fn extrema(&self) -> Option<&Vec<(T, T)>> {
+        self.extrema
+            .get_or_init(|| {
+                let roots = self.derivative().roots()?;
+                Some(roots.into_iter().map(|x| (x, self.poly.eval(x))).collect())
+            })
+            .as_ref()
+    }
+
+    /// Cached [`Polynomial::factors`]
+    pub fn factors(&self) -> &[Polynomial<T>] {
+        self.factors.get_or_init(|| self.poly.factors())
+    }
+
+    /// Add a monomial and invalidate the caches
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{AnalyzedPolynomial, Monomial, Polynomial};
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2").unwrap();
+    /// let mut analyzed = AnalyzedPolynomial::new(poly);
+    /// assert_eq!(analyzed.roots(), Some(&vec![0]));
+    ///
+    /// analyzed.push(Monomial::new(-9, 0));
+    ///
+    /// assert_eq!(analyzed.roots(), Some(&vec![-3, 3]));
+    /// ```
+    pub fn push(&mut self, mono: Monomial<T>) {
+        self.update(|poly| poly.push(mono));
+    }
+
+    /// Mutate the wrapped polynomial and invalidate the caches
+    pub fn update<F: FnOnce(&mut Polynomial<T>)>(&mut self, f: F) {
+        f(&mut self.poly);
+        self.invalidate();
+    }
+
+    /// Drop every cached result
+    fn invalidate(&mut self) {
+        self.derivative.take();
+        self.roots.take();
+        self.extrema.take();
+        self.factors.take();
+    }
+}
+
+impl<T: MonomialValue> From<Polynomial<T>> for AnalyzedPolynomial<T> {
+    fn from(value: Polynomial<T>) -> Self {
+        AnalyzedPolynomial::new(value)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{division::DivError, mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Pool of term buffers for the temporary polynomials of long computations. Polynomials
+/// given back with [`recycle`](PolyArena::recycle) keep their allocation, and the `_in`
+/// methods like [`Polynomial::div_in`] build their intermediate results in them instead of
+/// allocating new ones. The usual methods don't use it
+/// # Examples
+/// ```
+/// # use rust_polynomial::{PolyArena, Polynomial};
+/// let mut arena = PolyArena::new();
+/// let p: Polynomial<i32> = Polynomial::try_from("x^3 - 1").unwrap();
+/// let q: Polynomial<i32> = Polynomial::try_from("x - 1").unwrap();
+///
+/// let (quotient, remainder) = p.div_in(&q, &mut arena).unwrap();
+/// assert_eq!(format!("{quotient}"), "x^2 + x + 1");
+///
+/// // The intermediate remainders are kept for the next computation
+/// arena.recycle(remainder);
+/// assert!(arena.available() > 0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PolyArena<T> {
+    buffers: Vec<Vec<Monomial<T>>>,
+}
+
+impl<T: MonomialValue> PolyArena<T> {
+    /// Constructs an empty `PolyArena<T>`
+    pub fn new() -> PolyArena<T> {
+        PolyArena {
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Keeps the allocation of a polynomial that is no longer needed
+    pub fn recycle(&mut self, poly: Polynomial<T>) {
+        self.give_back(poly.into_mono_vec());
+    }
+
+    /// Returns the number of buffers ready to be reused
+    pub fn available(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// An empty buffer, reused if there is any
+    fn take(&mut self) -> Vec<Monomial<T>> {
+        self.buffers.pop().unwrap_or_default()
+    }
+
+    fn give_back(&mut self, mut buffer: Vec<Monomial<T>>) {
+        if buffer.capacity() > 0 {
+            buffer.clear();
+            self.buffers.push(buffer);
+        }
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Same as [`checked_div`](Polynomial::checked_div) by long division, with every
+    /// intermediate remainder built in a buffer of the arena and given back to it
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyArena, Polynomial};
+    /// let mut arena = PolyArena::new();
+    /// let p: Polynomial<i32> = Polynomial::try_from("x^4 + 3x - 2").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^2 + 1").unwrap();
+    ///
+    /// assert_eq!(p.div_in(&q, &mut arena), p.checked_div(&q));
+    /// ```
+    pub fn div_in(&self, rhs: &Self, arena: &mut PolyArena<T>) -> Result<(Self, Self), DivError> {
+        if rhs.is_zero() {
+            return Err(DivError::DivisionByZero);
+        }
+        if !self.quotient_fits(rhs) {
+            return Err(DivError::ExponentOverflow);
+        }
+
+        let lead = rhs.max_exp();
+        let mut remainder = arena.take();
+        remainder.extend(self);
+        let mut quotient = arena.take();
+
+        while remainder
+            .first()
+            .is_some_and(|m| m.get_exp() >= lead.get_exp())
+        {
+            let term = remainder[0] / lead;
+
+            // Not divisible in `T`, like `x` by `2x` with integer coefficients
+            if term.get_value().is_zero() {
+                break;
+            }
+
+            // Terms of a float quotient can repeat an exponent when the leading terms don't
+            // cancel exactly
+            match quotient.last_mut() {
+                Some(last) if last.get_exp() == term.get_exp() => {
+                    *last = Monomial::new(last.get_value() + term.get_value(), term.get_exp());
+                    if last.get_value().is_zero() {
+                        quotient.pop();
+                    }
+                }
+                _ => quotient.push(term),
+            }
+
+            let mut next = arena.take();
+            subtract_product(&remainder, rhs, term, &mut next);
+            arena.give_back(std::mem::replace(&mut remainder, next));
+        }
+
+        let var = self.get_var();
+        Ok((
+            Polynomial::from_sorted_monomials_unchecked(quotient).with_var(var),
+            Polynomial::from_sorted_monomials_unchecked(remainder).with_var(var),
+        ))
+    }
+
+    /// Same as [`euclidean_chain`](Polynomial::euclidean_chain) dividing with
+    /// [`div_in`](Polynomial::div_in), the remainders are recycled in the arena
+    pub fn euclidean_chain_in(&self, other: &Self, arena: &mut PolyArena<T>) -> Vec<Self> {
+        if self.into_iter().chain(other).any(|m| m.get_exp() < 0) {
+            return Vec::new();
+        }
+
+        let mut chain = Vec::new();
+        let (mut previous, mut current) = (self.clone(), other.clone());
+        while !current.is_zero() {
+            let Ok((quotient, remainder)) = previous.div_in(&current, arena) else {
+                break;
+            };
+            if !remainder.is_zero() && remainder.max_exp().get_exp() >= current.max_exp().get_exp()
+            {
+                break;
+            }
+
+            chain.push(quotient);
+            arena.recycle(std::mem::replace(&mut previous, current));
+            current = remainder;
+        }
+        arena.recycle(previous);
+        arena.recycle(current);
+
+        chain
+    }
+}
+
+/// Writes `lhs - term * rhs` in `out` merging both sorted lists of terms
+fn subtract_product<T: MonomialValue>(
+    lhs: &[Monomial<T>],
+    rhs: &Polynomial<T>,
+    term: Monomial<T>,
+    out: &mut Vec<Monomial<T>>,
+) {
+    let mut lhs = lhs.iter().copied().peekable();
+    let mut product = rhs.into_iter().map(|m| *m * -term).peekable();
+
+    loop {
+        let mono = match (lhs.peek().copied(), product.peek().copied()) {
+            (None, None) => break,
+            (Some(a), Some(b)) if a.get_exp() == b.get_exp() => {
+                let sum = Monomial::new(a.get_value() + b.get_value(), a.get_exp());
+                lhs.next();
+                product.next();
+                sum
+            }
+            (Some(a), Some(b)) if a.get_exp() < b.get_exp() => {
+                product.next();
+                b
+            }
+            (None, Some(b)) => {
+                product.next();
+                b
+            }
+            (Some(a), _) => {
+                lhs.next();
+                a
+            }
+        };
+
+        if !mono.get_value().is_zero() {
+            out.push(mono);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! [`ndarray`] interop, enabled with the `ndarray` feature
+
+use ndarray::{Array1, ArrayView1};
+
+use crate::{poly::Polynomial, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the coefficients in ascending order as an [`Array1`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// # use ndarray::array;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("2x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(poly.to_array1(), Ok(array![-1.0, 0.0, 2.0]));
+    /// ```
+    pub fn to_array1(&self) -> Result<Array1<T>, &'static str> {
+        Ok(Array1::from_vec(self.to_coeffs()?))
+    }
+
+    /// Constructs a polynomial from its coefficients in ascending order
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// # use ndarray::array;
+    /// let poly: Polynomial<f64> = Polynomial::from_array1(array![-1.0, 0.0, 2.0].view());
+    ///
+    /// assert_eq!(format!("{poly}"), "2x^2 - 1");
+    /// ```
+    pub fn from_array1(coeffs: ArrayView1<T>) -> Self {
+        match coeffs.as_slice() {
+            Some(slice) => Polynomial::from_coeffs(slice),
+            None => Polynomial::from_coeffs(&coeffs.to_vec()),
+        }
+    }
+
+    /// Evaluates the polynomial at every point of `xs` using [`f64`] arithmetic
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// # use ndarray::array;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(poly.eval_array1(array![0.0, 0.5, 2.0].view()), array![-1.0, -0.75, 3.0]);
+    /// ```
+    pub fn eval_array1(&self, xs: ArrayView1<f64>) -> Array1<f64> {
+        let terms: Vec<(f64, i32)> = self
+            .into_iter()
+            .map(|m| (m.get_value().to_f64().unwrap_or(f64::NAN), m.get_exp()))
+            .collect();
+
+        xs.mapv(|x| terms.iter().map(|(value, exp)| value * x.powi(*exp)).sum())
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    bezier::Bezier, economize::chebyshev_polynomials, poly::Polynomial, resultant::bareiss,
+    MonomialValue,
+};
+
+/// Basis of the polynomials of degree up to `n`, `n + 1` linearly independent polynomials of
+/// degree at most `n`. Polynomials are expressed in it with [`Polynomial::coordinates_in`]
+/// and built back with [`PolyBasis::from_coordinates`]
+#[derive(Debug, Clone)]
+pub struct PolyBasis<T> {
+    basis: Vec<Polynomial<T>>,
+}
+
+impl<T: MonomialValue> PolyBasis<T> {
+    /// Constructs a new `PolyBasis<T>` from its polynomials
+    ///
+    /// Returns an error if there isn't any polynomial, there is any negative exponent, a
+    /// degree isn't less than the number of polynomials or they are linearly dependent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyBasis, Polynomial};
+    /// let basis: Vec<Polynomial<i32>> = ["1", "x + 1", "x^2 + 2x + 1"]
+    ///     .iter()
+    ///     .map(|p| Polynomial::try_from(*p).unwrap())
+    ///     .collect();
+    ///
+    /// assert!(PolyBasis::new(basis.clone()).is_ok());
+    /// assert!(PolyBasis::new(basis[1..].to_vec()).is_err());
+    /// ```
+    pub fn new(basis: Vec<Polynomial<T>>) -> Result<PolyBasis<T>, &'static str> {
+        if basis.is_empty() {
+            return Err("The basis can't be empty");
+        }
+
+        for poly in &basis {
+            if poly.to_coeffs()?.len() > basis.len() {
+                return Err("The basis degrees must be less than its size");
+            }
+        }
+
+        if !Polynomial::are_linearly_independent(&basis) {
+            return Err("The basis must be linearly independent");
+        }
+
+        Ok(PolyBasis { basis })
+    }
+
+    /// Constructs the monomial basis `1, x, ..., x^n`
+    pub fn monomial(n: usize) -> PolyBasis<T> {
+        let basis = (0..=n as i32)
+            .map(|exp| Polynomial::from((T::one(), exp)))
+            .collect();
+
+        PolyBasis { basis }
+    }
+
+    /// Constructs the basis of
+    /// [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials)
+    /// `T_0, ..., T_n`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyBasis, Polynomial};
+    /// let poly: Polynomial<f64> = Polynomial::try_from("4x^3 + 2x").unwrap();
+    ///
+    /// // T_3 + 5 T_1
+    /// assert_eq!(poly.coordinates_in(&PolyBasis::chebyshev(3)), Ok(vec![0.0, 5.0, 0.0, 1.0]));
+    /// ```
+    pub fn chebyshev(n: usize) -> PolyBasis<T> {
+        let mut basis = chebyshev_polynomials(n as i32);
+        basis.truncate(n + 1);
+
+        PolyBasis { basis }
+    }
+
+    /// Constructs the [Bernstein basis](https://en.wikipedia.org/wiki/Bernstein_polynomial)
+    /// `C(n, i) x^i (1 - x)^(n - i)`, the coordinates are the control points of a [`Bezier`]
+    pub fn bernstein(n: usize) -> PolyBasis<T> {
+        let basis = (0..=n)
+            .map(|i| {
+                let control = (0..=n)
+                    .map(|j| if i == j { T::one() } else { T::zero() })
+                    .collect();
+                Bezier::new(control).to_polynomial()
+            })
+            .collect();
+
+        PolyBasis { basis }
+    }
+
+    /// Constructs the basis of
+    /// [falling factorials](https://en.wikipedia.org/wiki/Falling_and_rising_factorials)
+    /// `x (x - 1) ... (x - i + 1)`, the coordinates are the forward differences at zero
+    /// divided by `i!`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyBasis, Polynomial};
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2").unwrap();
+    ///
+    /// // x^2 = x (x - 1) + x
+    /// assert_eq!(poly.coordinates_in(&PolyBasis::falling_factorial(2)), Ok(vec![0, 1, 1]));
+    /// ```
+    pub fn falling_factorial(n: usize) -> PolyBasis<T> {
+        let mut basis = vec![Polynomial::constant(T::one())];
+        for i in 0..n {
+            let root = T::from(i).expect("basis size out of range");
+            let factor = Polynomial::from((T::one(), 1)) + Polynomial::constant(-root);
+            basis.push(basis[i].clone() * factor);
+        }
+
+        PolyBasis { basis }
+    }
+
+    pub fn basis(&self) -> &[Polynomial<T>] {
+        &self.basis
+    }
+
+    /// Returns the number of polynomials, one more than the maximum degree
+    pub fn dimension(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Returns `Σ c_i b_i`, the polynomial with the given coordinates
+    ///
+    /// Returns an error if there isn't one coordinate per polynomial of the basis
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::PolyBasis;
+    /// let basis: PolyBasis<i32> = PolyBasis::chebyshev(2);
+    ///
+    /// assert_eq!(format!("{}", basis.from_coordinates(&[1, 0, 3]).unwrap()), "6x^2 - 2");
+    /// ```
+    pub fn from_coordinates(&self, coordinates: &[T]) -> Result<Polynomial<T>, &'static str> {
+        if coordinates.len() != self.basis.len() {
+            return Err("Expected one coordinate per basis polynomial");
+        }
+
+        Ok(self
+            .basis
+            .iter()
+            .zip(coordinates)
+            .fold(Polynomial::zero(), |acc, (poly, c)| {
+                acc + poly.clone() * Polynomial::constant(*c)
+            }))
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the coordinates `c` with `self = Σ c_i b_i` in the basis, the inverse of
+    /// [`PolyBasis::from_coordinates`]. They are solved by fraction-free Bareiss elimination
+    /// with partial pivoting, so integer coefficients give the exact coordinates when they are
+    /// integers
+    ///
+    /// Returns an error if the degree is greater than the basis degree, there is any negative
+    /// exponent, or the coefficients are integers and a coordinate isn't
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyBasis, Polynomial};
+    /// let poly: Polynomial<f64> = Polynomial::try_from("-4x^2 + 4x").unwrap();
+    ///
+    /// // The control points of the Bézier curve
+    /// assert_eq!(poly.coordinates_in(&PolyBasis::bernstein(2)), Ok(vec![0.0, 2.0, 0.0]));
+    /// ```
+    pub fn coordinates_in(&self, basis: &PolyBasis<T>) -> Result<Vec<T>, &'static str> {
+        let size = basis.dimension();
+        let mut target = self.to_coeffs()?;
+        if target.len() > size {
+            return Err("The polynomial degree is greater than the basis degree");
+        }
+        target.resize(size, T::zero());
+
+        // Row `i` has the coefficients of `x^i`, with the target as the last column
+        let mut rows: Vec<Vec<T>> = target.into_iter().map(|c| vec![c]).collect();
+        for poly in basis.basis.iter().rev() {
+            let coeffs = poly.to_coeffs()?;
+            for (i, row) in rows.iter_mut().enumerate() {
+                row.insert(0, coeffs.get(i).copied().unwrap_or_else(T::zero));
+            }
+        }
+
+        // The basis is independent, so every pivot is on the diagonal
+        bareiss(&mut rows);
+
+        // Integer division truncates, a remainder means the coordinate isn't an integer
+        let truncates = (T::one() / (T::one() + T::one())).is_zero();
+        let mut coordinates = vec![T::zero(); size];
+        for i in (0..size).rev() {
+            let sum = (i + 1..size).fold(rows[i][size], |acc, j| acc - rows[i][j] * coordinates[j]);
+            if truncates && !(sum % rows[i][i]).is_zero() {
+                return Err("The coordinates aren't integers");
+            }
+            coordinates[i] = sum / rows[i][i];
+        }
+
+        Ok(coordinates)
+    }
+
+    /// Returns `true` if the polynomial maps every integer to an integer, like `x (x - 1) / 2`.
+    /// Its coordinates in the [`falling_factorial`](PolyBasis::falling_factorial) basis times
+    /// `i!`, the forward differences of `p(0), p(1), ..., p(n)`, must all be integers.
+    /// Integer coefficients are always integer valued, and a negative exponent isn't defined
+    /// at zero
+    ///
+    /// Float coefficients are checked exactly, a coefficient like `1/3` that isn't
+    /// representable may fail
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("0.5x^2 - 0.5x").unwrap();
+    ///
+    /// assert!(poly.is_integer_valued());
+    /// assert!(!Polynomial::<f64>::try_from("0.5x^2").unwrap().is_integer_valued());
+    /// ```
+    pub fn is_integer_valued(&self) -> bool {
+        let Ok(coeffs) = self.to_coeffs() else {
+            return false;
+        };
+
+        let mut differences: Vec<T> = (0..coeffs.len())
+            .map(|x| T::from(x).map(|x| self.eval(x)))
+            .collect::<Option<Vec<T>>>()
+            .unwrap_or_default();
+
+        // Every pass leaves the next forward difference at zero in front
+        for k in 0..differences.len() {
+            if !(differences[k] % T::one()).is_zero() {
+                return false;
+            }
+            for i in (k + 1..differences.len()).rev() {
+                differences[i] = differences[i] - differences[i - 1];
+            }
+        }
+
+        true
+    }
+}
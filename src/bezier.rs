@@ -0,0 +1,127 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// One-dimensional [Bézier curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve) given by
+/// its control points, the coefficients in the
+/// [Bernstein basis](https://en.wikipedia.org/wiki/Bernstein_polynomial) of degree
+/// `control.len() - 1`. Every coordinate of a curve is a different `Bezier`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bezier<T> {
+    control: Vec<T>,
+}
+
+impl<T: MonomialValue> Bezier<T> {
+    /// Constructs a new `Bezier<T>` from its control points
+    pub fn new(control: Vec<T>) -> Bezier<T> {
+        Bezier { control }
+    }
+
+    pub fn control_points(&self) -> &[T] {
+        &self.control
+    }
+
+    /// Returns the degree, one less than the number of control points
+    pub fn degree(&self) -> usize {
+        self.control.len().saturating_sub(1)
+    }
+
+    /// Evaluates the curve at `t` by the
+    /// [de Casteljau algorithm](https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm),
+    /// numerically stable for `t` in `[0, 1]`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Bezier;
+    /// let curve: Bezier<f64> = Bezier::new(vec![0.0, 2.0, 0.0]);
+    ///
+    /// assert_eq!(curve.eval(0.5), 1.0);
+    /// ```
+    pub fn eval(&self, t: T) -> T {
+        let mut points = self.control.clone();
+        let rest = T::one() - t;
+
+        for len in (1..points.len()).rev() {
+            for i in 0..len {
+                points[i] = points[i] * rest + points[i + 1] * t;
+            }
+        }
+
+        points.first().copied().unwrap_or_else(T::zero)
+    }
+
+    /// Returns the curve in monomial form, `Σ b_i C(n, i) t^i (1 - t)^(n - i)`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Bezier;
+    /// let curve: Bezier<i32> = Bezier::new(vec![0, 2, 0]);
+    ///
+    /// assert_eq!(format!("{}", curve.to_polynomial()), "-4x^2 + 4x");
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        let n = self.degree();
+        let binomial_n = binomials::<T>(n);
+
+        let coeffs: Vec<T> = (0..self.control.len())
+            .map(|k| {
+                let binomial_k = binomials::<T>(k);
+                let sum = (0..=k).fold(T::zero(), |acc, i| {
+                    let term = binomial_k[i] * self.control[i];
+                    match (k - i) % 2 {
+                        0 => acc + term,
+                        _ => acc - term,
+                    }
+                });
+
+                binomial_n[k] * sum
+            })
+            .collect();
+
+        Polynomial::from_coeffs(&coeffs)
+    }
+
+    /// Returns the control points of degree `degree` describing `poly` on `t`, the inverse of
+    /// [`to_polynomial`](Bezier::to_polynomial). Integer coefficients are truncated by the
+    /// division
+    ///
+    /// Returns an error if `degree` is less than the degree of `poly` or there is any negative
+    /// exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Bezier, Polynomial};
+    /// let poly: Polynomial<f64> = Polynomial::try_from("-4x^2 + 4x").unwrap();
+    ///
+    /// assert_eq!(Bezier::from_polynomial(&poly, 2), Ok(Bezier::new(vec![0.0, 2.0, 0.0])));
+    /// assert!(Bezier::from_polynomial(&poly, 1).is_err());
+    /// ```
+    pub fn from_polynomial(poly: &Polynomial<T>, degree: usize) -> Result<Bezier<T>, &'static str> {
+        let mut coeffs = poly.to_coeffs()?;
+        if coeffs.len() > degree + 1 {
+            return Err("The degree is less than the polynomial degree");
+        }
+        coeffs.resize(degree + 1, T::zero());
+
+        let binomial_n = binomials::<T>(degree);
+        let control = (0..=degree)
+            .map(|i| {
+                let binomial_i = binomials::<T>(i);
+                (0..=i).fold(T::zero(), |acc, k| {
+                    acc + binomial_i[k] * coeffs[k] / binomial_n[k]
+                })
+            })
+            .collect();
+
+        Ok(Bezier { control })
+    }
+}
+
+/// Row `n` of Pascal's triangle
+fn binomials<T: MonomialValue>(n: usize) -> Vec<T> {
+    let mut row = vec![T::one()];
+    for _ in 0..n {
+        let mut next = vec![T::one(); row.len() + 1];
+        for i in 1..row.len() {
+            next[i] = row[i - 1] + row[i];
+        }
+        row = next;
+    }
+
+    row
+}
@@ -0,0 +1,235 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg},
+};
+
+use crate::{matrix::PolyMatrix, multi::MultiMonomial, poly::Polynomial, MonomialValue};
+
+/// Polynomial in two variables written as a polynomial in `x` whose coefficients are
+/// polynomials in `y`, `p(x, y) = Σ qᵢ(y) xⁱ`
+#[derive(Debug, Clone)]
+pub struct Bivariate<T> {
+    coeffs: Vec<Polynomial<T>>,
+}
+
+impl<T: MonomialValue> Bivariate<T> {
+    /// Constructs a new `Bivariate<T>` from the coefficients of `x` in ascending order, they
+    /// are displayed with `y` as variable
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Bivariate, Polynomial};
+    /// // x^2 + y^2 - 1
+    /// let circle: Bivariate<i32> = Bivariate::new(vec![
+    ///     Polynomial::try_from("x^2 - 1").unwrap(),
+    ///     Polynomial::zero(),
+    ///     Polynomial::try_from("1").unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(format!("{circle}"), "(1)x^2 + (y^2 - 1)");
+    /// assert_eq!(circle.eval(3, 4), 24);
+    /// ```
+    pub fn new(coeffs: Vec<Polynomial<T>>) -> Bivariate<T> {
+        let mut coeffs: Vec<Polynomial<T>> = coeffs.into_iter().map(|q| q.with_var('y')).collect();
+        while coeffs.last().is_some_and(Polynomial::is_zero) {
+            coeffs.pop();
+        }
+
+        Bivariate { coeffs }
+    }
+
+    /// Constructs a new `Bivariate<T>` adding monomials of the variables `x` and `y`
+    ///
+    /// Returns an error if there is any other variable or a negative exponent of `x`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Bivariate, MultiMonomial};
+    /// let terms: Vec<MultiMonomial<i32>> = ["3x^2y", "-x", "y^3"]
+    ///     .iter()
+    ///     .map(|t| MultiMonomial::try_from(*t).unwrap())
+    ///     .collect();
+    /// let poly = Bivariate::from_terms(&terms).unwrap();
+    ///
+    /// assert_eq!(format!("{poly}"), "(3y)x^2 + (-1)x + (y^3)");
+    /// ```
+    pub fn from_terms(terms: &[MultiMonomial<T>]) -> Result<Bivariate<T>, &'static str> {
+        let mut coeffs: Vec<Polynomial<T>> = Vec::new();
+        for term in terms {
+            if term.vars().any(|var| var != 'x' && var != 'y') {
+                return Err("Only the variables x and y are allowed");
+            }
+
+            let exp = usize::try_from(term.get_exp('x'))
+                .map_err(|_| "Negative exponents of x can't be represented")?;
+            if coeffs.len() <= exp {
+                coeffs.resize(exp + 1, Polynomial::zero());
+            }
+
+            let q = Polynomial::from((term.get_value(), term.get_exp('y')));
+            coeffs[exp] = coeffs[exp].clone() + q;
+        }
+
+        Ok(Bivariate::new(coeffs))
+    }
+
+    /// Returns the coefficients of `x` in ascending order, polynomials in `y`
+    pub fn coeffs(&self) -> &[Polynomial<T>] {
+        &self.coeffs
+    }
+
+    /// Returns the degree in `x`, [`None`] for the zero polynomial
+    pub fn degree_x(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at `(x, y)`
+    pub fn eval(&self, x: T, y: T) -> T {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, q| acc * x + q.eval(y))
+    }
+
+    /// Returns the polynomial in `y` that results of fixing `x`
+    pub fn eval_x(&self, x: T) -> Polynomial<T> {
+        let x = Polynomial::constant(x);
+
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Polynomial::zero(), |acc, q| acc * x.clone() + q.clone())
+            .with_var('y')
+    }
+
+    /// Returns the polynomial in `x` that results of fixing `y`
+    pub fn eval_y(&self, y: T) -> Polynomial<T> {
+        let coeffs: Vec<T> = self.coeffs.iter().map(|q| q.eval(y)).collect();
+
+        Polynomial::from_coeffs(&coeffs)
+    }
+
+    /// Returns the [resultant](https://en.wikipedia.org/wiki/Resultant) with respect to `x`,
+    /// a polynomial in `y` whose roots are the `y` coordinates of the common zeros, the
+    /// determinant of the Sylvester matrix with polynomial entries
+    ///
+    /// Returns an error if a coefficient has a negative exponent of `y`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Bivariate, Polynomial};
+    /// let circle: Bivariate<i32> = Bivariate::new(vec![
+    ///     Polynomial::try_from("x^2 - 1").unwrap(),
+    ///     Polynomial::zero(),
+    ///     Polynomial::try_from("1").unwrap(),
+    /// ]);
+    /// // y - x
+    /// let line: Bivariate<i32> = Bivariate::new(vec![
+    ///     Polynomial::try_from("x").unwrap(),
+    ///     Polynomial::try_from("-1").unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(format!("{}", circle.resultant_x(&line).unwrap()), "2y^2 - 1");
+    /// ```
+    pub fn resultant_x(&self, other: &Self) -> Result<Polynomial<T>, &'static str> {
+        if self
+            .coeffs
+            .iter()
+            .chain(&other.coeffs)
+            .any(|q| q.into_iter().any(|m| m.get_exp() < 0))
+        {
+            return Err("Negative exponents can't be represented as coefficients");
+        }
+
+        let (Some(m), Some(n)) = (self.degree_x(), other.degree_x()) else {
+            return Ok(Polynomial::zero().with_var('y'));
+        };
+
+        let size = m + n;
+        if size == 0 {
+            return Ok(Polynomial::constant(T::one()).with_var('y'));
+        }
+
+        let mut rows = vec![vec![Polynomial::zero(); size]; size];
+        for i in 0..n {
+            for (j, q) in self.coeffs.iter().rev().enumerate() {
+                rows[i][i + j] = q.clone();
+            }
+        }
+        for i in 0..m {
+            for (j, q) in other.coeffs.iter().rev().enumerate() {
+                rows[n + i][i + j] = q.clone();
+            }
+        }
+
+        Ok(PolyMatrix::new(rows)?.determinant()?.with_var('y'))
+    }
+}
+
+impl<T: MonomialValue> PartialEq for Bivariate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<T: MonomialValue> Add for Bivariate<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        let coeffs = (0..len)
+            .map(|i| {
+                let [a, b] = [&self, &rhs].map(|p| p.coeffs.get(i).cloned().unwrap_or_default());
+                a + b
+            })
+            .collect();
+
+        Bivariate::new(coeffs)
+    }
+}
+
+impl<T: MonomialValue> Mul for Bivariate<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return Bivariate::new(Vec::new());
+        }
+
+        let mut coeffs = vec![Polynomial::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+
+        Bivariate::new(coeffs)
+    }
+}
+
+impl<T: MonomialValue> Neg for Bivariate<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Bivariate::new(self.coeffs.into_iter().map(Neg::neg).collect())
+    }
+}
+
+impl<T: MonomialValue> Display for Bivariate<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms: Vec<String> = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, q)| !q.is_zero())
+            .map(|(exp, q)| match exp {
+                0 => format!("({q})"),
+                1 => format!("({q})x"),
+                _ => format!("({q})x^{exp}"),
+            })
+            .collect();
+
+        match terms.is_empty() {
+            true => write!(f, "0"),
+            false => write!(f, "{}", terms.join(" + ")),
+        }
+    }
+}
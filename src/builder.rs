@@ -0,0 +1,79 @@
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Fluent constructor for [`Polynomial`], terms are collapsed only once on [`build`]
+///
+/// [`build`]: PolynomialBuilder::build
+#[derive(Debug, Clone)]
+pub struct PolynomialBuilder<T> {
+    mono_vec: Vec<Monomial<T>>,
+    var: char,
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns a [`PolynomialBuilder`] to construct a polynomial term by term
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::builder()
+    ///     .term(3, 2)
+    ///     .term(-1, 0)
+    ///     .var('t')
+    ///     .build();
+    ///
+    /// assert_eq!(format!("{poly}"), "3t^2 - 1");
+    /// ```
+    pub fn builder() -> PolynomialBuilder<T> {
+        PolynomialBuilder::new()
+    }
+}
+
+impl<T: MonomialValue> PolynomialBuilder<T> {
+    /// Constructs an empty `PolynomialBuilder<T>` using `x` as variable
+    pub fn new() -> PolynomialBuilder<T> {
+        PolynomialBuilder {
+            mono_vec: Vec::new(),
+            var: 'x',
+        }
+    }
+
+    /// Add the term `value * x^exp`
+    ///  - `value`: Coefficient
+    ///  - `exp`: Exponent
+    pub fn term(self, value: T, exp: i32) -> Self {
+        self.mono(Monomial::new(value, exp))
+    }
+
+    /// Add a monomial term
+    pub fn mono(mut self, mono: Monomial<T>) -> Self {
+        self.mono_vec.push(mono);
+        self
+    }
+
+    /// Set the variable symbol
+    pub fn var(mut self, var: char) -> Self {
+        self.var = var;
+        self
+    }
+
+    /// Construct the polynomial, terms with the same exponent are summed
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::builder()
+    ///     .term(2, 1)
+    ///     .term(5, 0)
+    ///     .term(3, 1)
+    ///     .build();
+    ///
+    /// assert_eq!(poly, Polynomial::try_from("5x + 5").unwrap());
+    /// ```
+    pub fn build(self) -> Polynomial<T> {
+        Polynomial::new(self.mono_vec).with_var(self.var)
+    }
+}
+
+impl<T: MonomialValue> Default for PolynomialBuilder<T> {
+    fn default() -> Self {
+        PolynomialBuilder::new()
+    }
+}
@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+
+use num::{BigInt, CheckedAdd, CheckedMul, Zero};
+
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Evaluates the polynomial at `x` by Horner's method in [`i128`], or [`None`] if a step
+    /// overflows, a coefficient isn't an integer or there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("x^3 - 2x + 7").unwrap();
+    ///
+    /// assert_eq!(poly.eval_i128(3), Some(28));
+    /// assert_eq!(poly.eval_i128(1 << 50), None);
+    /// ```
+    pub fn eval_i128(&self, x: i128) -> Option<i128> {
+        integer_horner(&self.integer_coeffs()?, x)
+    }
+
+    /// Evaluates the polynomial at `x` by Horner's method with checked operations of `T`, or
+    /// [`None`] if a step overflows or there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 + 1").unwrap();
+    ///
+    /// assert_eq!(poly.eval_checked(100), Some(10001));
+    /// assert_eq!(poly.eval_checked(50000), None);
+    /// ```
+    pub fn eval_checked(&self, x: T) -> Option<T>
+    where
+        T: CheckedAdd + CheckedMul,
+    {
+        let coeffs = self.to_coeffs().ok()?;
+
+        coeffs
+            .iter()
+            .rev()
+            .try_fold(T::zero(), |acc, c| acc.checked_mul(&x)?.checked_add(c))
+    }
+
+    /// Returns the sign of `p(x)` as an [`Ordering`] with zero
+    ///
+    /// When `x` and every coefficient are integers the sign is exact, the value is computed in
+    /// [`i128`] and again with big integers if it overflows. Otherwise it's the sign of
+    /// [`eval`](Polynomial::eval). Returns [`None`] if the value is `NaN`, or at zero with a
+    /// negative exponent
+    /// # Examples
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("x^5 - 1").unwrap();
+    /// let x = 1 << 40;
+    ///
+    /// assert_eq!(poly.eval_checked(x), None);
+    /// assert_eq!(poly.sign_at(x), Some(Ordering::Greater));
+    /// assert_eq!(poly.sign_at(-x), Some(Ordering::Less));
+    /// assert_eq!(poly.sign_at(1), Some(Ordering::Equal));
+    /// ```
+    pub fn sign_at(&self, x: T) -> Option<Ordering> {
+        let low = self.into_iter().last().map_or(0, |m| m.get_exp());
+        if low < 0 && x.is_zero() {
+            return None;
+        }
+
+        let reduced = self.clone().div_mono(Monomial::new(T::one(), low.min(0)));
+        let exact = integer(x).zip(reduced.integer_coeffs());
+        let Some((x, coeffs)) = exact else {
+            return self.eval(x).partial_cmp(&T::zero());
+        };
+
+        // `p(x) = x^low q(x)` for a negative `low`, the power only flips the sign of a negative
+        // `x` and odd `low`
+        let sign = match integer_horner(&coeffs, x) {
+            Some(value) => value.cmp(&0),
+            None => big_horner(&coeffs, x).cmp(&BigInt::zero()),
+        };
+        match x < 0 && low < 0 && low % 2 != 0 {
+            true => Some(sign.reverse()),
+            false => Some(sign),
+        }
+    }
+
+    /// Coefficients as [`i128`] in ascending order, [`None`] if any isn't an integer or there
+    /// is any negative exponent
+    fn integer_coeffs(&self) -> Option<Vec<i128>> {
+        self.to_coeffs().ok()?.into_iter().map(integer).collect()
+    }
+}
+
+/// The value as an [`i128`] if it's an integer
+pub(crate) fn integer<T: MonomialValue>(value: T) -> Option<i128> {
+    value.to_i128().filter(|int| T::from(*int) == Some(value))
+}
+
+fn integer_horner(coeffs: &[i128], x: i128) -> Option<i128> {
+    coeffs
+        .iter()
+        .rev()
+        .try_fold(0i128, |acc, c| acc.checked_mul(x)?.checked_add(*c))
+}
+
+fn big_horner(coeffs: &[i128], x: i128) -> BigInt {
+    let x = BigInt::from(x);
+
+    coeffs
+        .iter()
+        .rev()
+        .fold(BigInt::zero(), |acc, c| acc * &x + BigInt::from(*c))
+}
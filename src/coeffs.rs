@@ -0,0 +1,51 @@
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Conversions with dense coefficient vectors in ascending order, `coeffs[i]` is the
+/// coefficient of `x^i`. It's the representation used by other polynomial crates like
+/// `polynomial` (`Polynomial::new(coeffs)`/`data()`) or `polynomen`
+/// (`Poly::new_from_coeffs(&coeffs)`/`coeffs()`), so values can be moved between them
+impl<T: MonomialValue> Polynomial<T> {
+    /// Constructs a polynomial from its coefficients in ascending order
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::from_coeffs(&[-1, 0, 3]);
+    ///
+    /// assert_eq!(format!("{poly}"), "3x^2 - 1");
+    /// ```
+    pub fn from_coeffs(coeffs: &[T]) -> Self {
+        let mono_vec = coeffs
+            .iter()
+            .enumerate()
+            .map(|(exp, value)| Monomial::new(*value, exp as i32))
+            .collect();
+
+        Polynomial::new(mono_vec)
+    }
+
+    /// Returns the coefficients in ascending order, the zero polynomial returns an empty vec
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(poly.to_coeffs(), Ok(vec![-1, 0, 3]));
+    /// assert!(Polynomial::<i32>::try_from("x^-1").unwrap().to_coeffs().is_err());
+    /// ```
+    pub fn to_coeffs(&self) -> Result<Vec<T>, &'static str> {
+        if self.is_zero() {
+            return Ok(Vec::new());
+        }
+
+        let mut coeffs = vec![T::zero(); self.max_exp().get_exp().max(0) as usize + 1];
+        for mono in self {
+            if mono.get_exp() < 0 {
+                return Err("Negative exponents can't be represented as coefficients");
+            }
+
+            coeffs[mono.get_exp() as usize] = mono.get_value();
+        }
+
+        Ok(coeffs)
+    }
+}
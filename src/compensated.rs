@@ -0,0 +1,59 @@
+use crate::poly::Polynomial;
+
+impl Polynomial<f64> {
+    /// Evaluates the polynomial at `x` with the
+    /// [compensated Horner scheme](https://hal.science/hal-00107222), which carries the rounding
+    /// error of every step in a second accumulator with error-free transformations. The result
+    /// is as accurate as Horner's method with twice the working precision, so it stays
+    /// meaningful close to ill-conditioned roots where [`eval`](Polynomial::eval) returns noise
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // (x - 2)^5
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^5 - 10x^4 + 40x^3 - 80x^2 + 80x - 32").unwrap();
+    /// let x = 2.001;
+    /// let exact = (x - 2.0f64).powi(5);
+    ///
+    /// assert!((poly.eval_compensated(x) - exact).abs() < 1e-12 * exact);
+    /// assert!((poly.eval(x) - exact).abs() > exact);
+    /// ```
+    pub fn eval_compensated(&self, x: f64) -> f64 {
+        let Some(high) = self.into_iter().next().map(|m| m.get_exp()) else {
+            return 0f64;
+        };
+        let low = self.into_iter().last().map_or(0, |m| m.get_exp());
+
+        let mut coeffs = vec![0f64; (high - low) as usize + 1];
+        for mono in self {
+            coeffs[(mono.get_exp() - low) as usize] = mono.get_value();
+        }
+
+        let mut value = coeffs[coeffs.len() - 1];
+        let mut error = 0f64;
+        for c in coeffs.iter().rev().skip(1) {
+            let (product, product_error) = two_prod(value, x);
+            let (sum, sum_error) = two_sum(product, *c);
+            value = sum;
+            error = error * x + (product_error + sum_error);
+        }
+
+        (value + error) * x.powi(low)
+    }
+}
+
+/// Sum with its exact rounding error, `a + b = sum + error`
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let virtual_b = sum - a;
+    let error = (a - (sum - virtual_b)) + (b - virtual_b);
+
+    (sum, error)
+}
+
+/// Product with its exact rounding error, `a b = product + error`, through a fused
+/// multiply-add
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+
+    (product, a.mul_add(b, -product))
+}
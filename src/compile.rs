@@ -0,0 +1,99 @@
+use std::any::type_name;
+
+use crate::{mono::Monomial, poly::Polynomial, shader::horner, MonomialValue};
+
+/// Primitive coefficient types that can be written as suffixed Rust literals
+const PRIMITIVES: [&str; 8] = ["i8", "i16", "i32", "i64", "i128", "isize", "f32", "f64"];
+
+/// Evaluation strategy chosen when compiling a polynomial
+enum Kernel<T> {
+    Constant(T),
+    Linear([T; 2]),
+    Quadratic([T; 3]),
+    Cubic([T; 4]),
+    Dense(Vec<T>),
+    Sparse(Vec<Monomial<T>>),
+}
+
+impl<T: MonomialValue> Kernel<T> {
+    fn eval(&self, x: T) -> T {
+        match self {
+            Kernel::Constant(c) => *c,
+            Kernel::Linear([c0, c1]) => *c0 + x * *c1,
+            Kernel::Quadratic([c0, c1, c2]) => *c0 + x * (*c1 + x * *c2),
+            Kernel::Cubic([c0, c1, c2, c3]) => *c0 + x * (*c1 + x * (*c2 + x * *c3)),
+            Kernel::Dense(coeffs) => coeffs.iter().rev().fold(T::zero(), |acc, c| acc * x + *c),
+            Kernel::Sparse(monos) => monos.iter().fold(T::zero(), |acc, m| acc + m.eval(x)),
+        }
+    }
+}
+
+/// Evaluators specialized for a fixed polynomial, for hot loops
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns a closure that evaluates the polynomial like [`eval`](Polynomial::eval). The
+    /// coefficients are copied to a dense array and evaluated with Horner's method, unrolled
+    /// up to degree 3. Polynomials with negative exponents are evaluated term by term
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    /// let f = poly.compile();
+    ///
+    /// assert_eq!((0..5).map(&f).collect::<Vec<_>>(), vec![-6, 0, 0, 0, 6]);
+    /// ```
+    pub fn compile(&self) -> impl Fn(T) -> T {
+        let kernel = match self.to_coeffs() {
+            Ok(coeffs) => match coeffs[..] {
+                [] => Kernel::Constant(T::zero()),
+                [c0] => Kernel::Constant(c0),
+                [c0, c1] => Kernel::Linear([c0, c1]),
+                [c0, c1, c2] => Kernel::Quadratic([c0, c1, c2]),
+                [c0, c1, c2, c3] => Kernel::Cubic([c0, c1, c2, c3]),
+                _ => Kernel::Dense(coeffs),
+            },
+            Err(_) => Kernel::Sparse(self.into_iter().cloned().collect()),
+        };
+
+        move |x| kernel.eval(x)
+    }
+
+    /// Returns the source of a `pub const fn` called `name` that evaluates the polynomial in
+    /// Horner's form, with the variable of the polynomial as argument. A build script can
+    /// write it to `OUT_DIR` and the crate `include!` it, so the evaluator is known at compile
+    /// time
+    ///
+    /// Returns an error if there is any negative exponent, the coefficients aren't a
+    /// primitive number or a float coefficient isn't finite
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("2x^2 - 3").unwrap();
+    ///
+    /// assert_eq!(
+    ///     poly.to_rust_const_fn("eval_poly").unwrap(),
+    ///     "pub const fn eval_poly(x: i64) -> i64 {\n    -3i64 + x * (x * 2i64)\n}\n"
+    /// );
+    /// ```
+    pub fn to_rust_const_fn(&self, name: &str) -> Result<String, &'static str> {
+        let ty = type_name::<T>();
+        if !PRIMITIVES.contains(&ty) {
+            return Err("The coefficients must be a primitive number");
+        }
+
+        let literals = self
+            .to_coeffs()?
+            .into_iter()
+            .map(|c| match c.to_f64().is_some_and(f64::is_finite) {
+                true => Ok((!c.is_zero()).then(|| format!("{c}{ty}"))),
+                false => Err("Not valid coefficient"),
+            })
+            .collect::<Result<Vec<_>, &'static str>>()?;
+
+        let var = self.get_var();
+        let body = horner(literals, var).unwrap_or(format!("0{ty}"));
+
+        Ok(format!(
+            "pub const fn {name}({var}: {ty}) -> {ty} {{\n    {body}\n}}\n"
+        ))
+    }
+}
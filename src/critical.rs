@@ -0,0 +1,129 @@
+use num::Float;
+
+use crate::{
+    mono::Monomial,
+    poly::{total_cmp, Polynomial},
+    MonomialValue,
+};
+
+/// Distinct roots of the first three derivatives, returned by
+/// [`Polynomial::stationary_point_spectrum`]. A list is [`None`] when the derivative couldn't
+/// be solved, like [`Polynomial::roots`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StationarySpectrum<T> {
+    /// Roots of `p'`, the stationary points
+    pub first: Option<Vec<T>>,
+
+    /// Roots of `p''`, the candidates to inflection points
+    pub second: Option<Vec<T>>,
+
+    /// Roots of `p'''`
+    pub third: Option<Vec<T>>,
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the roots of `p'`, `p''` and `p'''` in ascending order
+    ///
+    /// The derivatives are solved from the highest one down: a multiple root of a derivative
+    /// is a root of the next one, so the known roots are deflated before calling the solver
+    /// and it only sees the remaining factor
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^4 - 4x^3").unwrap();
+    /// let spectrum = poly.stationary_point_spectrum();
+    ///
+    /// assert_eq!(spectrum.first, Some(vec![0, 3]));
+    /// assert_eq!(spectrum.second, Some(vec![0, 2]));
+    /// assert_eq!(spectrum.third, Some(vec![1]));
+    /// ```
+    pub fn stationary_point_spectrum(&self) -> StationarySpectrum<T> {
+        let first = self.derivative();
+        let second = first.derivative();
+        let third = second.derivative();
+
+        let third_roots = third.roots().map(distinct);
+        let second_roots = second.roots_deflated(third_roots.as_deref().unwrap_or_default());
+        let first_roots = first.roots_deflated(second_roots.as_deref().unwrap_or_default());
+
+        StationarySpectrum {
+            first: first_roots,
+            second: second_roots,
+            third: third_roots,
+        }
+    }
+
+    /// Distinct roots, dividing out the `known` values that are roots before solving the rest
+    pub(crate) fn roots_deflated(&self, known: &[T]) -> Option<Vec<T>> {
+        if self.max_exp().get_exp() < 1 || self.into_iter().any(|m| m.get_exp() < 0) {
+            return self.roots().map(distinct);
+        }
+
+        let mut rest = self.clone();
+        let mut roots = Vec::new();
+        for &root in known {
+            let factor = Polynomial::new(vec![Monomial::new(T::one(), 1), Monomial::new(-root, 0)]);
+            while rest.max_exp().get_exp() > 0 && rest.eval(root).is_zero() {
+                rest = (rest / factor.clone()).0;
+                roots.push(root);
+            }
+        }
+
+        if rest.max_exp().get_exp() > 0 {
+            roots.extend(rest.roots()?);
+        }
+
+        Some(distinct(roots))
+    }
+}
+
+impl<T: MonomialValue + Float> Polynomial<T> {
+    /// Returns the global minimum `(x, p(x))` of a polynomial bounded below, that is, a
+    /// constant or a polynomial of even degree with positive leading coefficient. The
+    /// stationary points are isolated with the Sturm sequence of `p'` inside its root bound
+    /// and the lowest one is returned
+    ///
+    /// Returns [`None`] if the polynomial isn't bounded below or there is any negative
+    /// exponent. Any `x` is a minimum of a constant, zero is returned
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^4 - 2x^2 + 0.5x").unwrap();
+    /// let (x, min) = poly.global_minimum().unwrap();
+    ///
+    /// assert!((x + 1.05745).abs() < 1e-5);
+    /// assert!((min + 1.51475).abs() < 1e-5);
+    /// assert_eq!(Polynomial::<f64>::try_from("-x^2").unwrap().global_minimum(), None);
+    /// ```
+    pub fn global_minimum(&self) -> Option<(T, T)> {
+        if self.into_iter().any(|m| m.get_exp() < 0) {
+            return None;
+        }
+
+        let lead = self.max_exp();
+        if lead.get_exp() == 0 {
+            return Some((T::zero(), lead.get_value()));
+        }
+        if lead.get_exp() % 2 == 1 || lead.get_value() < T::zero() {
+            return None;
+        }
+
+        let first = self.derivative();
+        let bound = first.max_root_magnitude_bound()?;
+
+        first
+            .roots_in_interval(-bound, bound)
+            .ok()?
+            .into_iter()
+            .filter_map(T::from)
+            .map(|x| (x, self.eval(x)))
+            .min_by(|a, b| total_cmp(&a.1, &b.1))
+    }
+}
+
+/// Sorted roots without repetitions
+fn distinct<T: MonomialValue>(mut roots: Vec<T>) -> Vec<T> {
+    roots.sort_by(total_cmp);
+    roots.dedup();
+    roots
+}
@@ -0,0 +1,157 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use num::{FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+/// Exact decimal coefficient, a wrapper of [`rust_decimal::Decimal`] with the traits of a
+/// [`MonomialValue`](crate::MonomialValue). Parsed values keep their decimal digits, so
+/// `0.1 + 0.2` is exactly `0.3` unlike with [`f64`]
+///
+/// Arithmetic panics on overflow, like the wrapped type
+/// # Examples
+/// ```
+/// # use rust_polynomial::{Decimal, Polynomial};
+/// let poly: Polynomial<Decimal> = Polynomial::try_from("0.1x + 0.2").unwrap();
+///
+/// assert_eq!(poly.eval(Decimal::from(1)), "0.3".parse().unwrap());
+/// assert_eq!(format!("{}", poly.clone() * poly), "0.01x^2 + 0.04x + 0.04");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(pub rust_decimal::Decimal);
+
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Decimal(value)
+    }
+}
+
+impl From<Decimal> for rust_decimal::Decimal {
+    fn from(value: Decimal) -> Self {
+        value.0
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Decimal(rust_decimal::Decimal::from(value))
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// Plain decimal strings, and scientific notation like `1.5e-3`
+impl FromStr for Decimal {
+    type Err = rust_decimal::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        rust_decimal::Decimal::from_str(s)
+            .or_else(|e| rust_decimal::Decimal::from_scientific(s).map_err(|_| e))
+            .map(Decimal)
+    }
+}
+
+macro_rules! impl_decimal_op {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait for Decimal {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Decimal(self.0.$method(rhs.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_decimal_op!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Decimal(-self.0)
+    }
+}
+
+impl Zero for Decimal {
+    fn zero() -> Self {
+        Decimal(rust_decimal::Decimal::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for Decimal {
+    fn one() -> Self {
+        Decimal(rust_decimal::Decimal::ONE)
+    }
+}
+
+impl Num for Decimal {
+    type FromStrRadixErr = rust_decimal::Error;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        rust_decimal::Decimal::from_str_radix(str, radix).map(Decimal)
+    }
+}
+
+impl Signed for Decimal {
+    fn abs(&self) -> Self {
+        Decimal(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Decimal(self.0.abs_sub(&other.0))
+    }
+
+    fn signum(&self) -> Self {
+        Decimal(self.0.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.is_sign_positive() && !self.0.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_sign_negative() && !self.0.is_zero()
+    }
+}
+
+impl ToPrimitive for Decimal {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+/// Integers are converted exactly, other values through [`f64`]
+impl NumCast for Decimal {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        match n.to_f64() {
+            Some(float) if float.fract() != 0f64 => rust_decimal::Decimal::from_f64(float),
+            _ => rust_decimal::Decimal::from_i128(n.to_i128()?),
+        }
+        .map(Decimal)
+    }
+}
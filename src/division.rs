@@ -0,0 +1,138 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Reason why [`Monomial::checked_div`] or [`Polynomial::checked_div`] couldn't divide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivError {
+    /// The divisor is zero
+    DivisionByZero,
+
+    /// An exponent of the result doesn't fit in [`i32`]
+    ExponentOverflow,
+}
+
+impl Display for DivError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DivError::DivisionByZero => write!(f, "Division by zero"),
+            DivError::ExponentOverflow => write!(f, "Exponent overflow"),
+        }
+    }
+}
+
+impl Error for DivError {}
+
+impl<T: MonomialValue> Monomial<T> {
+    /// Same as `/`, but dividing by zero or an exponent out of [`i32`] is an error instead of
+    /// a panic or an infinite value. A divisor of higher exponent gives a negative exponent,
+    /// `x / x^3 = x^-2`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{DivError, Monomial};
+    /// assert_eq!(Monomial::new(6, 1).checked_div(Monomial::new(3, 3)), Ok(Monomial::new(2, -2)));
+    /// assert_eq!(Monomial::new(6, 1).checked_div(Monomial::new(0, 0)), Err(DivError::DivisionByZero));
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Result<Self, DivError> {
+        if rhs.value.is_zero() {
+            return Err(DivError::DivisionByZero);
+        }
+
+        let exp = self
+            .exp
+            .checked_sub(rhs.exp)
+            .ok_or(DivError::ExponentOverflow)?;
+
+        Ok(Monomial::new(self.value / rhs.value, exp))
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Same as `/`, returning the quotient and the remainder, but dividing by zero is an error
+    /// instead of a panic
+    ///
+    /// Negative exponents follow the rules of
+    /// [Laurent polynomials](https://en.wikipedia.org/wiki/Laurent_polynomial): terms of the
+    /// quotient are produced while the remainder has a term with exponent greater or equal
+    /// than the degree of the divisor, so the quotient can have negative exponents. The
+    /// remainder has lower degree than the divisor unless a leading coefficient isn't
+    /// divisible in `T`, like `x` by `2x` with integer coefficients, then the division stops
+    /// there and the remainder keeps the rest of the dividend
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{DivError, Polynomial};
+    /// let p: Polynomial<i32> = Polynomial::try_from("x^2 + 3x^-1").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^-1").unwrap();
+    /// let (quotient, remainder) = p.checked_div(&q).unwrap();
+    ///
+    /// assert_eq!(format!("{quotient}"), "x^3 + 3");
+    /// assert!(remainder.is_zero());
+    /// assert_eq!(p.checked_div(&Polynomial::zero()), Err(DivError::DivisionByZero));
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> Result<(Self, Self), DivError> {
+        if rhs.is_zero() {
+            return Err(DivError::DivisionByZero);
+        }
+
+        if !self.quotient_fits(rhs) {
+            return Err(DivError::ExponentOverflow);
+        }
+
+        Ok(self.clone() / rhs.clone())
+    }
+
+    /// Returns `true` if the exponents of the long division by `rhs` fit in [`i32`]
+    pub(crate) fn quotient_fits(&self, rhs: &Self) -> bool {
+        let fits = |m: &Monomial<T>| {
+            m.get_exp()
+                .checked_sub(rhs.max_exp().get_exp())
+                .and_then(|exp| exp.checked_add(rhs.into_iter().last()?.get_exp()))
+                .is_some()
+        };
+
+        self.into_iter().all(fits)
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the successive quotients of the Euclidean algorithm on `self` and `other`,
+    /// `r_(i-1) = q_i r_i + r_(i+1)` from `r_0 = self` and `r_1 = other` until the remainder
+    /// is zero. They are the terms of the continued fraction
+    /// `self / other = q_1 + 1 / (q_2 + 1 / (q_3 + ...))`
+    ///
+    /// The chain stops early when a division isn't possible in `T`, like `x` by `2x` with
+    /// integer coefficients. A zero `other` or any negative exponent returns an empty vec
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<f64> = Polynomial::try_from("x^3 + 2x").unwrap();
+    /// let q: Polynomial<f64> = Polynomial::try_from("x^2 + 1").unwrap();
+    /// let chain: Vec<String> = p.euclidean_chain(&q).iter().map(|q| format!("{q}")).collect();
+    ///
+    /// // (x^3 + 2x) / (x^2 + 1) = x + 1 / (x + 1 / x)
+    /// assert_eq!(chain, vec!["x", "x", "x"]);
+    /// ```
+    pub fn euclidean_chain(&self, other: &Self) -> Vec<Self> {
+        if self.into_iter().chain(other).any(|m| m.get_exp() < 0) {
+            return Vec::new();
+        }
+
+        let mut chain = Vec::new();
+        let (mut previous, mut current) = (self.clone(), other.clone());
+        while !current.is_zero() {
+            let (quotient, remainder) = previous.clone() / current.clone();
+            if !remainder.is_zero() && remainder.max_exp().get_exp() >= current.max_exp().get_exp()
+            {
+                break;
+            }
+
+            chain.push(quotient);
+            (previous, current) = (current, remainder);
+        }
+
+        chain
+    }
+}
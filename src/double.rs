@@ -0,0 +1,428 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use num::{Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+use crate::compensated::{two_prod, two_sum};
+
+/// Significant decimal digits written by `Display`
+const DISPLAY_DIGITS: usize = 32;
+
+/// [Double-double](https://en.wikipedia.org/wiki/Quadruple-precision_floating-point_format#Double-double_arithmetic)
+/// float, the unevaluated sum of two [`f64`] with about 106 bits of mantissa, 32 decimal
+/// digits. It's a coefficient type for ill-conditioned problems: evaluation near clustered
+/// roots and [`newton_refine`](crate::Polynomial::newton_refine) of roots found in `f64`
+///
+/// The exponent range is the one of [`f64`]. Parsing reads every digit, so `0.1` is more
+/// accurate than the [`f64`] literal
+/// # Examples
+/// ```
+/// # use rust_polynomial::{DoubleDouble, Polynomial};
+/// // (x - 1)^7 close to the root, where f64 only returns rounding noise
+/// let expanded = "x^7 - 7x^6 + 21x^5 - 35x^4 + 35x^3 - 21x^2 + 7x - 1";
+/// let poly: Polynomial<DoubleDouble> = Polynomial::try_from(expanded).unwrap();
+/// let x: DoubleDouble = "1.001".parse().unwrap();
+/// let exact = 1e-21;
+///
+/// assert!((poly.eval(x).hi() - exact).abs() < 1e-6 * exact);
+///
+/// let float: Polynomial<f64> = Polynomial::try_from(expanded).unwrap();
+/// assert!((float.eval(1.001) - exact).abs() > exact);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    /// Constructs a new `DoubleDouble` with the value `hi + lo`, exactly
+    pub fn new(hi: f64, lo: f64) -> DoubleDouble {
+        let (hi, lo) = two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Returns the leading part, the nearest [`f64`] to the value
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the rounding error of [`hi`](DoubleDouble::hi)
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Largest integer less or equal
+    pub fn floor(&self) -> DoubleDouble {
+        match self.hi.floor() {
+            hi if hi == self.hi => DoubleDouble::new(hi, self.lo.floor()),
+            hi => exact(hi),
+        }
+    }
+
+    /// Integer part, rounded towards zero
+    pub fn trunc(&self) -> DoubleDouble {
+        match self.hi < 0f64 {
+            true => -(-*self).floor(),
+            false => self.floor(),
+        }
+    }
+
+    /// Returns `10^exp`
+    fn pow10(exp: i32) -> DoubleDouble {
+        let mut result = DoubleDouble::one();
+        let mut base = exact(10f64);
+        let mut n = exp.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            n >>= 1;
+        }
+
+        match exp < 0 {
+            true => DoubleDouble::one() / result,
+            false => result,
+        }
+    }
+
+    /// Decimal digits of the absolute value, rounded to `count` significant digits, and the
+    /// exponent of the first one
+    fn digits(&self, count: usize) -> (Vec<u8>, i32) {
+        let value = self.abs();
+        let mut exp = value.hi.log10().floor() as i32;
+        let mut scaled = value / DoubleDouble::pow10(exp);
+        if scaled.hi >= 10f64 {
+            (scaled, exp) = (scaled / exact(10f64), exp + 1);
+        }
+        if scaled.hi < 1f64 {
+            (scaled, exp) = (scaled * exact(10f64), exp - 1);
+        }
+
+        let mut digits = Vec::with_capacity(count + 1);
+        for _ in 0..=count {
+            let digit = scaled.floor().hi.clamp(0f64, 9f64);
+            digits.push(digit as u8);
+            scaled = (scaled - exact(digit)) * exact(10f64);
+        }
+
+        // Round half up on the extra digit
+        if digits.pop().is_some_and(|d| d >= 5) {
+            let mut i = digits.len();
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    digits.pop();
+                    exp += 1;
+                    break;
+                }
+                i -= 1;
+                match digits[i] {
+                    9 => digits[i] = 0,
+                    _ => {
+                        digits[i] += 1;
+                        break;
+                    }
+                }
+            }
+        }
+
+        (digits, exp)
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(value: f64) -> Self {
+        exact(value)
+    }
+}
+
+/// The `f64` as a `DoubleDouble`, a free function so it doesn't clash with [`NumCast::from`]
+fn exact(value: f64) -> DoubleDouble {
+    DoubleDouble {
+        hi: value,
+        lo: 0f64,
+    }
+}
+
+impl Display for DoubleDouble {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.hi.is_finite() || self.hi == 0f64 {
+            return match f.precision() {
+                Some(precision) => write!(f, "{:.precision$}", self.hi),
+                None => write!(f, "{}", self.hi),
+            };
+        }
+
+        let sign = if self.hi < 0f64 { "-" } else { "" };
+        let exp = self.abs().digits(1).1;
+        let count = match f.precision() {
+            // No digit is shown, the rounding of f64 is enough
+            Some(precision) if exp + 1 + (precision as i32) < 1 => {
+                return write!(f, "{:.precision$}", self.hi);
+            }
+            Some(precision) => (exp + 1 + precision as i32).min(DISPLAY_DIGITS as i32),
+            None => DISPLAY_DIGITS as i32,
+        } as usize;
+        let (mut digits, exp) = self.digits(count);
+
+        // Scientific notation out of the range of the digits
+        if f.precision().is_none() && !(-5..DISPLAY_DIGITS as i32).contains(&exp) {
+            while digits.len() > 1 && digits.last() == Some(&0) {
+                digits.pop();
+            }
+            let [first, rest @ ..] = digits.as_slice() else {
+                unreachable!()
+            };
+            let rest: String = rest.iter().map(|d| (b'0' + d) as char).collect();
+            let point = if rest.is_empty() { "" } else { "." };
+            return write!(f, "{sign}{first}{point}{rest}e{exp}");
+        }
+
+        let mut text = String::new();
+        if exp < 0 {
+            text.push('0');
+            text.push('.');
+            text.extend(std::iter::repeat_n('0', (-exp - 1) as usize));
+        }
+        for (i, digit) in digits.iter().enumerate() {
+            if exp >= 0 && i == exp as usize + 1 {
+                text.push('.');
+            }
+            text.push((b'0' + digit) as char);
+        }
+        for _ in digits.len()..(exp + 1).max(0) as usize {
+            text.push('0');
+        }
+
+        if let Some(precision) = f.precision() {
+            let decimals = text.find('.').map_or(0, |point| text.len() - point - 1);
+            if decimals < precision {
+                if decimals == 0 {
+                    text.push('.');
+                }
+                text.extend(std::iter::repeat_n('0', precision - decimals));
+            }
+        } else if text.contains('.') {
+            text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+        }
+
+        write!(f, "{sign}{text}")
+    }
+}
+
+/// Decimal strings with optional sign, fraction and exponent, `-1.25e-3`
+impl FromStr for DoubleDouble {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (mantissa, exp) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exp)) => (
+                mantissa,
+                exp.parse::<i32>().map_err(|_| "Not valid exponent")?,
+            ),
+            None => (unsigned, 0),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let digits = int.chars().chain(frac.chars());
+        if int.is_empty() && frac.is_empty() || !digits.clone().all(|c| c.is_ascii_digit()) {
+            return Err("Not valid number");
+        }
+
+        let ten = exact(10f64);
+        let value = digits.fold(DoubleDouble::zero(), |acc, c| {
+            acc * ten + exact((c as u8 - b'0') as f64)
+        });
+        let exp = exp
+            .checked_sub(frac.len() as i32)
+            .ok_or("Not valid exponent")?;
+        let value = match exp < 0 {
+            true => value / DoubleDouble::pow10(-exp),
+            false => value * DoubleDouble::pow10(exp),
+        };
+
+        Ok(if negative { -value } else { value })
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi)? {
+            Ordering::Equal => self.lo.partial_cmp(&other.lo),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, error) = two_sum(self.hi, rhs.hi);
+        let (low_sum, low_error) = two_sum(self.lo, rhs.lo);
+        let sum = DoubleDouble::new(sum, error + low_sum);
+
+        DoubleDouble::new(sum.hi, sum.lo + low_error)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (product, error) = two_prod(self.hi, rhs.hi);
+
+        DoubleDouble::new(product, error + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.hi == 0f64 {
+            return exact(self.hi / rhs.hi);
+        }
+
+        // Long division, one f64 of the quotient per step
+        let first = self.hi / rhs.hi;
+        let rest = self - rhs * exact(first);
+        let second = rest.hi / rhs.hi;
+        let rest = rest - rhs * exact(second);
+        let third = rest.hi / rhs.hi;
+
+        DoubleDouble::new(first, second) + exact(third)
+    }
+}
+
+impl Rem for DoubleDouble {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Zero for DoubleDouble {
+    fn zero() -> Self {
+        exact(0f64)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.hi == 0f64
+    }
+}
+
+impl One for DoubleDouble {
+    fn one() -> Self {
+        exact(1f64)
+    }
+}
+
+impl Num for DoubleDouble {
+    type FromStrRadixErr = &'static str;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        match radix {
+            10 => str.parse(),
+            _ => Err("Only radix 10 is supported"),
+        }
+    }
+}
+
+impl Signed for DoubleDouble {
+    fn abs(&self) -> Self {
+        match self.hi < 0f64 {
+            true => -*self,
+            false => *self,
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        match self > other {
+            true => *self - *other,
+            false => DoubleDouble::zero(),
+        }
+    }
+
+    fn signum(&self) -> Self {
+        exact(match self.hi {
+            0f64 => 0f64,
+            hi => hi.signum(),
+        })
+    }
+
+    fn is_positive(&self) -> bool {
+        self.hi > 0f64
+    }
+
+    fn is_negative(&self) -> bool {
+        self.hi < 0f64
+    }
+}
+
+impl ToPrimitive for DoubleDouble {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128()?.to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        let int = self.trunc();
+        if !int.hi.is_finite() || int.hi.abs() >= 2f64.powi(126) {
+            return None;
+        }
+
+        Some(int.hi as i128 + int.lo as i128)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_i128()?.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.hi)
+    }
+}
+
+/// Integers are converted exactly up to 106 bits, other values through [`f64`]
+impl NumCast for DoubleDouble {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        match n.to_f64() {
+            Some(float) if float.fract() != 0f64 || !float.is_finite() => Some(exact(float)),
+            _ => {
+                let int = n.to_i128()?;
+                let hi = int as f64;
+                Some(DoubleDouble::new(hi, (int - hi as i128) as f64))
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::MonomialValue;
+
+/// [Dual number](https://en.wikipedia.org/wiki/Dual_number) `a + bε` with `ε² = 0`, carries
+/// a value and its derivative through additions and products. It's the automatic
+/// differentiation path of the crate: evaluating a polynomial at [`Dual::variable`] with
+/// [`Polynomial::eval_as`](crate::Polynomial::eval_as) returns `p(x)` and `p'(x)` at once,
+/// without building the derivative
+/// # Examples
+/// ```
+/// # use rust_polynomial::{Dual, Polynomial};
+/// let poly: Polynomial<f64> = Polynomial::try_from("x^3 - 2x + 1").unwrap();
+/// let result = poly.eval_as(Dual::variable(2.0)).unwrap();
+///
+/// assert_eq!(result.value(), 5.0);
+/// assert_eq!(result.derivative(), 10.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Dual<T> {
+    value: T,
+    derivative: T,
+}
+
+impl<T: MonomialValue> Dual<T> {
+    /// Constructs a new `Dual<T>` from the value and its derivative
+    pub fn new(value: T, derivative: T) -> Dual<T> {
+        Dual { value, derivative }
+    }
+
+    /// The variable of differentiation at `x`, with derivative one
+    pub fn variable(x: T) -> Dual<T> {
+        Dual::new(x, T::one())
+    }
+
+    /// A constant, with derivative zero
+    pub fn constant(c: T) -> Dual<T> {
+        Dual::new(c, T::zero())
+    }
+
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    pub fn derivative(&self) -> T {
+        self.derivative
+    }
+}
+
+impl<T: MonomialValue> From<T> for Dual<T> {
+    fn from(value: T) -> Self {
+        Dual::constant(value)
+    }
+}
+
+impl<T: MonomialValue> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<T: MonomialValue> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dual::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<T: MonomialValue> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dual::new(
+            self.value * rhs.value,
+            self.value * rhs.derivative + self.derivative * rhs.value,
+        )
+    }
+}
+
+impl<T: MonomialValue> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
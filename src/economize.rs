@@ -0,0 +1,81 @@
+use num::Float;
+
+use crate::{
+    operator::{LinearOperator, ScaleOp, ShiftOp},
+    poly::Polynomial,
+    MonomialValue,
+};
+
+impl<T: MonomialValue + Float> Polynomial<T> {
+    /// Returns an approximation of degree `n` over `[a, b]` by
+    /// [Chebyshev economization](https://en.wikipedia.org/wiki/Chebyshev_polynomials#Polynomial_in_Chebyshev_form):
+    /// every term above `n` is replaced by the lower terms of its Chebyshev polynomial, which
+    /// truncates the Chebyshev series of the polynomial. It's close to the best uniform
+    /// approximation, and the error is at most the sum of the removed Chebyshev coefficients
+    ///
+    /// Returns the same polynomial if its degree is at most `n`, and an error if `a >= b`,
+    /// `n` is negative or there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("4x^3 - 3x + 0.5").unwrap();
+    ///
+    /// // `4x^3 - 3x` is the Chebyshev polynomial of degree 3
+    /// assert_eq!(format!("{}", poly.reduce_degree(2, (-1.0, 1.0)).unwrap()), "0.5");
+    /// ```
+    pub fn reduce_degree(&self, n: i32, (a, b): (T, T)) -> Result<Self, &'static str> {
+        if n < 0 {
+            return Err("Not valid degree");
+        }
+        if a.partial_cmp(&b) != Some(std::cmp::Ordering::Less) {
+            return Err("Not valid interval");
+        }
+        if self.into_iter().any(|m| m.get_exp() < 0) {
+            return Err("Negative exponents can't be reduced");
+        }
+        if self.max_exp().get_exp() <= n {
+            return Ok(self.clone());
+        }
+
+        // Map `[a, b]` to `[-1, 1]`, `x = mid + half * t`
+        let two = T::one() + T::one();
+        let (mid, half) = ((a + b) / two, (b - a) / two);
+        let mut reduced = ScaleOp(half).apply(&self.shift(mid)?)?;
+
+        let chebyshev = chebyshev_polynomials(reduced.max_exp().get_exp());
+        while !reduced.is_zero() && reduced.max_exp().get_exp() > n {
+            let lead = reduced.max_exp();
+            let degree = lead.get_exp();
+            let factor = lead.get_value() / two.powi(degree - 1);
+            let removed = chebyshev[degree as usize].clone() * Polynomial::constant(factor);
+
+            // The leading term is removed exactly, rounding could leave a tiny remainder
+            reduced = Polynomial::new(
+                (reduced + -removed)
+                    .into_iter()
+                    .filter(|m| m.get_exp() != degree)
+                    .collect(),
+            );
+        }
+
+        Ok(ScaleOp(half.recip())
+            .then(ShiftOp(-mid))
+            .apply(&reduced)?
+            .with_var(self.get_var()))
+    }
+}
+
+/// Chebyshev polynomials of the first kind `T_0..=T_n`, `T_k+1 = 2x T_k - T_k-1`
+pub(crate) fn chebyshev_polynomials<T: MonomialValue>(n: i32) -> Vec<Polynomial<T>> {
+    let two_x = Polynomial::from((T::one() + T::one(), 1));
+    let mut result = vec![
+        Polynomial::constant(T::one()),
+        Polynomial::from((T::one(), 1)),
+    ];
+    for k in 2..=n as usize {
+        let next = two_x.clone() * result[k - 1].clone() + -result[k - 2].clone();
+        result.push(next);
+    }
+
+    result
+}
@@ -0,0 +1,199 @@
+use std::ops::{Add, Mul, Neg};
+
+use crate::{
+    hash::{mul_mod, probe_points, PRIME, SEED},
+    poly::Polynomial,
+    MonomialValue,
+};
+
+/// Unexpanded sum and product of borrowed polynomials, built with `+`, `*` and `-` on
+/// `&Polynomial`. Evaluating it or asking its degree works on the operands, the expanded
+/// polynomial is only built by [`expand`](PolyExpr::expand)
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let p: Polynomial<i64> = Polynomial::try_from("x^3 + 2").unwrap();
+/// let q: Polynomial<i64> = Polynomial::try_from("x^2 - x").unwrap();
+/// let r: Polynomial<i64> = Polynomial::try_from("4x").unwrap();
+/// let expr = &p * &q + &r;
+///
+/// assert_eq!(expr.degree(), Some(5));
+/// assert_eq!(expr.eval(2), 28);
+/// assert_eq!(format!("{}", expr.expand()), "x^5 - x^4 + 2x^2 + 2x");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolyExpr<'a, T> {
+    node: Node<'a, T>,
+}
+
+#[derive(Debug, Clone)]
+enum Node<'a, T> {
+    Leaf(&'a Polynomial<T>),
+    Add(Box<PolyExpr<'a, T>>, Box<PolyExpr<'a, T>>),
+    Mul(Box<PolyExpr<'a, T>>, Box<PolyExpr<'a, T>>),
+    Neg(Box<PolyExpr<'a, T>>),
+}
+
+impl<'a, T: MonomialValue> PolyExpr<'a, T> {
+    /// Evaluates every operand at `x` and combines the values
+    pub fn eval(&self, x: T) -> T {
+        match &self.node {
+            Node::Leaf(poly) => poly.eval(x),
+            Node::Add(lhs, rhs) => lhs.eval(x) + rhs.eval(x),
+            Node::Mul(lhs, rhs) => lhs.eval(x) * rhs.eval(x),
+            Node::Neg(expr) => -expr.eval(x),
+        }
+    }
+
+    /// Returns the highest exponent of the expanded polynomial, [`None`] if it's zero. It's
+    /// found from the leading terms of the operands, only a sum whose leading terms cancel is
+    /// expanded
+    pub fn degree(&self) -> Option<i32> {
+        self.leading().map(|(exp, _)| exp)
+    }
+
+    /// Returns the expanded polynomial
+    pub fn expand(&self) -> Polynomial<T> {
+        match &self.node {
+            Node::Leaf(poly) => (*poly).clone(),
+            Node::Add(lhs, rhs) => lhs.expand() + rhs.expand(),
+            Node::Mul(lhs, rhs) => lhs.expand() * rhs.expand(),
+            Node::Neg(expr) => -expr.expand(),
+        }
+    }
+
+    /// Same as [`Polynomial::probably_equal`] without expanding the expression, the
+    /// operands are evaluated at the same points and combined
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i64> = Polynomial::try_from("x + 1").unwrap();
+    /// let q: Polynomial<i64> = Polynomial::try_from("x - 1").unwrap();
+    /// let expanded: Polynomial<i64> = Polynomial::try_from("x^2 - 1").unwrap();
+    ///
+    /// assert!((&p * &q).probably_equal(&expanded));
+    /// assert!(!(&p * &p).probably_equal(&expanded));
+    /// ```
+    pub fn probably_equal(&self, other: &Polynomial<T>) -> bool {
+        probe_points(SEED).all(|point| {
+            match (self.eval_mod_prime(point), other.eval_mod_prime(point)) {
+                (Some(a), Some(b)) => a == b,
+                _ => self.expand() == *other,
+            }
+        })
+    }
+
+    /// Exponent and coefficient of the leading term of the expanded polynomial
+    fn leading(&self) -> Option<(i32, T)> {
+        let lead = |poly: &Polynomial<T>| {
+            let mono = poly.max_exp();
+            (!poly.is_zero()).then(|| (mono.get_exp(), mono.get_value()))
+        };
+
+        match &self.node {
+            Node::Leaf(poly) => lead(poly),
+            Node::Neg(expr) => expr.leading().map(|(exp, value)| (exp, -value)),
+            Node::Mul(lhs, rhs) => {
+                let ((lhs_exp, lhs_value), (rhs_exp, rhs_value)) = (lhs.leading()?, rhs.leading()?);
+                let value = lhs_value * rhs_value;
+
+                // Products of nonzero values only vanish by underflow or overflow
+                match value.is_zero() {
+                    true => lead(&self.expand()),
+                    false => Some((lhs_exp + rhs_exp, value)),
+                }
+            }
+            Node::Add(lhs, rhs) => match (lhs.leading(), rhs.leading()) {
+                (None, other) | (other, None) => other,
+                (Some(a), Some(b)) if a.0 != b.0 => Some(if a.0 > b.0 { a } else { b }),
+                (Some((exp, a)), Some((_, b))) => match (a + b).is_zero() {
+                    true => lead(&self.expand()),
+                    false => Some((exp, a + b)),
+                },
+            },
+        }
+    }
+
+    /// Value at `point` modulo the prime of [`Polynomial::probably_equal`]
+    fn eval_mod_prime(&self, point: u64) -> Option<u64> {
+        match &self.node {
+            Node::Leaf(poly) => poly.eval_mod_prime(point),
+            Node::Add(lhs, rhs) => {
+                Some((lhs.eval_mod_prime(point)? + rhs.eval_mod_prime(point)?) % PRIME)
+            }
+            Node::Mul(lhs, rhs) => Some(mul_mod(
+                lhs.eval_mod_prime(point)?,
+                rhs.eval_mod_prime(point)?,
+            )),
+            Node::Neg(expr) => Some((PRIME - expr.eval_mod_prime(point)?) % PRIME),
+        }
+    }
+}
+
+impl<'a, T> From<&'a Polynomial<T>> for PolyExpr<'a, T> {
+    fn from(poly: &'a Polynomial<T>) -> Self {
+        PolyExpr {
+            node: Node::Leaf(poly),
+        }
+    }
+}
+
+/// Implements a binary operator for every combination of `&Polynomial` and `PolyExpr`
+macro_rules! impl_expr_op {
+    ($trait:ident $method:ident $variant:ident) => {
+        impl<'a, T: MonomialValue> $trait for PolyExpr<'a, T> {
+            type Output = PolyExpr<'a, T>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                PolyExpr {
+                    node: Node::$variant(Box::new(self), Box::new(rhs)),
+                }
+            }
+        }
+
+        impl<'a, T: MonomialValue> $trait<&'a Polynomial<T>> for PolyExpr<'a, T> {
+            type Output = PolyExpr<'a, T>;
+
+            fn $method(self, rhs: &'a Polynomial<T>) -> Self::Output {
+                self.$method(PolyExpr::from(rhs))
+            }
+        }
+
+        impl<'a, T: MonomialValue> $trait<PolyExpr<'a, T>> for &'a Polynomial<T> {
+            type Output = PolyExpr<'a, T>;
+
+            fn $method(self, rhs: PolyExpr<'a, T>) -> Self::Output {
+                PolyExpr::from(self).$method(rhs)
+            }
+        }
+
+        impl<'a, T: MonomialValue> $trait for &'a Polynomial<T> {
+            type Output = PolyExpr<'a, T>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                PolyExpr::from(self).$method(PolyExpr::from(rhs))
+            }
+        }
+    };
+}
+
+impl_expr_op!(Add add Add);
+impl_expr_op!(Mul mul Mul);
+
+impl<'a, T: MonomialValue> Neg for PolyExpr<'a, T> {
+    type Output = PolyExpr<'a, T>;
+
+    fn neg(self) -> Self::Output {
+        PolyExpr {
+            node: Node::Neg(Box::new(self)),
+        }
+    }
+}
+
+impl<'a, T: MonomialValue> Neg for &'a Polynomial<T> {
+    type Output = PolyExpr<'a, T>;
+
+    fn neg(self) -> Self::Output {
+        -PolyExpr::from(self)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    mono::Monomial,
+    parse::{SyntaxError, SyntaxErrorKind},
+    poly::Polynomial,
+    MonomialValue,
+};
+
+/// Highest power of a factor with more than one term in
+/// [`parse_factored`](Polynomial::parse_factored), the expansion has at least as many terms
+const MAX_POWER: u32 = 1 << 12;
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the compact factored form of the polynomial, `2(x-1)(x+3)^2`, built from
+    /// [`factors`](Polynomial::factors). Repeated factors are written as a power and the
+    /// constant goes first without parentheses. There are no spaces and the variable is
+    /// always `x`, so it can be used as a short key and read back with
+    /// [`parse_factored`](Polynomial::parse_factored)
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("2x^3 + 10x^2 + 6x - 18").unwrap();
+    ///
+    /// assert_eq!(poly.to_factored_string(), "2(x+3)^2(x-1)");
+    /// assert_eq!(Polynomial::parse_factored("2(x-1)(x+3)^2"), Ok(poly));
+    /// ```
+    pub fn to_factored_string(&self) -> String {
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        let factors = self.factors();
+        let compact = |p: &Polynomial<T>| format!("{}", p.clone().with_var('x')).replace(' ', "");
+        let mut out = String::new();
+        let mut i = 0;
+
+        while i < factors.len() {
+            let factor = &factors[i];
+            let power = factors[i..].iter().take_while(|f| *f == factor).count();
+            i += power;
+
+            if factor.is_constant() {
+                let value = factor.max_exp().get_value();
+                match value {
+                    _ if factors.len() == 1 => out.push_str(&compact(factor)),
+                    v if v.is_one() => {}
+                    v if v == -T::one() => out.push('-'),
+                    _ => out.push_str(&compact(factor)),
+                }
+                continue;
+            }
+
+            out.push_str(&format!("({})", compact(factor)));
+            if power > 1 {
+                out.push_str(&format!("^{power}"));
+            }
+        }
+
+        out
+    }
+
+    /// Parses the factored form written by [`to_factored_string`](Polynomial::to_factored_string),
+    /// a product of polynomial expressions in parentheses, each one with an optional power
+    /// `^n`. The factors can be preceded by a coefficient or a single sign, and whitespace is
+    /// allowed between them. An expression without parentheses is read with
+    /// [`parse`](Polynomial::parse)
+    ///
+    /// Errors inside a factor point to the offending token in the whole expression. A factor
+    /// is rejected if an exponent of the product doesn't fit in [`i32`], or if it has more
+    /// than one term and is raised above `4096`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::parse_factored("-(x - 2)^2 (x + 1)").unwrap();
+    /// assert_eq!(format!("{poly}"), "-x^3 + 3x^2 - 4");
+    ///
+    /// let error = Polynomial::<i32>::parse_factored("3(x + 1)(x^ - 2)").unwrap_err();
+    /// assert_eq!(error.column, 11);
+    /// assert_eq!(error.message, "Missing exponent");
+    /// ```
+    pub fn parse_factored(expr: &str) -> Result<Self, SyntaxError> {
+        let chars: Vec<(usize, char)> = expr.char_indices().collect();
+        let offset = |pos: usize| chars.get(pos).map_or(expr.len(), |(offset, _)| *offset);
+        let error = |pos: usize, kind: SyntaxErrorKind| SyntaxError {
+            column: pos + 1,
+            offset: offset(pos),
+            token: chars
+                .get(pos)
+                .map(|(_, c)| c.to_string())
+                .unwrap_or_default(),
+            kind,
+            message: kind.message(),
+        };
+
+        let Some(open) = chars.iter().position(|(_, c)| *c == '(') else {
+            return Polynomial::parse(expr);
+        };
+
+        let one = Polynomial::new(vec![Monomial::new(T::one(), 0)]);
+        let prefix = &expr[..offset(open)];
+        let mut product = match prefix.trim() {
+            "" | "+" => one.clone(),
+            "-" => -one.clone(),
+            _ => Polynomial::parse(prefix)?,
+        };
+
+        let skip_whitespace = |mut pos: usize| {
+            while chars.get(pos).is_some_and(|(_, c)| c.is_whitespace()) {
+                pos += 1;
+            }
+            pos
+        };
+
+        let mut pos = open;
+        loop {
+            pos = skip_whitespace(pos);
+            match chars.get(pos) {
+                None => break,
+                Some((_, '(')) => {}
+                Some(_) => return Err(error(pos, SyntaxErrorKind::ExpectedParenthesis)),
+            }
+
+            let start = pos + 1;
+            let close = chars[start..]
+                .iter()
+                .position(|(_, c)| ['(', ')'].contains(c))
+                .map(|i| start + i);
+            let close = match close {
+                Some(close) if chars[close].1 == ')' => close,
+                Some(nested) => return Err(error(nested, SyntaxErrorKind::UnexpectedSymbol)),
+                None => return Err(error(chars.len(), SyntaxErrorKind::MissingParenthesis)),
+            };
+
+            let factor = Polynomial::parse(&expr[offset(start)..offset(close)]).map_err(|e| {
+                SyntaxError {
+                    column: e.column + start,
+                    offset: e.offset + offset(start),
+                    ..e
+                }
+            })?;
+            pos = skip_whitespace(close + 1);
+
+            // Position of the power in errors, the factor if there isn't any
+            let mut power_pos = start - 1;
+            let mut power = 1;
+            if chars.get(pos).is_some_and(|(_, c)| *c == '^') {
+                let caret = pos;
+                pos = skip_whitespace(pos + 1);
+                let digits = chars[pos..]
+                    .iter()
+                    .take_while(|(_, c)| c.is_ascii_digit())
+                    .count();
+                if digits == 0 {
+                    return Err(error(caret, SyntaxErrorKind::MissingExponent));
+                }
+
+                power = expr[offset(pos)..offset(pos + digits)]
+                    .parse::<u32>()
+                    .map_err(|_| error(pos, SyntaxErrorKind::InvalidExponent))?;
+                power_pos = pos;
+                pos += digits;
+            }
+
+            if !power_fits(&product, &factor, power) {
+                return Err(error(power_pos, SyntaxErrorKind::PowerTooLarge));
+            }
+
+            product = product * factor_power(factor, power);
+        }
+
+        Ok(product)
+    }
+}
+
+/// Returns `true` if the exponents of `product * factor^power` fit in [`i32`] and the power
+/// of a factor with several terms is at most [`MAX_POWER`]
+fn power_fits<T: MonomialValue>(
+    product: &Polynomial<T>,
+    factor: &Polynomial<T>,
+    power: u32,
+) -> bool {
+    if factor.into_iter().len() > 1 && power > MAX_POWER {
+        return false;
+    }
+
+    let bounds = |p: &Polynomial<T>| {
+        let high = p.max_exp().get_exp() as i64;
+        (
+            high,
+            p.into_iter().last().map_or(high, |m| m.get_exp() as i64),
+        )
+    };
+    let ((product_high, product_low), (factor_high, factor_low)) =
+        (bounds(product), bounds(factor));
+
+    [
+        product_high + factor_high * power as i64,
+        product_low + factor_low * power as i64,
+    ]
+    .iter()
+    .all(|exp| i32::try_from(*exp).is_ok())
+}
+
+/// `base^power` by repeated squaring
+fn factor_power<T: MonomialValue>(mut base: Polynomial<T>, mut power: u32) -> Polynomial<T> {
+    let mut acc = Polynomial::new(vec![Monomial::new(T::one(), 0)]);
+    while power > 0 {
+        if power & 1 == 1 {
+            acc = acc * base.clone();
+        }
+        power >>= 1;
+        if power > 0 {
+            base = base.clone() * base;
+        }
+    }
+
+    acc
+}
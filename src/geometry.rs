@@ -0,0 +1,60 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Geometry of the curve `y = p(x)`
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the tangent line at `x`, `p(x) + p'(x) (t - x)`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.tangent_line_at(3)), "6x - 9");
+    /// ```
+    pub fn tangent_line_at(&self, x: T) -> Self {
+        let slope = self.derivative().eval(x);
+
+        Polynomial::from_coeffs(&[self.eval(x) - slope * x, slope]).with_var(self.get_var())
+    }
+
+    /// Returns the normal line at `x`, perpendicular to the tangent, `p(x) - (t - x) / p'(x)`.
+    /// Integer coefficients are truncated by the division
+    ///
+    /// Returns [`None`] if the tangent is horizontal, the normal is vertical then
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^2").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.normal_line_at(1.0).unwrap()), "-0.5x + 1.5");
+    /// assert_eq!(poly.normal_line_at(0.0), None);
+    /// ```
+    pub fn normal_line_at(&self, x: T) -> Option<Self> {
+        let slope = self.derivative().eval(x);
+        if slope.is_zero() {
+            return None;
+        }
+
+        let normal = -T::one() / slope;
+        let line = Polynomial::from_coeffs(&[self.eval(x) - normal * x, normal]);
+
+        Some(line.with_var(self.get_var()))
+    }
+
+    /// Returns the [curvature](https://en.wikipedia.org/wiki/Curvature#Graph_of_a_function)
+    /// at `x`, `|p''(x)| / (1 + p'(x)^2)^(3/2)`, the inverse of the radius of the osculating
+    /// circle
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2").unwrap();
+    ///
+    /// assert_eq!(poly.curvature_at(0), Some(2.0));
+    /// ```
+    pub fn curvature_at(&self, x: T) -> Option<f64> {
+        let first = self.derivative();
+        let [d1, d2] = [first.eval(x), first.derivative().eval(x)].map(|v| v.to_f64());
+        let (d1, d2) = (d1?, d2?);
+
+        Some(d2.abs() / (1f64 + d1 * d1).powf(1.5))
+    }
+}
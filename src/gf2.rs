@@ -0,0 +1,319 @@
+//! Polynomials over [GF(2)](https://en.wikipedia.org/wiki/GF(2)), coefficients are bits packed
+//! in words so addition is a `xor` and multiplication is carry-less
+//!
+//! Bitstrings are read as polynomials with the first bit as the highest degree, the convention
+//! of [CRCs](https://en.wikipedia.org/wiki/Cyclic_redundancy_check) and
+//! [LFSRs](https://en.wikipedia.org/wiki/Linear-feedback_shift_register).
+//! ```
+//! use rust_polynomial::gf2::{self, Gf2Poly};
+//!
+//! // CRC-16/XMODEM
+//! let generator = Gf2Poly::from_u64(0x1_1021);
+//! assert_eq!(gf2::crc(b"123456789", &generator).unwrap().to_u64(), Some(0x31C3));
+//! ```
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+};
+
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Polynomial with coefficients in GF(2), bit `i` is the coefficient of `x^i`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Gf2Poly {
+    words: Vec<u64>,
+}
+
+impl Gf2Poly {
+    /// Constructs the zero polynomial
+    pub fn zero() -> Gf2Poly {
+        Gf2Poly { words: Vec::new() }
+    }
+
+    /// Constructs a polynomial from the bits of `bits`, bit `i` is the coefficient of `x^i`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::gf2::Gf2Poly;
+    /// assert_eq!(format!("{}", Gf2Poly::from_u64(0b1011)), "x^3 + x + 1");
+    /// ```
+    pub fn from_u64(bits: u64) -> Gf2Poly {
+        Gf2Poly { words: vec![bits] }.normalized()
+    }
+
+    /// Constructs a polynomial from its coefficients in ascending order
+    pub fn from_coeffs(coeffs: &[bool]) -> Gf2Poly {
+        let mut poly = Gf2Poly::zero();
+        for (i, _) in coeffs.iter().enumerate().filter(|(_, c)| **c) {
+            poly.flip(i);
+        }
+
+        poly
+    }
+
+    /// Constructs the polynomial of a bitstring, the highest bit of the first byte is the
+    /// highest degree
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::gf2::Gf2Poly;
+    /// assert_eq!(Gf2Poly::from_bytes(&[0x01, 0x03]), Gf2Poly::from_u64(0x0103));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Gf2Poly {
+        let len = bytes.len() * 8;
+        let mut poly = Gf2Poly::zero();
+        for (i, _) in bits_of(bytes).enumerate().filter(|(_, b)| *b) {
+            poly.flip(len - 1 - i);
+        }
+
+        poly
+    }
+
+    /// Returns the coefficients in ascending order, the zero polynomial returns an empty vec
+    pub fn to_coeffs(&self) -> Vec<bool> {
+        match self.degree() {
+            Some(degree) => (0..=degree).map(|i| self.coeff(i)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the bits as an integer, or [`None`] if the degree is over 63
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.words.len() {
+            0 => Some(0),
+            1 => Some(self.words[0]),
+            _ => None,
+        }
+    }
+
+    /// Returns the degree, [`None`] for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        let last = self.words.last()?;
+        Some((self.words.len() - 1) * 64 + 63 - last.leading_zeros() as usize)
+    }
+
+    /// Returns the coefficient of `x^i`
+    pub fn coeff(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|w| w >> (i % 64) & 1 == 1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the remainder of the division by `modulus`
+    ///
+    /// Returns an error if `modulus` is zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::gf2::Gf2Poly;
+    /// let poly = Gf2Poly::from_u64(0b1_0000);
+    ///
+    /// assert_eq!(poly.rem(&Gf2Poly::from_u64(0b1_0011)), Ok(Gf2Poly::from_u64(0b0011)));
+    /// ```
+    pub fn rem(&self, modulus: &Gf2Poly) -> Result<Gf2Poly, &'static str> {
+        let modulus_degree = modulus.degree().ok_or("Division by zero")?;
+        let mut rem = self.clone();
+
+        while let Some(degree) = rem.degree().filter(|d| *d >= modulus_degree) {
+            rem.xor_shifted(modulus, degree - modulus_degree);
+        }
+
+        Ok(rem)
+    }
+
+    /// Returns the same polynomial with integer coefficients `0` and `1`
+    pub fn to_polynomial<T: MonomialValue>(&self) -> Polynomial<T> {
+        let coeffs: Vec<T> = self
+            .to_coeffs()
+            .into_iter()
+            .map(|c| match c {
+                true => T::one(),
+                false => T::zero(),
+            })
+            .collect();
+
+        Polynomial::from_coeffs(&coeffs)
+    }
+
+    /// `self += other * x^shift`
+    fn xor_shifted(&mut self, other: &Gf2Poly, shift: usize) {
+        let (word_shift, bit_shift) = (shift / 64, shift % 64);
+        let len = other.words.len() + word_shift + 1;
+        if self.words.len() < len {
+            self.words.resize(len, 0);
+        }
+
+        for (i, word) in other.words.iter().enumerate() {
+            self.words[i + word_shift] ^= word << bit_shift;
+            if bit_shift > 0 {
+                self.words[i + word_shift + 1] ^= word >> (64 - bit_shift);
+            }
+        }
+
+        self.normalize();
+    }
+
+    fn flip(&mut self, i: usize) {
+        if self.words.len() <= i / 64 {
+            self.words.resize(i / 64 + 1, 0);
+        }
+
+        self.words[i / 64] ^= 1 << (i % 64);
+        self.normalize();
+    }
+
+    fn normalize(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    fn normalized(mut self) -> Self {
+        self.normalize();
+        self
+    }
+}
+
+/// Reduces the coefficients modulo 2
+///
+/// Returns an error if there is any negative exponent
+impl<T: MonomialValue> TryFrom<&Polynomial<T>> for Gf2Poly {
+    type Error = &'static str;
+
+    fn try_from(value: &Polynomial<T>) -> Result<Self, Self::Error> {
+        let two = T::one() + T::one();
+        let coeffs: Vec<bool> = value
+            .to_coeffs()?
+            .into_iter()
+            .map(|c| !(c % two).is_zero())
+            .collect();
+
+        Ok(Gf2Poly::from_coeffs(&coeffs))
+    }
+}
+
+impl Add for Gf2Poly {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.xor_shifted(&rhs, 0);
+        self
+    }
+}
+
+impl Mul for Gf2Poly {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut product = Gf2Poly::zero();
+        for i in (0..rhs.degree().map_or(0, |d| d + 1)).filter(|i| rhs.coeff(*i)) {
+            product.xor_shifted(&self, i);
+        }
+
+        product
+    }
+}
+
+impl Display for Gf2Poly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(degree) = self.degree() else {
+            return write!(f, "0");
+        };
+
+        let terms: Vec<String> = (0..=degree)
+            .rev()
+            .filter(|i| self.coeff(*i))
+            .map(|i| match i {
+                0 => "1".to_string(),
+                1 => "x".to_string(),
+                _ => format!("x^{i}"),
+            })
+            .collect();
+
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+/// Returns the CRC of `data`, the remainder of `M(x) x^r` divided by the `generator` of
+/// degree `r`, where `M(x)` is the bitstring of `data`. It's the plain polynomial CRC,
+/// without initial value, reflection or final xor
+///
+/// Returns an error if the generator is constant
+/// # Examples
+/// ```
+/// # use rust_polynomial::gf2::{self, Gf2Poly};
+/// // CRC-8/SMBUS
+/// let generator = Gf2Poly::from_u64(0x107);
+///
+/// assert_eq!(gf2::crc(b"123456789", &generator).unwrap().to_u64(), Some(0xF4));
+/// ```
+pub fn crc(data: &[u8], generator: &Gf2Poly) -> Result<Gf2Poly, &'static str> {
+    let degree = generator
+        .degree()
+        .filter(|d| *d > 0)
+        .ok_or("The generator must have positive degree")?;
+
+    // Shift register holding the remainder of the bits read so far
+    let mut register = Gf2Poly::zero();
+    for bit in bits_of(data).chain(std::iter::repeat_n(false, degree)) {
+        let mut next = Gf2Poly::zero();
+        next.xor_shifted(&register, 1);
+        if bit {
+            next.flip(0);
+        }
+        if next.coeff(degree) {
+            next.xor_shifted(generator, 0);
+        }
+
+        register = next;
+    }
+
+    Ok(register)
+}
+
+/// Returns the first `len` bits of the sequence of the LFSR with characteristic polynomial
+/// `feedback`, `x^k + c_(k-1) x^(k-1) + ... + c_0`, that satisfies
+/// `s_(n+k) = c_(k-1) s_(n+k-1) + ... + c_0 s_n`. The sequence starts with the `k` bits of
+/// `seed`, a primitive polynomial and a non zero seed give the maximal period `2^k - 1`
+///
+/// Returns an error if the feedback is constant or `seed` doesn't have `k` bits
+/// # Examples
+/// ```
+/// # use rust_polynomial::gf2::{self, Gf2Poly};
+/// let feedback = Gf2Poly::from_u64(0b1011);
+/// let bits = gf2::lfsr(&feedback, &[true, false, false], 10).unwrap();
+///
+/// // `s_(n+3) = s_(n+1) + s_n`, the seed comes back after 7 bits
+/// assert_eq!(bits[..7], [true, false, false, true, false, true, true]);
+/// assert_eq!(bits[7..], bits[..3]);
+/// ```
+pub fn lfsr(feedback: &Gf2Poly, seed: &[bool], len: usize) -> Result<Vec<bool>, &'static str> {
+    let degree = feedback
+        .degree()
+        .filter(|d| *d > 0)
+        .ok_or("The feedback must have positive degree")?;
+    if seed.len() != degree {
+        return Err("The seed must have as many bits as the degree");
+    }
+
+    let taps: Vec<usize> = (0..degree).filter(|i| feedback.coeff(*i)).collect();
+    let mut bits = seed.to_vec();
+    while bits.len() < len {
+        let n = bits.len() - degree;
+        let next = taps.iter().fold(false, |acc, i| acc ^ bits[n + i]);
+        bits.push(next);
+    }
+
+    bits.truncate(len);
+
+    Ok(bits)
+}
+
+/// Bits of `bytes`, the highest bit of every byte first
+fn bits_of(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+}
@@ -0,0 +1,371 @@
+//! Arithmetic in the extension fields [GF(2^m)](https://en.wikipedia.org/wiki/Finite_field_arithmetic)
+//! and the polynomial operations of [Reed–Solomon](https://en.wikipedia.org/wiki/Reed%E2%80%93Solomon_error_correction)
+//! codes and [Shamir's secret sharing](https://en.wikipedia.org/wiki/Shamir%27s_secret_sharing)
+//! over them
+//!
+//! Elements are the integers below `2^m`, the bits of a polynomial modulo a primitive
+//! [`Gf2Poly`] of degree `m`. Polynomials over the field are slices of elements in ascending
+//! order, like [`Polynomial::from_coeffs`](crate::Polynomial::from_coeffs).
+//! ```
+//! use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+//!
+//! // Field of QR codes
+//! let field = Gf2m::new(&Gf2Poly::from_u64(0x11D)).unwrap();
+//! let codeword = field.rs_encode(b"hello".map(u16::from).as_slice(), 4).unwrap();
+//!
+//! assert_eq!(field.syndromes(&codeword, 4), vec![0; 4]);
+//! ```
+
+use crate::gf2::Gf2Poly;
+
+/// Field GF(2^m) with exponential and logarithm tables of the generator `α = x`
+///
+/// The arithmetic methods expect elements, values below [`order`](Gf2m::order), and panic
+/// with anything else. [`interpolate`](Gf2m::interpolate) and the Reed–Solomon and secret
+/// sharing entry points return an error instead
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gf2m {
+    degree: usize,
+    exp: Vec<u16>,
+    log: Vec<u16>,
+}
+
+impl Gf2m {
+    /// Constructs the field of polynomials modulo `modulus`
+    ///
+    /// Returns an error if the degree isn't between 1 and 16, or `modulus` isn't primitive,
+    /// `x` must generate every non zero element
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+    /// assert!(Gf2m::new(&Gf2Poly::from_u64(0b1_0011)).is_ok());
+    ///
+    /// // x^4 + x^3 + x^2 + x + 1 is irreducible, but x^5 = 1
+    /// assert!(Gf2m::new(&Gf2Poly::from_u64(0b1_1111)).is_err());
+    /// ```
+    pub fn new(modulus: &Gf2Poly) -> Result<Gf2m, &'static str> {
+        let degree = modulus
+            .degree()
+            .filter(|d| (1..=16).contains(d))
+            .ok_or("The degree of the modulus must be between 1 and 16")?;
+        let bits = modulus.to_u64().ok_or("Not valid modulus")? as u32;
+
+        let order = 1usize << degree;
+        let mut exp = vec![0u16; order - 1];
+        let mut log = vec![0u16; order];
+        let mut value = 1u32;
+        for (i, slot) in exp.iter_mut().enumerate() {
+            if value == 1 && i > 0 {
+                return Err("The modulus must be primitive");
+            }
+
+            *slot = value as u16;
+            log[value as usize] = i as u16;
+            value <<= 1;
+            if value & (1 << degree) != 0 {
+                value ^= bits;
+            }
+        }
+
+        if value != 1 {
+            return Err("The modulus must be primitive");
+        }
+
+        Ok(Gf2m { degree, exp, log })
+    }
+
+    /// Returns `m`
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the number of elements, `2^m`
+    pub fn order(&self) -> usize {
+        1 << self.degree
+    }
+
+    /// Returns `α^i`, the generator to the power `i`
+    pub fn alpha_pow(&self, i: usize) -> u16 {
+        self.exp[i % self.exp.len()]
+    }
+
+    /// Returns `a + b`, also `a - b`
+    pub fn add(&self, a: u16, b: u16) -> u16 {
+        a ^ b
+    }
+
+    /// Returns `a b` through the logarithm tables
+    ///
+    /// # Panics
+    /// If `a` or `b` isn't an element
+    pub fn mul(&self, a: u16, b: u16) -> u16 {
+        let [log_a, log_b] = [a, b].map(|v| self.log(v));
+        if a == 0 || b == 0 {
+            return 0;
+        }
+
+        self.alpha_pow(log_a + log_b)
+    }
+
+    /// Returns the multiplicative inverse, or [`None`] for zero
+    ///
+    /// # Panics
+    /// If `a` isn't an element
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+    /// let field = Gf2m::new(&Gf2Poly::from_u64(0x11D)).unwrap();
+    ///
+    /// assert_eq!(field.mul(field.inv(29).unwrap(), 29), 1);
+    /// assert_eq!(field.inv(0), None);
+    /// ```
+    pub fn inv(&self, a: u16) -> Option<u16> {
+        let log = self.log(a);
+        match a {
+            0 => None,
+            _ => Some(self.alpha_pow(self.exp.len() - log)),
+        }
+    }
+
+    /// Returns `a / b`, or [`None`] if `b` is zero
+    ///
+    /// # Panics
+    /// If `a` or `b` isn't an element
+    pub fn div(&self, a: u16, b: u16) -> Option<u16> {
+        // Checked even when `b` is zero
+        self.log(a);
+        self.inv(b).map(|inv| self.mul(a, inv))
+    }
+
+    /// Returns `a^n`, `0^0` is `1`
+    ///
+    /// # Panics
+    /// If `a` isn't an element
+    pub fn pow(&self, a: u16, n: u64) -> u16 {
+        let log = self.log(a) as u64;
+        match (a, n) {
+            (_, 0) => 1,
+            (0, _) => 0,
+            _ => {
+                let log = log * (n % self.exp.len() as u64);
+                self.alpha_pow((log % self.exp.len() as u64) as usize)
+            }
+        }
+    }
+
+    /// Logarithm of `a` in base `α`, the table has `0` for zero
+    fn log(&self, a: u16) -> usize {
+        assert!(
+            (a as usize) < self.order(),
+            "{a} isn't an element of GF(2^{})",
+            self.degree
+        );
+
+        self.log[a as usize] as usize
+    }
+
+    /// Evaluates the polynomial `poly` at `x` by Horner's method
+    ///
+    /// # Panics
+    /// If `x` or a coefficient isn't an element
+    pub fn eval(&self, poly: &[u16], x: u16) -> u16 {
+        poly.iter()
+            .rev()
+            .fold(0, |acc, c| self.add(self.mul(acc, x), *c))
+    }
+
+    /// Returns the product of two polynomials
+    pub fn poly_mul(&self, lhs: &[u16], rhs: &[u16]) -> Vec<u16> {
+        if lhs.is_empty() || rhs.is_empty() {
+            return Vec::new();
+        }
+
+        let mut product = vec![0u16; lhs.len() + rhs.len() - 1];
+        for (i, a) in lhs.iter().enumerate() {
+            for (j, b) in rhs.iter().enumerate() {
+                product[i + j] ^= self.mul(*a, *b);
+            }
+        }
+
+        product
+    }
+
+    /// Returns the remainder of the division of `lhs` by `rhs`, with length `rhs.len() - 1`
+    ///
+    /// Returns an error if the leading coefficient of `rhs` is zero
+    pub fn poly_rem(&self, lhs: &[u16], rhs: &[u16]) -> Result<Vec<u16>, &'static str> {
+        let lead_inv = rhs
+            .last()
+            .and_then(|lead| self.inv(*lead))
+            .ok_or("The leading coefficient of the divider can't be zero")?;
+
+        let mut rem = lhs.to_vec();
+        while rem.len() >= rhs.len() {
+            let factor = self.mul(rem[rem.len() - 1], lead_inv);
+            let shift = rem.len() - rhs.len();
+            for (i, c) in rhs.iter().enumerate() {
+                rem[shift + i] ^= self.mul(factor, *c);
+            }
+            rem.pop();
+        }
+
+        rem.resize(rhs.len() - 1, 0);
+
+        Ok(rem)
+    }
+
+    /// Returns the polynomial of degree less than `points.len()` through `points`, by
+    /// Lagrange interpolation
+    ///
+    /// Returns an error if a coordinate isn't an element or two points share the same `x`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+    /// let field = Gf2m::new(&Gf2Poly::from_u64(0b1011)).unwrap();
+    /// let poly = [3, 0, 5];
+    /// let points: Vec<(u16, u16)> = [1, 2, 6].iter().map(|x| (*x, field.eval(&poly, *x))).collect();
+    ///
+    /// assert_eq!(field.interpolate(&points), Ok(poly.to_vec()));
+    /// ```
+    pub fn interpolate(&self, points: &[(u16, u16)]) -> Result<Vec<u16>, &'static str> {
+        let outside = |v: &u16| *v as usize >= self.order();
+        if points.iter().any(|(x, y)| outside(x) || outside(y)) {
+            return Err("Every coordinate must be an element of the field");
+        }
+
+        let mut result = vec![0u16; points.len()];
+
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            // Basis polynomial `Π (x - x_j) / (x_i - x_j)` for every `j != i`
+            let mut basis = vec![1u16];
+            let mut denom = 1u16;
+            for (_, (xj, _)) in points.iter().enumerate().filter(|(j, _)| *j != i) {
+                basis = self.poly_mul(&basis, &[*xj, 1]);
+                denom = self.mul(denom, self.add(*xi, *xj));
+            }
+
+            let scale = self
+                .div(*yi, denom)
+                .ok_or("Every point must have a different x")?;
+            for (r, b) in result.iter_mut().zip(basis) {
+                *r ^= self.mul(scale, b);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the Reed–Solomon generator polynomial `Π (x - α^i)` for `i` below `nsym`, the
+    /// number of parity symbols
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+    /// let field = Gf2m::new(&Gf2Poly::from_u64(0x11D)).unwrap();
+    ///
+    /// assert_eq!(field.rs_generator(4), vec![64, 120, 54, 15, 1]);
+    /// ```
+    pub fn rs_generator(&self, nsym: usize) -> Vec<u16> {
+        (0..nsym).fold(vec![1u16], |acc, i| {
+            self.poly_mul(&acc, &[self.alpha_pow(i), 1])
+        })
+    }
+
+    /// Returns the systematic codeword of `message`, `message(x) x^nsym` plus the remainder
+    /// of its division by the [`rs_generator`](Gf2m::rs_generator), so the first `nsym`
+    /// symbols are the parity and the rest the message itself
+    ///
+    /// Returns an error if any symbol isn't an element or the codeword is longer than
+    /// `2^m - 1`
+    pub fn rs_encode(&self, message: &[u16], nsym: usize) -> Result<Vec<u16>, &'static str> {
+        if message.len() + nsym > self.exp.len() {
+            return Err("The codeword can't be longer than the number of non zero elements");
+        }
+
+        if message.iter().any(|s| *s as usize >= self.order()) {
+            return Err("Every symbol must be an element of the field");
+        }
+
+        let shifted = [vec![0u16; nsym], message.to_vec()].concat();
+        let parity = self.poly_rem(&shifted, &self.rs_generator(nsym))?;
+
+        Ok([parity, message.to_vec()].concat())
+    }
+
+    /// Returns the syndromes `c(α^i)` for `i` below `nsym`, all zero for a valid codeword
+    pub fn syndromes(&self, codeword: &[u16], nsym: usize) -> Vec<u16> {
+        (0..nsym)
+            .map(|i| self.eval(codeword, self.alpha_pow(i)))
+            .collect()
+    }
+
+    /// Splits `secret` in `n` shares with [Shamir's scheme](https://en.wikipedia.org/wiki/Shamir%27s_secret_sharing),
+    /// any `threshold` of them recover it and fewer reveal nothing. The shares are the points
+    /// `x = 1, ..., n` of a polynomial with `secret` as constant term and the other
+    /// `threshold - 1` coefficients taken from `rng`, reduced to elements of the field
+    ///
+    /// Returns an error if `threshold` is zero or greater than `n`, `n` isn't less than the
+    /// order or `secret` isn't an element
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+    /// let field = Gf2m::new(&Gf2Poly::from_u64(0x11D)).unwrap();
+    /// let mut seed = 7u16;
+    /// let rng = || {
+    ///     seed = seed.wrapping_mul(31).wrapping_add(11);
+    ///     seed
+    /// };
+    ///
+    /// let shares = field.share_secret(42, 3, 5, rng).unwrap();
+    /// assert_eq!(field.reconstruct(&shares[1..4]), Ok(42));
+    /// ```
+    pub fn share_secret<R: FnMut() -> u16>(
+        &self,
+        secret: u16,
+        threshold: usize,
+        n: usize,
+        mut rng: R,
+    ) -> Result<Vec<(u16, u16)>, &'static str> {
+        if threshold == 0 || threshold > n {
+            return Err("The threshold must be between 1 and the number of shares");
+        }
+
+        if n >= self.order() {
+            return Err("There must be less shares than elements");
+        }
+
+        if secret as usize >= self.order() {
+            return Err("The secret must be an element of the field");
+        }
+
+        let mask = (self.order() - 1) as u16;
+        let mut poly = vec![secret];
+        poly.extend((1..threshold).map(|_| rng() & mask));
+
+        Ok((1..=n as u16).map(|x| (x, self.eval(&poly, x))).collect())
+    }
+
+    /// Recovers the secret from the shares of [`share_secret`](Gf2m::share_secret), the value
+    /// at zero of the polynomial through them. With less shares than the threshold the result
+    /// is unrelated to the secret
+    ///
+    /// Returns an error if there isn't any share, a share isn't a pair of elements, a share is
+    /// at `x = 0`, where the secret is, or two share the same `x`
+    pub fn reconstruct(&self, shares: &[(u16, u16)]) -> Result<u16, &'static str> {
+        if shares.is_empty() {
+            return Err("At least one share is needed");
+        }
+
+        if shares
+            .iter()
+            .any(|&(x, y)| x as usize >= self.order() || y as usize >= self.order())
+        {
+            return Err("Every share must be a pair of elements of the field");
+        }
+
+        if shares.iter().any(|&(x, _)| x == 0) {
+            return Err("No share can be at zero");
+        }
+
+        Ok(self.interpolate(shares)?[0])
+    }
+}
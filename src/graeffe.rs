@@ -0,0 +1,99 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns one step of the
+    /// [Graeffe iteration](https://en.wikipedia.org/wiki/Graeffe%27s_method), the polynomial
+    /// `q` with `q(x^2) = (-1)^n p(x) p(-x)`, whose roots are the squares of the roots of `p`
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 + x - 2").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.graeffe_step().unwrap()), "x^2 - 5x + 4");
+    /// ```
+    pub fn graeffe_step(&self) -> Result<Self, &'static str> {
+        let coeffs = self.to_coeffs()?;
+
+        Ok(Polynomial::from_coeffs(&graeffe(&coeffs)).with_var(self.get_var()))
+    }
+
+    /// Estimates the absolute value of every complex root with `steps` Graeffe iterations,
+    /// in ascending order and repeated by multiplicity. After `k` steps the roots are raised
+    /// to `2^k`, so roots of different magnitude separate and the ratio of consecutive
+    /// coefficients approaches their powers. Roots of equal magnitude, like a complex
+    /// conjugate pair, don't separate and only the product of their estimates is right
+    ///
+    /// The coefficients are computed in [`f64`] and scaled on every step. Returns [`None`] if
+    /// the polynomial is constant, there is any negative exponent, a coefficient can't be
+    /// converted to [`f64`], or a coefficient vanishes along the iteration
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 13x^2 + 32x - 20").unwrap();
+    /// let magnitudes = poly.graeffe_root_magnitudes(6).unwrap();
+    ///
+    /// for (estimate, root) in magnitudes.iter().zip([1.0, 2.0, 10.0]) {
+    ///     assert!((estimate - root).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn graeffe_root_magnitudes(&self, steps: u32) -> Option<Vec<f64>> {
+        let mut coeffs = self.to_f64_coeffs()?;
+        if coeffs.len() < 2 {
+            return None;
+        }
+
+        // Roots at zero are exact
+        let zeros = coeffs.iter().take_while(|c| **c == 0f64).count();
+        coeffs.drain(..zeros);
+
+        for _ in 0..steps {
+            coeffs = graeffe(&coeffs);
+            let scale = coeffs.iter().fold(0f64, |acc, c| acc.max(c.abs()));
+            coeffs.iter_mut().for_each(|c| *c /= scale);
+        }
+
+        let power = 2f64.powi(steps as i32);
+        let mut magnitudes = vec![0f64; zeros];
+        for pair in coeffs.windows(2) {
+            let ratio = (pair[0] / pair[1]).abs();
+            if ratio == 0f64 || !ratio.is_finite() {
+                return None;
+            }
+
+            magnitudes.push(ratio.powf(1f64 / power));
+        }
+        magnitudes[zeros..].sort_by(f64::total_cmp);
+
+        Some(magnitudes)
+    }
+}
+
+/// Coefficients of the Graeffe step in ascending order, `d_m = sum (-1)^j c_i c_j` over
+/// `i + j = 2m`, with the sign that makes the leading one positive
+fn graeffe<T: MonomialValue>(coeffs: &[T]) -> Vec<T> {
+    let Some(n) = coeffs.len().checked_sub(1) else {
+        return Vec::new();
+    };
+
+    (0..=n)
+        .map(|m| {
+            let sum =
+                (0..=2 * m)
+                    .filter(|i| *i <= n && 2 * m - i <= n)
+                    .fold(T::zero(), |acc, i| {
+                        let j = 2 * m - i;
+                        match j % 2 {
+                            0 => acc + coeffs[i] * coeffs[j],
+                            _ => acc - coeffs[i] * coeffs[j],
+                        }
+                    });
+
+            match n % 2 {
+                0 => sum,
+                _ => -sum,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,193 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Parameters of the 64 bits [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hash
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Tags of the encoding of a coefficient
+const INTEGER_TAG: u8 = 0;
+const FLOAT_TAG: u8 = 1;
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns a 64 bits hash of the terms that is stable across platforms, processes and
+    /// versions of the crate, to use as a cache key. Equal polynomials have equal hashes, the
+    /// variable is ignored like in `==`
+    ///
+    /// Coefficients with an integer value are hashed as that integer, whatever their type, so
+    /// `3x` has the same hash with `i32` and `f64` coefficients. Other floats are hashed by
+    /// their bits, with every `NaN` collapsed to the same one
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let a: Polynomial<i32> = Polynomial::try_from("x^2 + 3x - 1").unwrap();
+    /// let b: Polynomial<f64> = Polynomial::try_from("3x + x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// assert_ne!(a.content_hash(), Polynomial::<i32>::try_from("x^2 + 3x").unwrap().content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash = (hash ^ *byte as u64).wrapping_mul(FNV_PRIME);
+            }
+        };
+
+        for mono in self {
+            let value = mono.get_value();
+            if value.is_zero() {
+                continue;
+            }
+
+            write(&mono.get_exp().to_le_bytes());
+            match value.to_i128().filter(|i| T::from(*i) == Some(value)) {
+                Some(int) => {
+                    write(&[INTEGER_TAG]);
+                    write(&int.to_le_bytes());
+                }
+                None => {
+                    let float = value.to_f64().unwrap_or(f64::NAN);
+                    let bits = match float.is_nan() {
+                        true => f64::NAN.to_bits(),
+                        false => float.to_bits(),
+                    };
+
+                    write(&[FLOAT_TAG]);
+                    write(&bits.to_le_bytes());
+                }
+            }
+        }
+
+        hash
+    }
+}
+
+/// The Mersenne prime `2^61 - 1`, the field of [`Polynomial::probably_equal`]
+pub(crate) const PRIME: u64 = (1 << 61) - 1;
+
+/// Points compared by [`Polynomial::probably_equal`]
+const TRIALS: usize = 2;
+
+/// Seed of the points of [`Polynomial::probably_equal`]
+pub(crate) const SEED: u64 = FNV_OFFSET;
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns `true` if both polynomials are probably equal, comparing them at pseudo-random
+    /// points of the field of integers modulo the prime `2^61 - 1`
+    /// ([Schwartz–Zippel lemma](https://en.wikipedia.org/wiki/Schwartz%E2%80%93Zippel_lemma)).
+    /// Equal polynomials always return `true`. The points come from a fixed seed, so the
+    /// answer is deterministic and there is no probability over them: it's wrong for the
+    /// different polynomials whose difference vanishes at those points, which can be built on
+    /// purpose. Use [`probably_equal_with_seed`](Polynomial::probably_equal_with_seed) with a
+    /// random seed if the inputs aren't trusted
+    ///
+    /// Every finite float is a fraction with a power of two denominator, so it's mapped to
+    /// the field exactly, and other coefficients through their integer or [`f64`] value. If
+    /// a coefficient is infinite or `NaN` the terms are compared with `==`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<f64> = Polynomial::try_from("x - 0.5").unwrap();
+    /// let q: Polynomial<f64> = Polynomial::try_from("x + 3").unwrap();
+    /// let expanded: Polynomial<f64> = Polynomial::try_from("x^2 + 2.5x - 1.5").unwrap();
+    ///
+    /// assert!((p.clone() * q.clone()).probably_equal(&expanded));
+    /// assert!(!(p * q).probably_equal(&Polynomial::try_from("x^2 + 2.5x - 1.4").unwrap()));
+    /// ```
+    pub fn probably_equal(&self, other: &Self) -> bool {
+        self.probably_equal_with_seed(other, SEED)
+    }
+
+    /// Same as [`probably_equal`](Polynomial::probably_equal) with the points generated from
+    /// `seed`. For a seed chosen at random, independently of the polynomials, different ones
+    /// return `true` with probability at most `(d / (2^61 - 3))^2`, where `d` is the distance
+    /// between the highest and lowest exponent of the terms
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i64> = Polynomial::try_from("x^2 - 1").unwrap();
+    /// let q: Polynomial<i64> = Polynomial::try_from("x + 1").unwrap();
+    /// let r: Polynomial<i64> = Polynomial::try_from("x - 1").unwrap();
+    ///
+    /// assert!(p.probably_equal_with_seed(&(q.clone() * r), 7));
+    /// assert!(!p.probably_equal_with_seed(&(q.clone() * q), 7));
+    /// ```
+    pub fn probably_equal_with_seed(&self, other: &Self, seed: u64) -> bool {
+        probe_points(seed).all(|point| {
+            match (self.eval_mod_prime(point), other.eval_mod_prime(point)) {
+                (Some(a), Some(b)) => a == b,
+                _ => self == other,
+            }
+        })
+    }
+
+    /// Value at `point` modulo [`PRIME`], [`None`] if a coefficient has no residue
+    pub(crate) fn eval_mod_prime(&self, point: u64) -> Option<u64> {
+        let inverse = pow_mod(point, PRIME - 2);
+
+        self.into_iter().try_fold(0u64, |acc, mono| {
+            let power = match mono.get_exp() {
+                exp if exp < 0 => pow_mod(inverse, exp.unsigned_abs() as u64),
+                exp => pow_mod(point, exp as u64),
+            };
+
+            Some((acc + mul_mod(residue(mono.get_value())?, power)) % PRIME)
+        })
+    }
+}
+
+/// Residue of a coefficient modulo [`PRIME`], exact for integers and finite floats
+fn residue<T: MonomialValue>(value: T) -> Option<u64> {
+    if let Some(int) = value.to_i128().filter(|i| T::from(*i) == Some(value)) {
+        return Some(int.rem_euclid(PRIME as i128) as u64);
+    }
+
+    let float = value.to_f64().filter(|f| f.is_finite())?;
+    let bits = float.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+
+    // `float = mantissa 2^exp`, and `2^61 = 1` in the field
+    let (mantissa, exp) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), biased - 1075),
+    };
+    let value = mul_mod(mantissa % PRIME, 1 << exp.rem_euclid(61));
+
+    Some(match float < 0f64 {
+        true => (PRIME - value) % PRIME,
+        false => value,
+    })
+}
+
+/// The pseudo-random points compared by [`Polynomial::probably_equal_with_seed`]
+pub(crate) fn probe_points(seed: u64) -> impl Iterator<Item = u64> {
+    let mut state = seed;
+    (0..TRIALS).map(move |_| 2 + splitmix64(&mut state) % (PRIME - 2))
+}
+
+pub(crate) fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % PRIME as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base);
+        }
+        base = mul_mod(base, base);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Next value of the [SplitMix64](https://prng.di.unimi.it/splitmix64.c) generator
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
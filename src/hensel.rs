@@ -0,0 +1,186 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// [Hensel lifting](https://en.wikipedia.org/wiki/Hensel%27s_lemma) of a factorization
+    /// modulo the prime `p` to modulo `p^k`. Given `self ≡ g h (mod p)` with `g` monic and
+    /// coprime to `h` modulo `p`, returns `g'` and `h'` with `self ≡ g' h' (mod p^k)`, `g'`
+    /// monic of the same degree and both congruent to the originals modulo `p`. The
+    /// coefficients are reduced to `[0, p^k)`, one power of `p` is gained per step
+    ///
+    /// Returns an error if `p` isn't prime, `k` is zero, `g` isn't monic modulo `p`, the
+    /// factorization doesn't hold modulo `p`, the factors aren't coprime, there are negative
+    /// exponents or `p^k` doesn't fit in `T`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // x^2 + 1 ≡ (x + 3)(x + 2) modulo 5
+    /// let f: Polynomial<i64> = Polynomial::try_from("x^2 + 1").unwrap();
+    /// let g: Polynomial<i64> = Polynomial::try_from("x + 3").unwrap();
+    /// let h: Polynomial<i64> = Polynomial::try_from("x + 2").unwrap();
+    ///
+    /// // 182 is a square root of -1 modulo 625
+    /// let (g, h) = f.hensel_lift(&g, &h, 5, 4).unwrap();
+    /// assert_eq!(format!("{g}"), "x + 443");
+    /// assert_eq!(format!("{h}"), "x + 182");
+    /// ```
+    pub fn hensel_lift(
+        &self,
+        g: &Self,
+        h: &Self,
+        p: T,
+        k: u32,
+    ) -> Result<(Self, Self), &'static str> {
+        let p = p
+            .to_i128()
+            .filter(|p| is_prime(*p))
+            .ok_or("The modulus must be a prime")?;
+        if k == 0 {
+            return Err("The exponent must be positive");
+        }
+
+        let modulus = p.checked_pow(k).ok_or("The lifted modulus overflows")?;
+        T::from(modulus).ok_or("The lifted modulus overflows")?;
+
+        let [f, g, h] = [self, g, h].map(to_i128);
+        let (f, mut g, mut h) = (f?, reduce(g?, p), reduce(h?, p));
+
+        if g.last() != Some(&1) {
+            return Err("The first factor must be monic");
+        }
+
+        if !reduce(sub(&f, &mul(&g, &h, p)), p).is_empty() {
+            return Err("The factorization doesn't hold modulo p");
+        }
+
+        let (s, t) = ext_gcd(&g, &h, p).ok_or("The factors must be coprime modulo p")?;
+
+        let mut power = p;
+        for _ in 1..k {
+            // Error of the current factorization, divisible by `power`
+            let next = power * p;
+            let error = reduce(sub(&f, &mul(&g, &h, next)), next);
+            let error: Vec<i128> = error.iter().map(|c| c / power).collect();
+
+            // `g δh + h δg ≡ error (mod p)` with `deg δg < deg g`
+            let (q, delta_g) = div_rem(&mul(&t, &error, p), &g, p);
+            let delta_h = reduce(add(&mul(&s, &error, p), &mul(&q, &h, p)), p);
+
+            g = reduce(add(&g, &scale(&delta_g, power)), next);
+            h = reduce(add(&h, &scale(&delta_h, power)), next);
+            power = next;
+        }
+
+        let [g, h] = [g, h].map(|coeffs| {
+            coeffs
+                .into_iter()
+                .map(|c| T::from(c).ok_or("Not valid coefficient"))
+                .collect::<Result<Vec<T>, &'static str>>()
+        });
+
+        Ok((Polynomial::from_coeffs(&g?), Polynomial::from_coeffs(&h?)))
+    }
+}
+
+fn is_prime(n: i128) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+fn to_i128<T: MonomialValue>(poly: &Polynomial<T>) -> Result<Vec<i128>, &'static str> {
+    poly.to_coeffs()?
+        .into_iter()
+        .map(|c| c.to_i128().ok_or("Not valid coefficient"))
+        .collect()
+}
+
+/// Coefficients in `[0, modulus)` without leading zeros
+fn reduce(mut coeffs: Vec<i128>, modulus: i128) -> Vec<i128> {
+    coeffs.iter_mut().for_each(|c| *c = c.rem_euclid(modulus));
+    while coeffs.last() == Some(&0) {
+        coeffs.pop();
+    }
+
+    coeffs
+}
+
+fn add(lhs: &[i128], rhs: &[i128]) -> Vec<i128> {
+    (0..lhs.len().max(rhs.len()))
+        .map(|i| lhs.get(i).unwrap_or(&0) + rhs.get(i).unwrap_or(&0))
+        .collect()
+}
+
+fn sub(lhs: &[i128], rhs: &[i128]) -> Vec<i128> {
+    add(lhs, &scale(rhs, -1))
+}
+
+fn scale(coeffs: &[i128], factor: i128) -> Vec<i128> {
+    coeffs.iter().map(|c| c * factor).collect()
+}
+
+/// Product modulo `modulus`, every term is reduced so the sums don't overflow
+fn mul(lhs: &[i128], rhs: &[i128], modulus: i128) -> Vec<i128> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![0; lhs.len() + rhs.len() - 1];
+    for (i, a) in lhs.iter().enumerate() {
+        for (j, b) in rhs.iter().enumerate() {
+            product[i + j] = (product[i + j] + a * b).rem_euclid(modulus);
+        }
+    }
+
+    reduce(product, modulus)
+}
+
+/// Inverse modulo `p` by the extended Euclidean algorithm
+fn inv_mod(a: i128, p: i128) -> Option<i128> {
+    let (mut r0, mut r1) = (a.rem_euclid(p), p);
+    let (mut s0, mut s1) = (1i128, 0i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+
+    (r0 == 1).then(|| s0.rem_euclid(p))
+}
+
+/// Quotient and remainder modulo the prime `p`, `rhs` is reduced and not zero
+fn div_rem(lhs: &[i128], rhs: &[i128], p: i128) -> (Vec<i128>, Vec<i128>) {
+    let lead_inv = inv_mod(rhs[rhs.len() - 1], p).unwrap_or(0);
+    let mut rem = reduce(lhs.to_vec(), p);
+    let mut quotient = vec![0; rem.len().saturating_sub(rhs.len()) + 1];
+
+    while rem.len() >= rhs.len() {
+        let factor = (rem[rem.len() - 1] * lead_inv).rem_euclid(p);
+        let shift = rem.len() - rhs.len();
+        quotient[shift] = factor;
+        for (i, c) in rhs.iter().enumerate() {
+            rem[shift + i] -= factor * c;
+        }
+        rem = reduce(rem, p);
+    }
+
+    (reduce(quotient, p), rem)
+}
+
+/// Returns `s` and `t` with `s a + t b ≡ 1 (mod p)`, or [`None`] if they aren't coprime
+fn ext_gcd(a: &[i128], b: &[i128], p: i128) -> Option<(Vec<i128>, Vec<i128>)> {
+    let (mut r0, mut r1) = (a.to_vec(), b.to_vec());
+    let (mut s0, mut s1) = (vec![1], Vec::new());
+    let (mut t0, mut t1) = (Vec::new(), vec![1]);
+
+    while !r1.is_empty() {
+        let (q, r) = div_rem(&r0, &r1, p);
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1.clone(), reduce(sub(&s0, &mul(&q, &s1, p)), p));
+        (t0, t1) = (t1.clone(), reduce(sub(&t0, &mul(&q, &t1, p)), p));
+    }
+
+    if r0.len() != 1 {
+        return None;
+    }
+
+    let inv = inv_mod(r0[0], p)?;
+    Some((reduce(scale(&s0, inv), p), reduce(scale(&t0, inv), p)))
+}
@@ -0,0 +1,49 @@
+//! Conversions with the [`polynomial`](https://docs.rs/polynomial) crate, enabled with the
+//! `polynomial` feature
+
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Constructs a polynomial from the ascending coefficients of a `polynomial::Polynomial`
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let other = polynomial::Polynomial::new(vec![-1, 0, 2]);
+/// let poly: Polynomial<i32> = Polynomial::from(other);
+///
+/// assert_eq!(format!("{poly}"), "2x^2 - 1");
+/// ```
+impl<T: MonomialValue> From<polynomial::Polynomial<T>> for Polynomial<T> {
+    fn from(other: polynomial::Polynomial<T>) -> Self {
+        Polynomial::from_coeffs(other.data())
+    }
+}
+
+/// Constructs a `polynomial::Polynomial` from the ascending coefficients
+///
+/// Returns an error if there is any negative exponent, like
+/// [`to_coeffs`](Polynomial::to_coeffs)
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let poly: Polynomial<i32> = Polynomial::try_from("2x^2 - 1").unwrap();
+/// let other = polynomial::Polynomial::try_from(&poly).unwrap();
+///
+/// assert_eq!(other.data(), &[-1, 0, 2]);
+/// assert!(polynomial::Polynomial::try_from(&Polynomial::<i32>::try_from("x^-1").unwrap()).is_err());
+/// ```
+impl<T: MonomialValue> TryFrom<&Polynomial<T>> for polynomial::Polynomial<T> {
+    type Error = &'static str;
+
+    fn try_from(poly: &Polynomial<T>) -> Result<Self, Self::Error> {
+        Ok(polynomial::Polynomial::new(poly.to_coeffs()?))
+    }
+}
+
+/// Same as the conversion from a reference
+impl<T: MonomialValue> TryFrom<Polynomial<T>> for polynomial::Polynomial<T> {
+    type Error = &'static str;
+
+    fn try_from(poly: Polynomial<T>) -> Result<Self, Self::Error> {
+        polynomial::Polynomial::try_from(&poly)
+    }
+}
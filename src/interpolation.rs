@@ -0,0 +1,55 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the [Hermite interpolant](https://en.wikipedia.org/wiki/Hermite_interpolation)
+    /// of the points `(x, value, derivative)`: the polynomial of degree less than `2n` matching
+    /// both the value and the first derivative at the `n` nodes. It's computed in Newton form
+    /// from the divided differences with every node repeated, the derivative standing in for
+    /// the difference of a repeated node. Integer coefficients are truncated by the divisions
+    ///
+    /// Returns an error if there isn't any point or two nodes are equal
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // From rest at 0 to rest at 1
+    /// let poly: Polynomial<f64> =
+    ///     Polynomial::hermite_interpolate(&[(0.0, 0.0, 0.0), (1.0, 1.0, 0.0)]).unwrap();
+    ///
+    /// assert_eq!(format!("{poly}"), "-2x^3 + 3x^2");
+    /// ```
+    pub fn hermite_interpolate(points: &[(T, T, T)]) -> Result<Self, &'static str> {
+        if points.is_empty() {
+            return Err("At least one point is needed");
+        }
+
+        for (i, (x, _, _)) in points.iter().enumerate() {
+            if points[..i].iter().any(|p| p.0 == *x) {
+                return Err("Nodes must be distinct");
+            }
+        }
+
+        let nodes: Vec<T> = points.iter().flat_map(|p| [p.0, p.0]).collect();
+        let mut table: Vec<T> = points.iter().flat_map(|p| [p.1, p.1]).collect();
+        let mut coeffs = vec![table[0]];
+
+        // Column `k` of the divided differences, overwritten in place from the bottom
+        for k in 1..nodes.len() {
+            for i in (k..nodes.len()).rev() {
+                table[i] = match k == 1 && i % 2 == 1 {
+                    true => points[i / 2].2,
+                    false => (table[i] - table[i - 1]) / (nodes[i] - nodes[i - k]),
+                };
+            }
+            coeffs.push(table[k]);
+        }
+
+        let poly = nodes.iter().zip(&coeffs).rev().skip(1).fold(
+            Polynomial::constant(coeffs[coeffs.len() - 1]),
+            |acc, (x, c)| {
+                acc * Polynomial::from_coeffs(&[-*x, T::one()]) + Polynomial::constant(*c)
+            },
+        );
+
+        Ok(poly)
+    }
+}
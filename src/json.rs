@@ -0,0 +1,133 @@
+//! JSON wire format, enabled with the `json` feature
+//!
+//! Schema of version `1`, coefficients are written as JSON numbers so the format doesn't
+//! depend on the coefficient type:
+//! ```json
+//! {
+//!   "version": 1,
+//!   "polynomial": {
+//!     "variable": "x",
+//!     "terms": [{ "coeff": 3, "exp": 2 }, { "coeff": -1.5, "exp": 0 }]
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Current version of the JSON schema
+pub const JSON_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    polynomial: Wire,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Wire {
+    variable: String,
+    terms: Vec<Term>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Term {
+    coeff: Number,
+    exp: i32,
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the polynomial serialized with the versioned JSON schema
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(
+    ///     poly.to_json().unwrap(),
+    ///     r#"{"version":1,"polynomial":{"variable":"x","terms":[{"coeff":3,"exp":2},{"coeff":-1,"exp":0}]}}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> Result<String, &'static str> {
+        let terms = self
+            .into_iter()
+            .map(|m| {
+                let value = m.get_value();
+                let coeff = match value.to_i64() {
+                    Some(v) if T::from(v) == Some(value) => Number::from(v),
+                    _ => value
+                        .to_f64()
+                        .and_then(Number::from_f64)
+                        .ok_or("Not valid JSON number")?,
+                };
+
+                Ok(Term {
+                    coeff,
+                    exp: m.get_exp(),
+                })
+            })
+            .collect::<Result<Vec<Term>, &'static str>>()?;
+
+        let envelope = Envelope {
+            version: JSON_VERSION,
+            polynomial: Wire {
+                variable: self.get_var().to_string(),
+                terms,
+            },
+        };
+
+        serde_json::to_string(&envelope).map_err(|_| "Not serializable polynomial")
+    }
+
+    /// Parse a polynomial serialized with the versioned JSON schema
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let json = r#"{"version":1,"polynomial":{"variable":"t","terms":[{"coeff":2.5,"exp":1}]}}"#;
+    /// let poly: Polynomial<f64> = Polynomial::from_json(json).unwrap();
+    ///
+    /// assert_eq!(format!("{poly}"), "2.5t");
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, &'static str> {
+        let envelope: Envelope = serde_json::from_str(json).map_err(|_| "Not valid JSON")?;
+
+        if envelope.version != JSON_VERSION {
+            return Err("Unsupported JSON version");
+        }
+
+        let mut chars = envelope.polynomial.variable.chars();
+        let var = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err("Not valid variable"),
+        };
+
+        let mono_vec = envelope
+            .polynomial
+            .terms
+            .into_iter()
+            .map(|term| {
+                let value = match term.coeff.as_i64() {
+                    Some(v) => T::from(v),
+                    None => term
+                        .coeff
+                        .as_f64()
+                        .and_then(|v| T::from(v).filter(|t| !is_truncated(v, *t))),
+                };
+
+                Ok(Monomial::new(
+                    value.ok_or("Not valid coefficient")?,
+                    term.exp,
+                ))
+            })
+            .collect::<Result<Vec<Monomial<T>>, &'static str>>()?;
+
+        Ok(Polynomial::new(mono_vec).with_var(var))
+    }
+}
+
+/// Check if an integer coefficient type dropped the fractional part of `value`
+fn is_truncated<T: MonomialValue>(value: f64, coeff: T) -> bool {
+    value.fract() != 0f64 && coeff.to_f64().is_some_and(|c| c.fract() == 0f64)
+}
@@ -0,0 +1,163 @@
+//! Algebraic laws the operations of [`Polynomial`] must satisfy, to validate custom
+//! coefficient types against the expectations of the crate
+//!
+//! Every check takes the polynomials to test, so it can be driven by any property-based
+//! testing crate, and returns the name of the first law that doesn't hold. [`samples`] offers
+//! a fixed set of small polynomials for a quick check
+//! ```
+//! # use rust_polynomial::laws;
+//! let samples = laws::samples::<i64>();
+//!
+//! assert_eq!(laws::check_samples(&samples), Ok(()));
+//! ```
+
+use std::ops::Neg;
+
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Result of a check, the error is the name of the law that doesn't hold
+pub type LawResult = Result<(), &'static str>;
+
+/// Checks that addition is associative and commutative, with zero as identity and the
+/// negation as inverse
+pub fn additive_laws<T: MonomialValue>(
+    p: &Polynomial<T>,
+    q: &Polynomial<T>,
+    r: &Polynomial<T>,
+) -> LawResult {
+    let zero = Polynomial::zero();
+
+    law(
+        (p.clone() + q.clone()) + r.clone() == p.clone() + (q.clone() + r.clone()),
+        "addition is associative",
+    )?;
+    law(
+        p.clone() + q.clone() == q.clone() + p.clone(),
+        "addition is commutative",
+    )?;
+    law(
+        p.clone() + zero.clone() == *p,
+        "zero is the additive identity",
+    )?;
+    law(
+        p.clone() + p.clone().neg() == zero,
+        "the negation is the additive inverse",
+    )
+}
+
+/// Checks that multiplication is associative, commutative and distributive over addition,
+/// with one as identity
+pub fn multiplicative_laws<T: MonomialValue>(
+    p: &Polynomial<T>,
+    q: &Polynomial<T>,
+    r: &Polynomial<T>,
+) -> LawResult {
+    let one = Polynomial::from_coeffs(&[T::one()]);
+
+    law(
+        (p.clone() * q.clone()) * r.clone() == p.clone() * (q.clone() * r.clone()),
+        "multiplication is associative",
+    )?;
+    law(
+        p.clone() * q.clone() == q.clone() * p.clone(),
+        "multiplication is commutative",
+    )?;
+    law(p.clone() * one == *p, "one is the multiplicative identity")?;
+    law(
+        p.clone() * (q.clone() + r.clone()) == p.clone() * q.clone() + p.clone() * r.clone(),
+        "multiplication distributes over addition",
+    )
+}
+
+/// Checks that `(p q) / q == (p, 0)`, skipped if `q` is zero
+pub fn division_laws<T: MonomialValue>(p: &Polynomial<T>, q: &Polynomial<T>) -> LawResult {
+    if q.is_zero() {
+        return Ok(());
+    }
+
+    law(
+        (p.clone() * q.clone()) / q.clone() == (p.clone(), Polynomial::zero()),
+        "exact division returns the factor and no remainder",
+    )
+}
+
+/// Checks that the derivative is linear and follows the product rule, and that it undoes
+/// the integral, the last one only if the coefficients have exact division, like floats with
+/// small integer values, and there is no `x^-1` term
+pub fn calculus_laws<T: MonomialValue>(p: &Polynomial<T>, q: &Polynomial<T>) -> LawResult {
+    law(
+        (p.clone() + q.clone()).derivative() == p.derivative() + q.derivative(),
+        "the derivative is linear",
+    )?;
+    law(
+        (p.clone() * q.clone()).derivative()
+            == p.derivative() * q.clone() + p.clone() * q.derivative(),
+        "the derivative follows the product rule",
+    )?;
+
+    let exact = (T::one() / (T::one() + T::one())) * (T::one() + T::one()) == T::one();
+    match p.integral() {
+        Some(integral) if exact => law(
+            integral.derivative() == *p,
+            "the derivative of the integral is the polynomial",
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Runs every check with the three polynomials, in every order
+pub fn check_all<T: MonomialValue>(
+    p: &Polynomial<T>,
+    q: &Polynomial<T>,
+    r: &Polynomial<T>,
+) -> LawResult {
+    for [a, b, c] in [[p, q, r], [q, r, p], [r, p, q]] {
+        additive_laws(a, b, c)?;
+        multiplicative_laws(a, b, c)?;
+        division_laws(a, b)?;
+        calculus_laws(a, b)?;
+    }
+
+    Ok(())
+}
+
+/// Runs [`check_all`] with every triple of the samples, with repetition
+pub fn check_samples<T: MonomialValue>(samples: &[Polynomial<T>]) -> LawResult {
+    for p in samples {
+        for q in samples {
+            for r in samples {
+                check_all(p, q, r)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns small polynomials covering the zero polynomial, constants, monic and non-monic
+/// divisors, gaps between exponents and negative coefficients
+pub fn samples<T: MonomialValue>() -> Vec<Polynomial<T>> {
+    let coeffs: [&[i8]; 6] = [
+        &[],
+        &[3],
+        &[-1, 1],
+        &[2, 0, -1],
+        &[1, -2, 0, 1],
+        &[0, 4, 1, 0, -2],
+    ];
+
+    coeffs
+        .iter()
+        .map(|c| {
+            let c: Vec<T> = c.iter().filter_map(|v| T::from(*v)).collect();
+            Polynomial::from_coeffs(&c)
+        })
+        .collect()
+}
+
+fn law(holds: bool, name: &'static str) -> LawResult {
+    match holds {
+        true => Ok(()),
+        false => Err(name),
+    }
+}
@@ -16,10 +16,169 @@
 //! - `*` Multiply
 //! - `/` Divide
 //! - Root Calculation (Only Polynomial)
+//! - Derivative, extrema and factorization (Only Polynomial)
+//!
+//! The factorization can be written and read back in a compact form like `2(x-1)(x+3)^2`, see
+//! [`Polynomial::to_factored_string`]
+//!
+//! Monomials of several variables (`3x^2y`) are supported by [`MultiMonomial`], compared under a
+//! [`MonomialOrder`]
+//!
+//! Polynomials in `x` and `y` can be handled as polynomials in `x` with coefficients in `y`,
+//! a [`Bivariate`]
+//!
+//! Polynomials can be written in other bases, like the Chebyshev or Bernstein ones, with a
+//! [`PolyBasis`]
+//!
+//! Integrals are approximated with the [`Quadrature`] rules built from orthogonal polynomials
+//!
+//! Functions defined by intervals are represented by [`PiecewisePolynomial`], like the cubic
+//! [`Spline`] interpolations
+//!
+//! Matrices with polynomial entries, like characteristic matrices, are a [`PolyMatrix`]
+//!
+//! Quotients of polynomials are a [`RationalFunction`], like the result of substituting the
+//! variable by one with [`Polynomial::compose_rational`]
+//!
+//! Control systems are described by a [`TransferFunction`]
+//!
+//! Shaders can evaluate it from the Horner expression of [`Polynomial::to_glsl`] or
+//! [`Polynomial::to_wgsl`]
+//!
+//! Derivatives can be evaluated without building them, evaluating at a [`Dual`] number
+//!
+//! Coefficients with about 32 significant digits are available as [`DoubleDouble`]
+//!
+//! Derivatives, translations and dilations are values implementing [`LinearOperator`], they can
+//! be composed before applying them
+//!
+//! Sums and products of references, like `&p * &q + &r`, are a lazy [`PolyExpr`] that is
+//! evaluated without expanding it
+//!
+//! Repeated queries can be cached wrapping the polynomial in an [`AnalyzedPolynomial`]
+//!
+//! The operations applied to a polynomial can be recorded and replayed with [`Tracked`]
+//!
+//! A polynomial read from many threads can be shared with [`SharedPoly`]
+//!
+//! Long chains of divisions can reuse the buffers of their temporary polynomials with a
+//! [`PolyArena`]
+//!
+//! Big operands are multiplied and divided with faster algorithms, the crossover points can be
+//! adjusted in the [`tuning`] module
+//!
+//! ## Cargo features
+//!
+//! - `decimal`: exact decimal coefficients, [`Decimal`] wraps `rust_decimal::Decimal`
+//! - `json`: versioned JSON import/export, `to_json()` and `from_json()`
+//! - `laws`: the [`laws`] module, checks of the algebraic laws to validate custom coefficient
+//!   types
+//! - `ndarray`: coefficient conversion with `Array1` and batch evaluation, `to_array1()`,
+//!   `from_array1()` and `eval_array1()`
+//! - `polynomial`: `From` and `TryFrom` conversions with the polynomials of the
+//!   [`polynomial`](https://docs.rs/polynomial) crate
+//! - `twofloat`: extended precision coefficients, [`TwoFloat`] wraps `twofloat::TwoFloat`
 //!
 
+mod analyzed;
+mod arena;
+#[cfg(feature = "ndarray")]
+mod array;
+mod basis;
+mod bezier;
+mod bivariate;
+mod builder;
+mod checked;
+mod coeffs;
+mod compensated;
+mod compile;
+mod critical;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod division;
+mod double;
+mod dual;
+mod economize;
+mod expr;
+mod factored;
+mod geometry;
+pub mod gf2;
+pub mod gf2m;
+mod graeffe;
+mod hash;
+mod hensel;
+#[cfg(feature = "polynomial")]
+mod interop;
+mod interpolation;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "laws")]
+pub mod laws;
+mod locus;
+mod matrix;
+mod modular;
 mod mono;
+mod mul;
+mod multi;
+mod norms;
+mod operator;
+mod parse;
+mod piecewise;
 mod poly;
+mod quadrature;
+mod rational;
+mod recurrence;
+mod resultant;
+mod roots;
+mod sensitivity;
+mod shader;
+mod shared;
+mod sos;
+mod spline;
+mod stability;
+mod sturm;
+mod symmetric;
+mod tracked;
+mod transfer;
+mod transform;
+pub mod tuning;
+#[cfg(feature = "twofloat")]
+mod two_float;
+mod umbral;
+mod worksheet;
+mod wronskian;
 
+pub use analyzed::*;
+pub use arena::PolyArena;
+pub use basis::PolyBasis;
+pub use bezier::*;
+pub use bivariate::Bivariate;
+pub use builder::*;
+pub use critical::StationarySpectrum;
+#[cfg(feature = "decimal")]
+pub use decimal::Decimal;
+pub use division::DivError;
+pub use double::DoubleDouble;
+pub use dual::Dual;
+pub use expr::PolyExpr;
+#[cfg(feature = "json")]
+pub use json::JSON_VERSION;
+pub use locus::RootLocus;
+pub use matrix::*;
 pub use mono::*;
+pub use multi::*;
+pub use operator::*;
+pub use parse::{ParseError, SyntaxError, SyntaxErrorKind};
+pub use piecewise::*;
 pub use poly::*;
+pub use quadrature::Quadrature;
+pub use rational::RationalFunction;
+pub use recurrence::ClosedForm;
+pub use roots::{QuadraticRoot, Root, RootsError};
+pub use sensitivity::RootSensitivity;
+pub use shared::SharedPoly;
+pub use spline::*;
+pub use tracked::*;
+pub use transfer::*;
+#[cfg(feature = "twofloat")]
+pub use two_float::TwoFloat;
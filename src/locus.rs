@@ -0,0 +1,104 @@
+use num::Complex;
+
+use crate::{
+    norms::{durand_kerner, durand_kerner_from},
+    poly::Polynomial,
+};
+
+/// The gain and the roots of every step of [`Polynomial::root_locus`]
+pub type RootLocus = Vec<(f64, Vec<Complex<f64>>)>;
+
+impl Polynomial<f64> {
+    /// Returns the complex roots of `family(k)` at `steps + 1` evenly spaced values of `k`
+    /// from `k_range.0` to `k_range.1`, like the
+    /// [root locus](https://en.wikipedia.org/wiki/Root_locus_analysis) of the characteristic
+    /// polynomial `den + k num` of a feedback loop
+    ///
+    /// Every step starts the simultaneous root search from the roots of the previous one,
+    /// which converges in a few iterations for small steps, and the roots are kept in the
+    /// same order, so `locus[i].1[j]` for every `i` is the branch `j`. A degree change or a
+    /// search that doesn't converge is solved from scratch, and matched to the nearest roots
+    /// of the previous step
+    ///
+    /// Returns an error if a polynomial has any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let den: Polynomial<f64> = Polynomial::try_from("x^2 + 2x").unwrap();
+    /// let num: Polynomial<f64> = Polynomial::try_from("1").unwrap();
+    /// let locus = Polynomial::root_locus(
+    ///     |k| den.clone() + num.clone() * Polynomial::constant(k),
+    ///     (0.0, 2.0),
+    ///     20,
+    /// )
+    /// .unwrap();
+    ///
+    /// // The poles meet at -1 for k = 1 and leave the real axis
+    /// assert_eq!(locus.len(), 21);
+    /// let (k, roots) = &locus[20];
+    /// assert_eq!(*k, 2.0);
+    /// assert!((roots[0].re + 1.0).abs() < 1e-9 && (roots[0].im.abs() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn root_locus(
+        family: impl Fn(f64) -> Polynomial<f64>,
+        k_range: (f64, f64),
+        steps: usize,
+    ) -> Result<RootLocus, &'static str> {
+        let (start, end) = k_range;
+        let mut locus: RootLocus = Vec::with_capacity(steps + 1);
+
+        for i in 0..=steps {
+            let k = match i {
+                0 => start,
+                _ if i == steps => end,
+                _ => start + (end - start) * i as f64 / steps as f64,
+            };
+
+            let coeffs = family(k).to_coeffs()?;
+            let previous = locus.last().map_or(&[][..], |(_, roots)| &roots[..]);
+
+            // Roots at zero are exact, the search only sees the rest. Branches leaving zero
+            // start next to it
+            let zeros = coeffs.iter().take_while(|c| **c == 0f64).count();
+            let rest = &coeffs[zeros..];
+            let mut roots = vec![Complex::new(0f64, 0f64); zeros];
+
+            let warm = match previous.len() == coeffs.len().saturating_sub(1) {
+                true => {
+                    let start: Vec<Complex<f64>> = previous
+                        .iter()
+                        .copied()
+                        .filter(|r| r.norm() != 0f64)
+                        .chain(std::iter::repeat(Complex::new(f64::EPSILON, 0f64)))
+                        .take(rest.len().saturating_sub(1))
+                        .collect();
+                    durand_kerner_from(rest, &start)
+                }
+                false => None,
+            };
+            roots.extend(warm.unwrap_or_else(|| durand_kerner(rest)));
+
+            locus.push((k, match_branches(previous, roots)));
+        }
+
+        Ok(locus)
+    }
+}
+
+/// Orders the roots like the nearest roots of the previous step, greedily
+fn match_branches(previous: &[Complex<f64>], mut roots: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+    let mut ordered = Vec::with_capacity(roots.len());
+    for target in previous {
+        let nearest = roots
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - target).norm().total_cmp(&(*b - target).norm()))
+            .map(|(i, _)| i);
+        if let Some(i) = nearest {
+            ordered.push(roots.swap_remove(i));
+        }
+    }
+    ordered.extend(roots);
+
+    ordered
+}
@@ -0,0 +1,257 @@
+use std::ops::{Add, Mul};
+
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Matrix with [`Polynomial`] entries, stored by rows
+#[derive(Debug, Clone)]
+pub struct PolyMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<Polynomial<T>>,
+}
+
+impl<T: MonomialValue> PolyMatrix<T> {
+    /// Constructs a new `PolyMatrix<T>` from its rows
+    ///
+    /// Returns an error if there isn't any row or column, or the rows have different length
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyMatrix, Polynomial};
+    /// let matrix: PolyMatrix<i32> = PolyMatrix::new(vec![
+    ///     vec![Polynomial::try_from("x").unwrap(), Polynomial::try_from("1").unwrap()],
+    ///     vec![Polynomial::try_from("2").unwrap(), Polynomial::try_from("x").unwrap()],
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(matrix.size(), (2, 2));
+    /// assert_eq!(format!("{}", matrix.get(0, 0).unwrap()), "x");
+    /// ```
+    pub fn new(rows: Vec<Vec<Polynomial<T>>>) -> Result<PolyMatrix<T>, &'static str> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err("The matrix can't be empty");
+        }
+
+        if rows.iter().any(|row| row.len() != cols) {
+            return Err("Every row must have the same length");
+        }
+
+        Ok(PolyMatrix {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Constructs the `n x n` identity matrix
+    ///
+    /// Returns an error if `n` is zero, like [`new`](PolyMatrix::new) with an empty matrix
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::PolyMatrix;
+    /// let identity = PolyMatrix::<i32>::identity(3).unwrap();
+    ///
+    /// assert_eq!(identity.size(), (3, 3));
+    /// assert_eq!(format!("{}", identity.determinant().unwrap()), "1");
+    /// assert!(PolyMatrix::<i32>::identity(0).is_err());
+    /// ```
+    pub fn identity(n: usize) -> Result<PolyMatrix<T>, &'static str> {
+        if n == 0 {
+            return Err("The matrix can't be empty");
+        }
+
+        let entries = (0..n * n)
+            .map(|i| match i % (n + 1) {
+                0 => Polynomial::from_coeffs(&[T::one()]),
+                _ => Polynomial::zero(),
+            })
+            .collect();
+
+        Ok(PolyMatrix {
+            rows: n,
+            cols: n,
+            entries,
+        })
+    }
+
+    /// Constructs the [characteristic matrix](https://en.wikipedia.org/wiki/Characteristic_polynomial)
+    /// `xI - A` of the square numeric matrix `A`, its determinant is the characteristic
+    /// polynomial
+    ///
+    /// Returns an error if `matrix` isn't square or is empty
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::PolyMatrix;
+    /// let matrix = PolyMatrix::characteristic(&[vec![2, 1], vec![1, 2]]).unwrap();
+    ///
+    /// assert_eq!(format!("{}", matrix.determinant().unwrap()), "x^2 - 4x + 3");
+    /// ```
+    pub fn characteristic(matrix: &[Vec<T>]) -> Result<PolyMatrix<T>, &'static str> {
+        if matrix.iter().any(|row| row.len() != matrix.len()) {
+            return Err("The matrix must be square");
+        }
+
+        let rows = matrix
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, value)| match i == j {
+                        true => Polynomial::from_coeffs(&[-*value, T::one()]),
+                        false => Polynomial::from_coeffs(&[-*value]),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        PolyMatrix::new(rows)
+    }
+
+    /// Returns the number of rows and columns
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the entry at `row` and `col`, or [`None`] if it's out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&Polynomial<T>> {
+        match row < self.rows && col < self.cols {
+            true => self.entries.get(row * self.cols + col),
+            false => None,
+        }
+    }
+
+    /// Evaluates every entry at `x`, returns the numeric matrix by rows
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::PolyMatrix;
+    /// let matrix = PolyMatrix::characteristic(&[vec![2, 1], vec![1, 2]]).unwrap();
+    ///
+    /// assert_eq!(matrix.eval(3), vec![vec![1, -1], vec![-1, 1]]);
+    /// ```
+    pub fn eval(&self, x: T) -> Vec<Vec<T>> {
+        self.entries
+            .chunks(self.cols)
+            .map(|row| row.iter().map(|p| p.eval(x)).collect())
+            .collect()
+    }
+
+    /// Returns the matrix with rows and columns swapped
+    pub fn transpose(&self) -> PolyMatrix<T> {
+        let entries = (0..self.cols)
+            .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+            .map(|(i, j)| self.entries[i * self.cols + j].clone())
+            .collect();
+
+        PolyMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries,
+        }
+    }
+
+    /// Returns the determinant by cofactor expansion, using only addition and multiplication
+    /// of polynomials so integer coefficients stay exact. Rows with more zero entries are
+    /// expanded first, it's meant for the small matrices of characteristic polynomials and
+    /// transfer functions
+    ///
+    /// Returns an error if the matrix isn't square
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PolyMatrix, Polynomial};
+    /// let matrix: PolyMatrix<i32> = PolyMatrix::new(vec![
+    ///     vec![Polynomial::try_from("x").unwrap(), Polynomial::try_from("1").unwrap()],
+    ///     vec![Polynomial::try_from("2").unwrap(), Polynomial::try_from("x").unwrap()],
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(format!("{}", matrix.determinant().unwrap()), "x^2 - 2");
+    /// ```
+    pub fn determinant(&self) -> Result<Polynomial<T>, &'static str> {
+        if self.rows != self.cols {
+            return Err("The determinant needs a square matrix");
+        }
+
+        let rows: Vec<usize> = (0..self.rows).collect();
+        let cols: Vec<usize> = (0..self.cols).collect();
+
+        Ok(self.minor(&rows, &cols))
+    }
+
+    /// Determinant of the submatrix with the given rows and columns, expanding along the row
+    /// with most zero entries
+    fn minor(&self, rows: &[usize], cols: &[usize]) -> Polynomial<T> {
+        let entry = |i: usize, j: usize| &self.entries[rows[i] * self.cols + cols[j]];
+
+        if rows.len() == 1 {
+            return entry(0, 0).clone();
+        }
+
+        let zeros = |i: usize| (0..cols.len()).filter(|j| entry(i, *j).is_zero()).count();
+        let pivot = (0..rows.len()).max_by_key(|i| zeros(*i)).unwrap_or(0);
+        let sub_rows: Vec<usize> = [&rows[..pivot], &rows[pivot + 1..]].concat();
+
+        (0..cols.len())
+            .filter(|j| !entry(pivot, *j).is_zero())
+            .fold(Polynomial::zero(), |acc, j| {
+                let sub_cols: Vec<usize> = [&cols[..j], &cols[j + 1..]].concat();
+                let term = entry(pivot, j).clone() * self.minor(&sub_rows, &sub_cols);
+
+                match (pivot + j) % 2 {
+                    0 => acc + term,
+                    _ => acc + -term,
+                }
+            })
+    }
+}
+
+impl<T: MonomialValue> Add for PolyMatrix<T> {
+    type Output = Result<Self, &'static str>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.size() != rhs.size() {
+            return Err("Matrices only allow add same size");
+        }
+
+        let entries = self
+            .entries
+            .into_iter()
+            .zip(rhs.entries)
+            .map(|(a, b)| a + b)
+            .collect();
+
+        Ok(PolyMatrix { entries, ..self })
+    }
+}
+
+impl<T: MonomialValue> Mul for PolyMatrix<T> {
+    type Output = Result<Self, &'static str>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.cols != rhs.rows {
+            return Err("Columns of the left matrix must match rows of the right one");
+        }
+
+        let entries = (0..self.rows * rhs.cols)
+            .map(|index| {
+                let (i, j) = (index / rhs.cols, index % rhs.cols);
+                (0..self.cols).fold(Polynomial::zero(), |acc, k| {
+                    acc + self.entries[i * self.cols + k].clone()
+                        * rhs.entries[k * rhs.cols + j].clone()
+                })
+            })
+            .collect();
+
+        Ok(PolyMatrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            entries,
+        })
+    }
+}
+
+impl<T: MonomialValue> PartialEq for PolyMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.entries == other.entries
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns `x^n mod modulus` by repeated squaring, reducing after every step so the
+    /// intermediate polynomials stay below the degree of the modulus. Takes `O(log n)`
+    /// products instead of building `x^n`
+    ///
+    /// Returns an error if the modulus is constant, has negative exponents or its leading
+    /// coefficient isn't invertible in `T`, like integers other than `±1`. Any non-zero float
+    /// is invertible, even if `lead * (1 / lead)` rounds
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let modulus: Polynomial<i64> = Polynomial::try_from("x^2 - x - 1").unwrap();
+    ///
+    /// // x^n = F(n) x + F(n - 1) modulo the Fibonacci polynomial
+    /// assert_eq!(format!("{}", Polynomial::xpow_mod(10, &modulus).unwrap()), "55x + 34");
+    /// ```
+    pub fn xpow_mod(n: u64, modulus: &Self) -> Result<Self, &'static str> {
+        let modulus_coeffs = modulus.to_coeffs()?;
+        if modulus_coeffs.len() < 2 {
+            return Err("The modulus must have positive degree");
+        }
+
+        let lead = modulus_coeffs[modulus_coeffs.len() - 1];
+        if (T::one() / lead).is_zero() {
+            return Err("The leading coefficient of the modulus must be invertible");
+        }
+
+        let mut result = vec![T::one()];
+        for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+            result = rem_dense(mul_dense(&result, &result), &modulus_coeffs);
+            if n >> bit & 1 == 1 {
+                result.insert(0, T::zero());
+                result = rem_dense(result, &modulus_coeffs);
+            }
+        }
+
+        Ok(Polynomial::from_coeffs(&result).with_var(modulus.get_var()))
+    }
+}
+
+/// Schoolbook product of dense coefficients in ascending order
+fn mul_dense<T: MonomialValue>(lhs: &[T], rhs: &[T]) -> Vec<T> {
+    let mut product = vec![T::zero(); lhs.len() + rhs.len() - 1];
+    for (i, a) in lhs.iter().enumerate() {
+        for (j, b) in rhs.iter().enumerate() {
+            product[i + j] = product[i + j] + *a * *b;
+        }
+    }
+
+    product
+}
+
+/// Remainder of the long division of dense coefficients, `modulus` has invertible leading
+/// coefficient
+fn rem_dense<T: MonomialValue>(mut rem: Vec<T>, modulus: &[T]) -> Vec<T> {
+    let lead = modulus[modulus.len() - 1];
+
+    while rem.len() >= modulus.len() {
+        let factor = rem[rem.len() - 1] / lead;
+        let shift = rem.len() - modulus.len();
+        for (i, c) in modulus.iter().enumerate() {
+            rem[shift + i] = rem[shift + i] - factor * *c;
+        }
+        rem.pop();
+    }
+
+    rem
+}
@@ -1,15 +1,16 @@
 use std::{
     default::Default,
-    fmt::{Debug, Display, Error},
-    i64,
+    fmt::{Debug, Display},
     iter::Sum,
     num::IntErrorKind,
-    ops::{Add, Div, Mul, Neg},
+    ops::{Add, Div, Mul, Neg, Sub},
     str::FromStr,
 };
 
 use num::{Num, NumCast, Signed};
 
+use crate::poly::Polynomial;
+
 /// Trait pattern to allow only **numbers** for generic value
 pub trait MonomialValue:
     Num + NumCast + Signed + Copy + Default + Debug + Display + FromStr + PartialOrd
@@ -47,6 +48,113 @@ impl<T: MonomialValue> Monomial<T> {
         self.exp
     }
 
+    /// Evaluates the monomial at `x`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Monomial;
+    /// let mono: Monomial<f64> = Monomial::new(3.0, -2);
+    ///
+    /// assert_eq!(Monomial::new(3, 2).eval(2), 12);
+    /// assert_eq!(mono.eval(2.0), 0.75);
+    /// ```
+    pub fn eval(&self, x: T) -> T {
+        let pow = num::pow(x, self.exp.unsigned_abs() as usize);
+
+        match self.exp < 0 {
+            true => self.value / pow,
+            false => self.value * pow,
+        }
+    }
+
+    /// Returns the derivative of the monomial
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Monomial;
+    /// assert_eq!(Monomial::new(3, 2).derivative(), Monomial::new(6, 1));
+    /// assert_eq!(Monomial::new(3, 0).derivative(), Monomial::new(0, 0));
+    /// ```
+    pub fn derivative(&self) -> Monomial<T> {
+        Monomial::new(self.value * T::from(self.exp).unwrap(), self.exp - 1)
+    }
+
+    /// Returns the antiderivative of the monomial, or [`None`] for `x^-1` whose integral
+    /// is a logarithm
+    ///
+    /// Integer coefficients are truncated by the division
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Monomial;
+    /// assert_eq!(Monomial::new(6, 2).integral(), Some(Monomial::new(2, 3)));
+    /// assert_eq!(Monomial::new(6.0, -1).integral(), None);
+    /// ```
+    pub fn integral(&self) -> Option<Monomial<T>> {
+        if self.exp == -1 {
+            return None;
+        }
+
+        Some(Monomial::new(
+            self.value / T::from(self.exp + 1)?,
+            self.exp + 1,
+        ))
+    }
+
+    /// Returns the monomial raised to the power `n`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Monomial;
+    /// let mono: Monomial<i32> = Monomial::try_from("-2x^3").unwrap();
+    ///
+    /// assert_eq!(mono.pow(3), Monomial::new(-8, 9));
+    /// assert_eq!(mono.pow(0), Monomial::new(1, 0));
+    /// ```
+    ///
+    /// # Panics
+    /// If the exponent overflows [`i32`], use [`checked_pow`](Monomial::checked_pow) to get
+    /// [`None`] instead
+    pub fn pow(&self, n: u32) -> Monomial<T> {
+        self.checked_pow(n).expect("exponent overflow")
+    }
+
+    /// Same as [`pow`](Monomial::pow), but returns [`None`] if the exponent overflows [`i32`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Monomial;
+    /// assert_eq!(Monomial::new(1, 1 << 20).checked_pow(1 << 11), None);
+    /// assert_eq!(Monomial::new(1, -3).checked_pow(2), Some(Monomial::new(1, -6)));
+    /// ```
+    pub fn checked_pow(&self, n: u32) -> Option<Monomial<T>> {
+        let exp = self.exp.checked_mul(i32::try_from(n).ok()?)?;
+
+        Some(Monomial::new(num::pow(self.value, n as usize), exp))
+    }
+
+    /// Same as `*`, but returns [`None`] if the exponent overflows [`i32`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Monomial;
+    /// assert_eq!(Monomial::new(2, 3).checked_mul(Monomial::new(5, -1)), Some(Monomial::new(10, 2)));
+    /// assert_eq!(Monomial::new(2, i32::MAX).checked_mul(Monomial::new(5, 1)), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Monomial<T>> {
+        Some(Monomial::new(
+            self.value * rhs.value,
+            self.exp.checked_add(rhs.exp)?,
+        ))
+    }
+
+    /// Returns the sum as a [`Polynomial`], unlike `+` it allows different exponents
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Monomial, Polynomial};
+    /// let mono: Monomial<i32> = Monomial::try_from("2x").unwrap();
+    ///
+    /// assert_eq!(format!("{}", mono.add_promote(Monomial::new(3, 0))), "2x + 3");
+    /// assert_eq!(format!("{}", mono.add_promote(mono)), "4x");
+    /// ```
+    pub fn add_promote(self, rhs: Self) -> Polynomial<T> {
+        Polynomial::new(vec![self, rhs])
+    }
+
     /// Check if other `Monomial` has same **exponent**
     pub fn is_operable(&self, other: &Self) -> bool {
         self.exp == other.exp
@@ -119,11 +227,28 @@ impl<T: MonomialValue> Add for Monomial<T> {
     }
 }
 
+impl<T: MonomialValue> Sub for Monomial<T> {
+    type Output = Result<Self, &'static str>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if !self.is_operable(&rhs) {
+            return Err("Monomials only allow subtract same exponent");
+        }
+
+        Ok(Monomial::new(self.value - rhs.value, self.exp))
+    }
+}
+
+/// Multiplies the values and adds the exponents
+///
+/// # Panics
+/// If the exponent overflows [`i32`], use [`checked_mul`](Monomial::checked_mul) to get [`None`]
+/// instead
 impl<T: MonomialValue> Mul for Monomial<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Monomial::new(self.value * rhs.value, self.exp + rhs.exp)
+        self.checked_mul(rhs).expect("exponent overflow")
     }
 }
 
@@ -135,16 +260,62 @@ impl<T: MonomialValue> Div for Monomial<T> {
     }
 }
 
+impl<T: MonomialValue> Mul<T> for Monomial<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Monomial::new(self.value * rhs, self.exp)
+    }
+}
+
+impl<T: MonomialValue> Div<T> for Monomial<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Monomial::new(self.value / rhs, self.exp)
+    }
+}
+
+/// Sum of monomials with the same exponent, zero monomials are accepted with any exponent
+///
+/// # Panics
+/// If the iterator mixes exponents, use [`SumToPolynomial::sum_to_polynomial`] instead
 impl<T: MonomialValue> Sum<Self> for Monomial<T> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut exp = 0;
+        let mut exp: Option<i32> = None;
         let mut sum = T::zero();
-        for mono in iter {
-            exp = mono.get_exp();
+        for mono in iter.filter(|m| !m.get_value().is_zero()) {
+            if *exp.get_or_insert(mono.get_exp()) != mono.get_exp() {
+                panic!("Monomials only allow add same exponent");
+            }
+
             sum = sum + mono.get_value();
         }
 
-        Monomial::new(sum, exp)
+        Monomial::new(sum, exp.unwrap_or_default())
+    }
+}
+
+/// Sum monomials of any exponent into a [`Polynomial`]
+pub trait SumToPolynomial<T> {
+    /// Returns the polynomial grouping the monomials by exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Monomial, SumToPolynomial};
+    /// let mono_vec: Vec<Monomial<i32>> = vec![
+    ///     Monomial::new(2, 2),
+    ///     Monomial::new(-1, 0),
+    ///     Monomial::new(3, 2),
+    /// ];
+    ///
+    /// assert_eq!(format!("{}", mono_vec.into_iter().sum_to_polynomial()), "5x^2 - 1");
+    /// ```
+    fn sum_to_polynomial(self) -> Polynomial<T>;
+}
+
+impl<T: MonomialValue, I: Iterator<Item = Monomial<T>>> SumToPolynomial<T> for I {
+    fn sum_to_polynomial(self) -> Polynomial<T> {
+        Polynomial::new(self.collect())
     }
 }
 
@@ -155,19 +326,37 @@ impl<T: MonomialValue> Sum<Self> for Monomial<T> {
 ///```
 impl<T: MonomialValue> Display for Monomial<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let val: i64 = T::to_i64(&self.value).ok_or(Error)?;
-        let base: String = match val {
-            -1 if self.exp == 0 => "-1".to_string(),
-            -1 => "-".to_string(),
-            1 if self.exp == 0 => "1".to_string(),
-            1 => "".to_string(),
-            _ => format!("{}", self.value),
+        self.fmt_var(f, 'x')
+    }
+}
+
+impl<T: MonomialValue> Monomial<T> {
+    /// Format the monomial using `var` as variable symbol
+    ///
+    /// Width and precision flags apply to the coefficient. The alternate flag writes the
+    /// strict form `c*x^e`, with every coefficient and exponent explicit
+    pub(crate) fn fmt_var(&self, f: &mut std::fmt::Formatter<'_>, var: char) -> std::fmt::Result {
+        let value = match (f.width(), f.precision()) {
+            (Some(width), Some(precision)) => format!("{:width$.precision$}", self.value),
+            (Some(width), None) => format!("{:width$}", self.value),
+            (None, Some(precision)) => format!("{:.precision$}", self.value),
+            (None, None) => format!("{}", self.value),
+        };
+
+        if f.alternate() {
+            return write!(f, "{value}*{var}^{}", self.exp);
+        }
+
+        let base: String = match self.value {
+            v if v == -T::one() && self.exp != 0 => "-".to_string(),
+            v if v.is_one() && self.exp != 0 => "".to_string(),
+            _ => value,
         };
 
         let exp: String = match self.exp {
             0 => "".to_string(),
-            1 => "x".to_string(),
-            _ => format!("x^{}", self.exp),
+            1 => var.to_string(),
+            _ => format!("{var}^{}", self.exp),
         };
 
         write!(f, "{}{}", base, exp)
@@ -0,0 +1,330 @@
+use num::{BigInt, BigUint, One, Signed, ToPrimitive, Zero};
+
+use crate::{mono::Monomial, poly::Polynomial, tuning, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Select the multiplication strategy by the operand sizes:
+    /// naive for small operands, Toom-3 for mid-size ones and Kronecker substitution for
+    /// the biggest, falling back to the previous tier when a strategy can't be applied.
+    /// Sparse operands are always multiplied term by term
+    pub(crate) fn mul_dispatch(lhs: &Self, rhs: &Self) -> Self {
+        let len = lhs.len().min(rhs.len());
+        let dense = is_dense(lhs) && is_dense(rhs);
+
+        if len >= tuning::kronecker_threshold() && dense {
+            if let Some(result) = lhs.mul_kronecker(rhs) {
+                return result;
+            }
+        }
+
+        if len >= tuning::toom3_threshold() && dense {
+            if let Some(result) = lhs.mul_toom3(rhs) {
+                return result;
+            }
+        }
+
+        Polynomial::<T>::naive_mul(lhs, rhs)
+    }
+
+    /// Same as `*`, but returns [`None`] if an exponent of the product overflows [`i32`]
+    /// instead of panicking
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i64> = Polynomial::try_from("x^2000000000 + 1").unwrap();
+    /// let q: Polynomial<i64> = Polynomial::try_from("x^-5 + 3").unwrap();
+    ///
+    /// assert_eq!(format!("{}", p.checked_mul(&q).unwrap()), "3x^2000000000 + x^1999999995 + 3 + x^-5");
+    /// assert_eq!(p.checked_mul(&p), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let lowest = |p: &Self| p.into_iter().last().map_or(0, |m| m.get_exp());
+        self.max_exp()
+            .get_exp()
+            .checked_add(rhs.max_exp().get_exp())?;
+        lowest(self).checked_add(lowest(rhs))?;
+
+        Some(Polynomial::<T>::mul_dispatch(self, rhs))
+    }
+
+    /// Multiply every monomial of `lhs` by every monomial of `rhs`
+    pub(crate) fn naive_mul(lhs: &Self, rhs: &Self) -> Self {
+        let mut result: Vec<Monomial<T>> = Vec::new();
+        for lhs_mono in lhs {
+            for rhs_mono in rhs {
+                result.push(*lhs_mono * *rhs_mono);
+            }
+        }
+
+        Polynomial::new(result).with_var(lhs.get_var())
+    }
+
+    /// Returns the product computed by [Kronecker substitution](https://en.wikipedia.org/wiki/Kronecker_substitution):
+    /// both polynomials are packed into a single big integer evaluating them at `2^k`, the
+    /// integers are multiplied and the coefficients unpacked from the result
+    ///
+    /// Returns [`None`] if any coefficient isn't an integer fitting in [`i64`], any exponent is
+    /// negative, the degree of the product overflows [`i32`] or a result coefficient doesn't
+    /// fit in `T`. The packed integers have a digit for every exponent up to the degree, so
+    /// sparse polynomials are better multiplied with `*`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p1: Polynomial<i64> = Polynomial::try_from("3x^2 - 2x + 5").unwrap();
+    /// let p2: Polynomial<i64> = Polynomial::try_from("-x + 7").unwrap();
+    ///
+    /// assert_eq!(p1.mul_kronecker(&p2), Some(p1 * p2));
+    /// ```
+    pub fn mul_kronecker(&self, rhs: &Self) -> Option<Self> {
+        let max_exp = self
+            .max_exp()
+            .get_exp()
+            .checked_add(rhs.max_exp().get_exp())?;
+        let lhs_terms = integer_terms(self)?;
+        let rhs_terms = integer_terms(rhs)?;
+
+        if lhs_terms.is_empty() || rhs_terms.is_empty() {
+            return Some(Polynomial::zero().with_var(self.get_var()));
+        }
+
+        let max_value = |terms: &[(i32, i64)]| terms.iter().map(|(_, v)| v.unsigned_abs()).max();
+        let bound = BigUint::from(max_value(&lhs_terms)?)
+            * BigUint::from(max_value(&rhs_terms)?)
+            * BigUint::from(lhs_terms.len().min(rhs_terms.len()));
+        let bits = bound.bits() + 1;
+
+        let product = pack(&lhs_terms, bits) * pack(&rhs_terms, bits);
+        let words = product.magnitude().to_u32_digits();
+        let sign = match product.is_negative() {
+            true => -BigInt::one(),
+            false => BigInt::one(),
+        };
+
+        let modulus = BigInt::one() << bits;
+        let half = BigInt::one() << (bits - 1);
+
+        let mut mono_vec: Vec<Monomial<T>> = Vec::new();
+        let mut carry = BigInt::zero();
+        for exp in 0..=max_exp {
+            let mut digit = BigInt::from(read_bits(&words, exp as u64 * bits, bits)) + &carry;
+            carry = BigInt::zero();
+
+            if digit >= half {
+                digit -= &modulus;
+                carry = BigInt::one();
+            }
+
+            let value = T::from((&sign * digit).to_i64()?)?;
+            mono_vec.push(Monomial::new(value, exp));
+        }
+
+        Some(Polynomial::new(mono_vec).with_var(self.get_var()))
+    }
+
+    /// Returns the product computed by [Toom-Cook 3-way](https://en.wikipedia.org/wiki/Toom%E2%80%93Cook_multiplication)
+    /// multiplication, splitting both operands in three parts and recursing until the parts
+    /// are smaller than the naive threshold. The evaluations grow the coefficients, so they are
+    /// computed with big integers
+    ///
+    /// Returns [`None`] if any coefficient isn't an integer, any exponent is negative or a
+    /// result coefficient doesn't fit in `T`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p1: Polynomial<i32> = Polynomial::try_from("3x^2 - 2x + 5").unwrap();
+    /// let p2: Polynomial<i32> = Polynomial::try_from("-x + 7").unwrap();
+    ///
+    /// assert_eq!(p1.mul_toom3(&p2), Some(p1 * p2));
+    /// ```
+    pub fn mul_toom3(&self, rhs: &Self) -> Option<Self> {
+        let lhs_dense = dense(self)?;
+        let rhs_dense = dense(rhs)?;
+
+        let mono_vec = toom3(&lhs_dense, &rhs_dense)
+            .into_iter()
+            .enumerate()
+            .map(|(exp, value)| Some(Monomial::new(T::from(value.to_i128()?)?, exp as i32)))
+            .collect::<Option<Vec<Monomial<T>>>>()?;
+
+        Some(Polynomial::new(mono_vec).with_var(self.get_var()))
+    }
+}
+
+/// Check that at least half of the coefficients up to the degree are non-zero
+fn is_dense<T: MonomialValue>(poly: &Polynomial<T>) -> bool {
+    (poly.max_exp().get_exp() as usize) < poly.len() * 2
+}
+
+/// Returns the coefficients ordered by exponent if every coefficient is an integer and every
+/// exponent non-negative
+fn dense<T: MonomialValue>(poly: &Polynomial<T>) -> Option<Vec<BigInt>> {
+    let mut coeffs = vec![BigInt::zero(); poly.max_exp().get_exp().max(0) as usize + 1];
+
+    for mono in poly {
+        let value = mono.get_value();
+        let int = value.to_i128()?;
+        if mono.get_exp() < 0 || T::from(int)? != value {
+            return None;
+        }
+
+        coeffs[mono.get_exp() as usize] = BigInt::from(int);
+    }
+
+    Some(coeffs)
+}
+
+fn toom3(lhs: &[BigInt], rhs: &[BigInt]) -> Vec<BigInt> {
+    if lhs.len().min(rhs.len()) < tuning::toom3_threshold().max(3) {
+        return dense_naive(lhs, rhs);
+    }
+
+    let k = lhs.len().max(rhs.len()).div_ceil(3);
+    let split = |p: &[BigInt]| -> [Vec<BigInt>; 3] {
+        [0, 1, 2].map(|i| p.iter().skip(i * k).take(k).cloned().collect())
+    };
+
+    let [a0, a1, a2] = split(lhs);
+    let [b0, b1, b2] = split(rhs);
+
+    // Evaluation at 0, 1, -1, -2 and infinity
+    let eval = |p0: &[BigInt], p1: &[BigInt], p2: &[BigInt]| {
+        let p02 = dense_add(p0, p2);
+        let at_1 = dense_add(&p02, p1);
+        let at_m1 = dense_sub(&p02, p1);
+        let at_m2 = dense_sub(&dense_scale(&dense_add(&at_m1, p2), 2), p0);
+        (at_1, at_m1, at_m2)
+    };
+
+    let (a_1, a_m1, a_m2) = eval(&a0, &a1, &a2);
+    let (b_1, b_m1, b_m2) = eval(&b0, &b1, &b2);
+
+    let w0 = toom3(&a0, &b0);
+    let w1 = toom3(&a_1, &b_1);
+    let wm1 = toom3(&a_m1, &b_m1);
+    let wm2 = toom3(&a_m2, &b_m2);
+    let winf = toom3(&a2, &b2);
+
+    // Interpolation, Bodrato sequence, the divisions are exact
+    let r0 = w0;
+    let r4 = winf;
+    let r3 = dense_div(&dense_sub(&wm2, &w1), 3);
+    let r1 = dense_div(&dense_sub(&w1, &wm1), 2);
+    let r2 = dense_sub(&wm1, &r0);
+    let r3 = dense_add(&dense_div(&dense_sub(&r2, &r3), 2), &dense_scale(&r4, 2));
+    let r2 = dense_sub(&dense_add(&r2, &r1), &r4);
+    let r1 = dense_sub(&r1, &r3);
+
+    let mut result = vec![BigInt::zero(); lhs.len() + rhs.len() - 1];
+    for (i, r) in [r0, r1, r2, r3, r4].iter().enumerate() {
+        for (j, value) in r.iter().enumerate() {
+            if let Some(coeff) = result.get_mut(i * k + j) {
+                *coeff += value;
+            }
+        }
+    }
+
+    result
+}
+
+fn dense_naive(lhs: &[BigInt], rhs: &[BigInt]) -> Vec<BigInt> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result = vec![BigInt::zero(); lhs.len() + rhs.len() - 1];
+    for (i, l) in lhs.iter().enumerate() {
+        for (j, r) in rhs.iter().enumerate() {
+            result[i + j] += l * r;
+        }
+    }
+
+    result
+}
+
+fn dense_add(lhs: &[BigInt], rhs: &[BigInt]) -> Vec<BigInt> {
+    let zero = BigInt::zero();
+    (0..lhs.len().max(rhs.len()))
+        .map(|i| lhs.get(i).unwrap_or(&zero) + rhs.get(i).unwrap_or(&zero))
+        .collect()
+}
+
+fn dense_sub(lhs: &[BigInt], rhs: &[BigInt]) -> Vec<BigInt> {
+    let zero = BigInt::zero();
+    (0..lhs.len().max(rhs.len()))
+        .map(|i| lhs.get(i).unwrap_or(&zero) - rhs.get(i).unwrap_or(&zero))
+        .collect()
+}
+
+fn dense_scale(p: &[BigInt], value: i32) -> Vec<BigInt> {
+    p.iter().map(|c| c * value).collect()
+}
+
+fn dense_div(p: &[BigInt], value: i32) -> Vec<BigInt> {
+    p.iter().map(|c| c / value).collect()
+}
+
+/// Returns `(exp, value)` pairs if every coefficient is an integer and every exponent
+/// non-negative
+fn integer_terms<T: MonomialValue>(poly: &Polynomial<T>) -> Option<Vec<(i32, i64)>> {
+    poly.into_iter()
+        .map(|m| {
+            let value = m.get_value().to_i64()?;
+            if m.get_exp() < 0 || T::from(value)? != m.get_value() {
+                return None;
+            }
+
+            Some((m.get_exp(), value))
+        })
+        .collect()
+}
+
+/// Evaluates the terms at `2^bits`
+fn pack(terms: &[(i32, i64)], bits: u64) -> BigInt {
+    let len = (terms.iter().map(|(e, _)| *e).max().unwrap_or(0) as u64 + 1) * bits;
+    let mut positive = vec![0u32; len.div_ceil(32) as usize + 1];
+    let mut negative = positive.clone();
+
+    for (exp, value) in terms {
+        let words = match value.is_negative() {
+            true => &mut negative,
+            false => &mut positive,
+        };
+        write_bits(words, *exp as u64 * bits, value.unsigned_abs());
+    }
+
+    BigInt::from(BigUint::new(positive)) - BigInt::from(BigUint::new(negative))
+}
+
+/// OR `value` into `words` starting at bit `offset`
+fn write_bits(words: &mut [u32], offset: u64, value: u64) {
+    let mut value = (value as u128) << (offset % 32);
+    let mut index = (offset / 32) as usize;
+
+    while value != 0 {
+        words[index] |= value as u32;
+        value >>= 32;
+        index += 1;
+    }
+}
+
+/// Read `len` bits of `words` starting at bit `offset`
+fn read_bits(words: &[u32], offset: u64, len: u64) -> BigUint {
+    let mut digits: Vec<u32> = Vec::new();
+
+    for chunk in (0..len).step_by(32) {
+        let pos = offset + chunk;
+        let index = (pos / 32) as usize;
+        let low = words.get(index).copied().unwrap_or(0) as u64;
+        let high = words.get(index + 1).copied().unwrap_or(0) as u64;
+
+        let mut digit = (((high << 32) | low) >> (pos % 32)) as u32;
+        let take = (len - chunk).min(32);
+        if take < 32 {
+            digit &= (1 << take) - 1;
+        }
+
+        digits.push(digit);
+    }
+
+    BigUint::new(digits)
+}
@@ -0,0 +1,213 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt::Display,
+    ops::{Mul, Neg},
+};
+
+use crate::MonomialValue;
+
+/// Order used to compare monomials of several variables, variables are ranked alphabetically
+/// (`x > y > z`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonomialOrder {
+    /// Lexicographic: compare the exponents variable by variable
+    #[default]
+    Lex,
+
+    /// Graded lexicographic: compare the total degree, ties are broken by [`Lex`](MonomialOrder::Lex)
+    GrLex,
+
+    /// Graded reverse lexicographic: compare the total degree, ties are broken by the last
+    /// variable with different exponent, the smaller exponent wins
+    GRevLex,
+}
+
+/// Monomial of several variables, like `3x^2y`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MultiMonomial<T> {
+    pub(crate) value: T,
+    pub(crate) exps: BTreeMap<char, i32>,
+}
+
+impl<T: MonomialValue> MultiMonomial<T> {
+    /// Constructs a new `MultiMonomial<T>`
+    ///  - `value`: Coefficient
+    ///  - `exps`: Pairs of variable and exponent, repeated variables are multiplied
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::MultiMonomial;
+    /// let mono: MultiMonomial<i32> = MultiMonomial::new(3, &[('x', 2), ('y', 1)]);
+    ///
+    /// assert_eq!(format!("{mono}"), "3x^2y");
+    /// ```
+    pub fn new(value: T, exps: &[(char, i32)]) -> MultiMonomial<T> {
+        let mut map: BTreeMap<char, i32> = BTreeMap::new();
+        if !value.is_zero() {
+            for (var, exp) in exps {
+                *map.entry(*var).or_default() += exp;
+            }
+            map.retain(|_, exp| *exp != 0);
+        }
+
+        MultiMonomial { value, exps: map }
+    }
+
+    pub fn get_value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the exponent of `var`, `0` if the variable doesn't appear
+    pub fn get_exp(&self, var: char) -> i32 {
+        self.exps.get(&var).copied().unwrap_or(0)
+    }
+
+    /// Returns the variables with non-zero exponent in alphabetical order
+    pub fn vars(&self) -> impl Iterator<Item = char> + '_ {
+        self.exps.keys().copied()
+    }
+
+    /// Returns the total degree, the sum of every exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::MultiMonomial;
+    /// let mono: MultiMonomial<i32> = MultiMonomial::try_from("-2x^3yz^2").unwrap();
+    ///
+    /// assert_eq!(mono.degree(), 6);
+    /// ```
+    pub fn degree(&self) -> i32 {
+        self.exps.values().sum()
+    }
+
+    /// Check if both monomials have the same variables and exponents
+    pub fn is_operable(&self, other: &Self) -> bool {
+        self.exps == other.exps
+    }
+
+    /// Compare the exponents of both monomials under `order`, coefficients are ignored
+    /// # Examples
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use rust_polynomial::{MonomialOrder, MultiMonomial};
+    /// let a: MultiMonomial<i32> = MultiMonomial::try_from("x^2").unwrap();
+    /// let b: MultiMonomial<i32> = MultiMonomial::try_from("xy^2").unwrap();
+    ///
+    /// assert_eq!(a.cmp_by(&b, MonomialOrder::Lex), Ordering::Greater);
+    /// assert_eq!(a.cmp_by(&b, MonomialOrder::GrLex), Ordering::Less);
+    /// ```
+    pub fn cmp_by(&self, other: &Self, order: MonomialOrder) -> Ordering {
+        let mut vars: Vec<char> = self.vars().chain(other.vars()).collect();
+        vars.sort_unstable();
+        vars.dedup();
+
+        let lex = || {
+            vars.iter()
+                .map(|v| self.get_exp(*v).cmp(&other.get_exp(*v)))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        };
+
+        match order {
+            MonomialOrder::Lex => lex(),
+            MonomialOrder::GrLex => self.degree().cmp(&other.degree()).then_with(lex),
+            MonomialOrder::GRevLex => self.degree().cmp(&other.degree()).then_with(|| {
+                vars.iter()
+                    .rev()
+                    .map(|v| other.get_exp(*v).cmp(&self.get_exp(*v)))
+                    .find(|o| o.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            }),
+        }
+    }
+}
+
+/// # Example expresion
+///
+///```rust
+/// let str = "-3x^2yz^-1";
+///```
+impl<T: MonomialValue> TryFrom<&str> for MultiMonomial<T> {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let clean_value = value.to_lowercase().replace(" ", "").replace("+", "");
+        let start = clean_value
+            .find(|c: char| c.is_alphabetic())
+            .unwrap_or(clean_value.len());
+        let (base, vars) = clean_value.split_at(start);
+
+        let base = match base {
+            "" => T::one(),
+            "-" => -T::one(),
+            _ => base.parse::<T>().map_err(|_| "Not valid base")?,
+        };
+
+        let mut exps: Vec<(char, i32)> = Vec::new();
+        let mut chars = vars.chars().peekable();
+        while let Some(var) = chars.next() {
+            if !var.is_alphabetic() {
+                return Err("Not valid variable");
+            }
+
+            if chars.next_if_eq(&'^').is_none() {
+                exps.push((var, 1));
+                continue;
+            }
+
+            let mut exp = String::new();
+            while let Some(c) =
+                chars.next_if(|c| c.is_ascii_digit() || (*c == '-' && exp.is_empty()))
+            {
+                exp.push(c);
+            }
+
+            let exp = exp.parse::<i32>().map_err(|_| "Not valid exponent")?;
+            exps.push((var, exp));
+        }
+
+        Ok(MultiMonomial::new(base, &exps))
+    }
+}
+
+impl<T: MonomialValue> Neg for MultiMonomial<T> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        self.value = -self.value;
+        self
+    }
+}
+
+impl<T: MonomialValue> Mul for MultiMonomial<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let exps: Vec<(char, i32)> = self.exps.into_iter().chain(rhs.exps).collect();
+        MultiMonomial::new(self.value * rhs.value, &exps)
+    }
+}
+
+/// # Example expresion
+///
+///```rust
+/// let str = "3x^2y";
+///```
+impl<T: MonomialValue> Display for MultiMonomial<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let constant = self.exps.is_empty();
+        match self.value {
+            v if v == -T::one() && !constant => write!(f, "-")?,
+            v if v.is_one() && !constant => {}
+            v => write!(f, "{v}")?,
+        }
+
+        for (var, exp) in &self.exps {
+            match exp {
+                1 => write!(f, "{var}")?,
+                _ => write!(f, "{var}^{exp}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
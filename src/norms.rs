@@ -0,0 +1,259 @@
+use num::Complex;
+
+use crate::{poly::Polynomial, sturm::balance, MonomialValue};
+
+/// Max iterations of the simultaneous root search
+const MAX_ITERATIONS: usize = 500;
+
+/// Size of the coefficients, to estimate the cost and the precision of other algorithms
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the [height](https://en.wikipedia.org/wiki/Height_of_a_polynomial), the largest
+    /// absolute value of the coefficients, zero for the zero polynomial
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x^2 - 7x + 1").unwrap();
+    ///
+    /// assert_eq!(poly.height(), 7);
+    /// ```
+    pub fn height(&self) -> T {
+        self.into_iter()
+            .map(|m| m.get_value().abs())
+            .fold(T::zero(), |acc, v| if v > acc { v } else { acc })
+    }
+
+    /// Returns the sum of the absolute values of the coefficients
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x^2 - 7x + 1").unwrap();
+    ///
+    /// assert_eq!(poly.coeff_l1_norm(), 11);
+    /// ```
+    pub fn coeff_l1_norm(&self) -> T {
+        self.into_iter()
+            .fold(T::zero(), |acc, m| acc + m.get_value().abs())
+    }
+
+    /// Returns the euclidean norm of the coefficients, computed in [`f64`]
+    ///
+    /// Returns [`None`] if any coefficient can't be converted
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("4x^3 + 3").unwrap();
+    ///
+    /// assert_eq!(poly.coeff_l2_norm(), Some(5.0));
+    /// ```
+    pub fn coeff_l2_norm(&self) -> Option<f64> {
+        let squares = self
+            .into_iter()
+            .map(|m| m.get_value().to_f64().map(|v| v * v))
+            .sum::<Option<f64>>()?;
+
+        Some(squares.sqrt())
+    }
+
+    /// Returns the number of bits of the integer part of the [`height`](Polynomial::height),
+    /// zero for the zero polynomial
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("255x - 256").unwrap();
+    ///
+    /// assert_eq!(poly.max_coeff_bits(), 9);
+    /// ```
+    pub fn max_coeff_bits(&self) -> u32 {
+        let height = self.height();
+
+        match height.to_u128() {
+            Some(value) => u128::BITS - value.leading_zeros(),
+            None => height
+                .to_f64()
+                .map_or(u32::MAX, |v| v.log2().floor() as u32 + 1),
+        }
+    }
+
+    /// Returns the [Mahler measure](https://en.wikipedia.org/wiki/Mahler_measure)
+    /// `|a_n| Π max(1, |r_i|)` over every complex root, found all at once by the
+    /// [Durand–Kerner method](https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method)
+    ///
+    /// Returns [`None`] for the zero polynomial, if there is any negative exponent or a
+    /// coefficient can't be converted to [`f64`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // Lehmer's polynomial
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^10 + x^9 - x^7 - x^6 - x^5 - x^4 - x^3 + x + 1").unwrap();
+    ///
+    /// assert!((poly.mahler_measure().unwrap() - 1.17628081826).abs() < 1e-9);
+    /// ```
+    pub fn mahler_measure(&self) -> Option<f64> {
+        let mut coeffs = self.to_f64_coeffs()?;
+        let lead = coeffs.last()?.abs();
+
+        // Roots at zero don't count
+        let zeros = coeffs.iter().take_while(|c| **c == 0f64).count();
+        coeffs.drain(..zeros);
+
+        let roots = durand_kerner(&coeffs);
+        Some(lead * roots.iter().map(|r| r.norm().max(1f64)).product::<f64>())
+    }
+
+    /// Returns an upper bound of the absolute value of every complex root,
+    /// [Fujiwara's bound](https://en.wikipedia.org/wiki/Geometrical_properties_of_polynomial_roots#Other_bounds)
+    /// `2 max |a_(n-i) / a_n|^(1/i)`, with the last term halved
+    ///
+    /// Returns [`None`] if the polynomial is constant, there is any negative exponent or a
+    /// coefficient can't be converted to [`f64`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 5x + 6").unwrap();
+    /// let bound = poly.max_root_magnitude_bound().unwrap();
+    ///
+    /// assert!(bound >= 3.0 && bound <= 10.0);
+    /// ```
+    pub fn max_root_magnitude_bound(&self) -> Option<f64> {
+        let coeffs = self.to_f64_coeffs()?;
+        fujiwara(&coeffs)
+    }
+
+    /// Returns a lower bound of the absolute value of every complex root, the inverse of the
+    /// [`max_root_magnitude_bound`](Polynomial::max_root_magnitude_bound) of the reversed
+    /// polynomial, whose roots are the inverses. It's zero if zero is a root
+    ///
+    /// Returns [`None`] if the polynomial is constant, there is any negative exponent or a
+    /// coefficient can't be converted to [`f64`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 5x + 6").unwrap();
+    /// let bound = poly.min_root_magnitude_bound().unwrap();
+    ///
+    /// assert!(bound > 0.0 && bound <= 2.0);
+    /// ```
+    pub fn min_root_magnitude_bound(&self) -> Option<f64> {
+        let mut coeffs = self.to_f64_coeffs()?;
+        if coeffs.len() < 2 {
+            return None;
+        }
+
+        if coeffs[0] == 0f64 {
+            return Some(0f64);
+        }
+
+        coeffs.reverse();
+        fujiwara(&coeffs).map(|bound| 1f64 / bound)
+    }
+
+    pub(crate) fn to_f64_coeffs(&self) -> Option<Vec<f64>> {
+        self.to_coeffs()
+            .ok()?
+            .into_iter()
+            .map(|c| c.to_f64())
+            .collect()
+    }
+}
+
+/// Fujiwara's bound of dense coefficients in ascending order, [`None`] if constant
+fn fujiwara(coeffs: &[f64]) -> Option<f64> {
+    let n = coeffs.len().checked_sub(1).filter(|n| *n > 0)?;
+    let lead = coeffs[n];
+
+    let bound = (1..=n).fold(0f64, |acc, i| {
+        let ratio = (coeffs[n - i] / lead).abs();
+        let ratio = if i == n { ratio / 2f64 } else { ratio };
+        acc.max(ratio.powf(1f64 / i as f64))
+    });
+
+    Some(2f64 * bound)
+}
+
+/// Every complex root of dense coefficients in ascending order, improved simultaneously
+/// until they stop moving. The search runs on the [`balance`]d polynomial
+pub(crate) fn durand_kerner(coeffs: &[f64]) -> Vec<Complex<f64>> {
+    let n = coeffs.len().saturating_sub(1);
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let (coeffs, scale) = balance(coeffs.to_vec());
+    let (monic, radius) = monic_and_radius(&coeffs);
+
+    // Starting points spread on a circle, off the real axis so conjugates split
+    let seed = Complex::new(0.4f64, 0.9f64);
+    let mut roots: Vec<Complex<f64>> = (0..n)
+        .map(|k| seed.powu(k as u32 + 1) / seed.norm().powi(k as i32 + 1) * radius)
+        .collect();
+    weierstrass_steps(&monic, &mut roots, radius);
+
+    roots.into_iter().map(|r| r * scale).collect()
+}
+
+/// Same as [`durand_kerner`] starting from the given approximations, one per root. Returns
+/// [`None`] if the number of approximations doesn't match the degree or they don't converge
+pub(crate) fn durand_kerner_from(
+    coeffs: &[f64],
+    start: &[Complex<f64>],
+) -> Option<Vec<Complex<f64>>> {
+    let n = coeffs.len().saturating_sub(1);
+    if n == 0 || start.len() != n {
+        return None;
+    }
+
+    let (coeffs, scale) = balance(coeffs.to_vec());
+    let (monic, radius) = monic_and_radius(&coeffs);
+
+    // Small different turns off the real axis, real or equal approximations would never
+    // split into conjugates
+    let mut roots: Vec<Complex<f64>> = start
+        .iter()
+        .enumerate()
+        .map(|(k, r)| r / scale * Complex::new(1f64, 1e-6f64 * (k + 1) as f64))
+        .collect();
+    weierstrass_steps(&monic, &mut roots, radius).then_some(())?;
+
+    Some(roots.into_iter().map(|r| r * scale).collect())
+}
+
+/// Monic coefficients and the bound of the roots, the size of the steps
+fn monic_and_radius(coeffs: &[f64]) -> (Vec<f64>, f64) {
+    let lead = coeffs[coeffs.len() - 1];
+    let monic: Vec<f64> = coeffs.iter().map(|c| c / lead).collect();
+    let radius = fujiwara(&monic).unwrap_or(1f64).max(f64::MIN_POSITIVE);
+
+    (monic, radius)
+}
+
+/// Weierstrass iteration of every root, returns `true` if they stopped moving
+fn weierstrass_steps(monic: &[f64], roots: &mut [Complex<f64>], radius: f64) -> bool {
+    let n = roots.len();
+    for _ in 0..MAX_ITERATIONS {
+        let mut moved = 0f64;
+        for i in 0..n {
+            let value = monic
+                .iter()
+                .rev()
+                .fold(Complex::new(0f64, 0f64), |acc, c| acc * roots[i] + c);
+            let denom = (0..n)
+                .filter(|j| *j != i)
+                .fold(Complex::new(1f64, 0f64), |acc, j| {
+                    acc * (roots[i] - roots[j])
+                });
+            if denom.norm() == 0f64 {
+                continue;
+            }
+
+            let step = value / denom;
+            roots[i] -= step;
+            moved = moved.max(step.norm() / radius);
+        }
+
+        if moved < 1e-15 {
+            return true;
+        }
+    }
+
+    false
+}
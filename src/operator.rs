@@ -0,0 +1,86 @@
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Linear map of polynomials that can be stored, compared and composed as a value before
+/// applying it
+/// # Examples
+/// ```
+/// # use rust_polynomial::{DiffOp, LinearOperator, Polynomial, ShiftOp};
+/// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 2x").unwrap();
+/// // Derivative at x + 1
+/// let op = DiffOp.then(ShiftOp(1));
+///
+/// assert_eq!(format!("{}", op.apply(&poly).unwrap()), "3x^2 + 6x + 1");
+/// ```
+pub trait LinearOperator<T: MonomialValue> {
+    /// Returns the image of `poly`
+    fn apply(&self, poly: &Polynomial<T>) -> Result<Polynomial<T>, &'static str>;
+
+    /// Returns the operator that applies `self` and then `next`
+    fn then<O: LinearOperator<T>>(self, next: O) -> Compose<Self, O>
+    where
+        Self: Sized,
+    {
+        Compose(self, next)
+    }
+}
+
+/// Differentiation, `p'(x)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffOp;
+
+impl<T: MonomialValue> LinearOperator<T> for DiffOp {
+    fn apply(&self, poly: &Polynomial<T>) -> Result<Polynomial<T>, &'static str> {
+        Ok(poly.derivative())
+    }
+}
+
+/// Translation, `p(x + a)`
+///
+/// Returns an error if there is any negative exponent, like [`Polynomial::shift`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShiftOp<T>(pub T);
+
+impl<T: MonomialValue> LinearOperator<T> for ShiftOp<T> {
+    fn apply(&self, poly: &Polynomial<T>) -> Result<Polynomial<T>, &'static str> {
+        poly.shift(self.0)
+    }
+}
+
+/// Dilation, `p(ax)`
+///
+/// Integer coefficients of negative exponents are truncated by the division. Returns an
+/// error if `a` is zero and there is any negative exponent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaleOp<T>(pub T);
+
+impl<T: MonomialValue> LinearOperator<T> for ScaleOp<T> {
+    fn apply(&self, poly: &Polynomial<T>) -> Result<Polynomial<T>, &'static str> {
+        let mut monos = Vec::new();
+        for mono in poly {
+            let exp = mono.get_exp();
+            let factor = num::pow(self.0, exp.unsigned_abs() as usize);
+            let value = match exp < 0 {
+                true if factor.is_zero() => return Err("Division by zero"),
+                true => mono.get_value() / factor,
+                false => mono.get_value() * factor,
+            };
+
+            monos.push(Monomial::new(value, exp));
+        }
+
+        Ok(Polynomial::new(monos).with_var(poly.get_var()))
+    }
+}
+
+/// Composition of two operators, built with [`LinearOperator::then`]: applies the first one
+/// and then the second one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Compose<A, B>(pub A, pub B);
+
+impl<T: MonomialValue, A: LinearOperator<T>, B: LinearOperator<T>> LinearOperator<T>
+    for Compose<A, B>
+{
+    fn apply(&self, poly: &Polynomial<T>) -> Result<Polynomial<T>, &'static str> {
+        self.1.apply(&self.0.apply(poly)?)
+    }
+}
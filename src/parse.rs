@@ -0,0 +1,480 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    io::BufRead,
+};
+
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Error parsing one polynomial of a document
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    /// Line of the document where the polynomial starts, starting at `1`
+    pub line: usize,
+
+    /// Reason of the failure
+    pub message: &'static str,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Kind of a [`SyntaxError`], to tell the failures apart without comparing messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxErrorKind {
+    /// The expression has no terms, like `""` or `"+"`
+    Empty,
+
+    /// A term isn't followed by `+` or `-`
+    ExpectedSign,
+
+    /// The coefficient can't be read as the coefficient type
+    InvalidCoefficient,
+
+    /// A char that doesn't belong to the expression, like another variable
+    UnexpectedSymbol,
+
+    /// A caret without an exponent
+    MissingExponent,
+
+    /// The exponent isn't a valid integer
+    InvalidExponent,
+
+    /// A factored expression doesn't continue with `(`
+    ExpectedParenthesis,
+
+    /// A factor isn't closed with `)`
+    MissingParenthesis,
+
+    /// The power of a factor gives too big a product
+    PowerTooLarge,
+}
+
+impl SyntaxErrorKind {
+    /// Returns the message of the errors of this kind
+    pub fn message(&self) -> &'static str {
+        match self {
+            SyntaxErrorKind::Empty => "Empty expression",
+            SyntaxErrorKind::ExpectedSign => "Expected + or -",
+            SyntaxErrorKind::InvalidCoefficient => "Not valid base",
+            SyntaxErrorKind::UnexpectedSymbol => "Unexpected symbol",
+            SyntaxErrorKind::MissingExponent => "Missing exponent",
+            SyntaxErrorKind::InvalidExponent => "Not valid exponent",
+            SyntaxErrorKind::ExpectedParenthesis => "Expected (",
+            SyntaxErrorKind::MissingParenthesis => "Missing )",
+            SyntaxErrorKind::PowerTooLarge => "Power too large",
+        }
+    }
+}
+
+/// Error parsing a polynomial expression, with the position of the offending token so it can
+/// be underlined
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SyntaxError {
+    /// Column of the first char of the token, starting at `1`
+    pub column: usize,
+
+    /// Byte offset of the token
+    pub offset: usize,
+
+    /// Offending text, empty if the expression ended too soon
+    pub token: String,
+
+    /// Reason of the failure
+    pub kind: SyntaxErrorKind,
+
+    /// Reason of the failure as text, the [`message`](SyntaxErrorKind::message) of the kind
+    pub message: &'static str,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "column {}: {} `{}`",
+            self.column, self.message, self.token
+        )
+    }
+}
+
+impl Error for SyntaxError {}
+
+/// Scanner of a polynomial expression, `[sign] term (sign [sign] term)*` where a term is
+/// `[coefficient] [x [^ exponent]]`, the caret can be omitted before an unsigned exponent.
+/// Whitespace is allowed between tokens
+struct Scanner {
+    chars: Vec<(usize, char)>,
+    len: usize,
+    pos: usize,
+}
+
+impl Scanner {
+    fn new(input: &str) -> Self {
+        Scanner {
+            chars: input.char_indices().collect(),
+            len: input.len(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|(_, c)| *c)
+    }
+
+    /// Skips the whitespace, returns `true` if there was any
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+
+        self.pos > start
+    }
+
+    /// Consumes chars while `accept` holds, returns where they start
+    fn take_while<F: Fn(&Self, char) -> bool>(&mut self, accept: F) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| accept(self, c)) {
+            self.pos += 1;
+        }
+
+        start
+    }
+
+    fn text(&self, start: usize, end: usize) -> String {
+        self.chars[start..end].iter().map(|(_, c)| c).collect()
+    }
+
+    /// Error at the tokens from `start` to the current position, or at the next char if
+    /// nothing was consumed
+    fn error(&self, start: usize, kind: SyntaxErrorKind) -> SyntaxError {
+        let end = match self.pos > start {
+            true => self.pos,
+            false => (start + 1).min(self.chars.len()),
+        };
+
+        SyntaxError {
+            column: start + 1,
+            offset: self
+                .chars
+                .get(start)
+                .map_or(self.len, |(offset, _)| *offset),
+            token: self.text(start, end),
+            kind,
+            message: kind.message(),
+        }
+    }
+
+    fn parse<T: MonomialValue>(&mut self) -> Result<Vec<Monomial<T>>, SyntaxError> {
+        let mut monos = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            let Some(c) = self.peek() else {
+                break;
+            };
+
+            let mut negative = match c {
+                '+' | '-' => {
+                    self.pos += 1;
+                    c == '-'
+                }
+                _ if monos.is_empty() => false,
+                _ => return Err(self.error(self.pos, SyntaxErrorKind::ExpectedSign)),
+            };
+
+            // The sign of the term can follow the operator, `x - -3`
+            self.skip_whitespace();
+            if let Some(sign @ ('+' | '-')) = self.peek() {
+                self.pos += 1;
+                negative ^= sign == '-';
+                self.skip_whitespace();
+            }
+
+            // A dangling sign is ignored
+            if self.peek().is_none() {
+                break;
+            }
+
+            let mono: Monomial<T> = self.term()?;
+            monos.push(match negative {
+                true => -mono,
+                false => mono,
+            });
+        }
+
+        Ok(monos)
+    }
+
+    fn term<T: MonomialValue>(&mut self) -> Result<Monomial<T>, SyntaxError> {
+        let is_var = |c: char| c == 'x' || c == 'X';
+        let start = self.pos;
+
+        let coeff_start = self.take_while(|scanner, c| {
+            let number = scanner.text(start, scanner.pos);
+            let exponent_sign = ['+', '-'].contains(&c)
+                && number.starts_with(|d: char| d.is_ascii_digit() || d == '.')
+                && number.ends_with(['e', 'E']);
+
+            exponent_sign || !(c.is_whitespace() || is_var(c) || ['+', '-', '^', '*'].contains(&c))
+        });
+        let coeff = match self.pos > coeff_start {
+            true => self
+                .text(coeff_start, self.pos)
+                .parse::<T>()
+                .map_err(|_| self.error(coeff_start, SyntaxErrorKind::InvalidCoefficient))?,
+            false => T::one(),
+        };
+        let has_coeff = self.pos > coeff_start;
+
+        self.skip_whitespace();
+        if has_coeff && self.peek() == Some('*') {
+            self.pos += 1;
+            self.skip_whitespace();
+            if !self.peek().is_some_and(is_var) {
+                return Err(self.error(self.pos, SyntaxErrorKind::UnexpectedSymbol));
+            }
+        }
+
+        if !self.peek().is_some_and(is_var) {
+            return match has_coeff {
+                true => Ok(Monomial::new(coeff, 0)),
+                false => Err(self.error(self.pos, SyntaxErrorKind::UnexpectedSymbol)),
+            };
+        }
+        self.pos += 1;
+
+        // Short form without caret, `x2`
+        if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return self.exponent(coeff, self.pos);
+        }
+
+        let before_caret = self.pos;
+        self.skip_whitespace();
+        if self.peek() != Some('^') {
+            self.pos = before_caret;
+            return Ok(Monomial::new(coeff, 1));
+        }
+
+        let caret = self.pos;
+        self.pos += 1;
+        let spaced = self.skip_whitespace();
+        let exp_start = self.pos;
+        if !spaced && self.peek().is_some_and(|c| ['+', '-'].contains(&c)) {
+            self.pos += 1;
+        }
+
+        match self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            true => self.exponent(coeff, exp_start),
+            false => {
+                self.pos = caret + 1;
+                Err(self.error(caret, SyntaxErrorKind::MissingExponent))
+            }
+        }
+    }
+
+    /// Reads the digits of the exponent, its sign starts at `start`
+    fn exponent<T: MonomialValue>(
+        &mut self,
+        coeff: T,
+        start: usize,
+    ) -> Result<Monomial<T>, SyntaxError> {
+        self.take_while(|_, c| c.is_ascii_digit());
+
+        let exp = self
+            .text(start, self.pos)
+            .parse::<i32>()
+            .map_err(|_| self.error(start, SyntaxErrorKind::InvalidExponent))?;
+
+        Ok(Monomial::new(coeff, exp))
+    }
+}
+
+/// Incremental splitter of a polynomial expression in terms for the [`Scanner`], a `+` or
+/// `-` starts a new term unless it's the sign of an exponent or follows another sign
+#[derive(Default)]
+pub(crate) struct TermSplitter {
+    term: String,
+}
+
+impl TermSplitter {
+    /// Feed the next char, returns the previous term when `c` starts a new one
+    pub(crate) fn push(&mut self, c: char) -> Option<String> {
+        let mut done = None;
+        if ['-', '+'].contains(&c) && self.ends_term() {
+            done = Some(std::mem::take(&mut self.term));
+        }
+
+        self.term.push(c);
+        done
+    }
+
+    /// Returns the last term
+    pub(crate) fn finish(self) -> Option<String> {
+        (!self.term.trim().is_empty()).then_some(self.term)
+    }
+
+    /// Returns `true` if a sign after the current text is an operator
+    fn ends_term(&self) -> bool {
+        let mut chars = self.term.chars().rev();
+        let Some(last) = self.term.trim_end().chars().last() else {
+            return false;
+        };
+
+        let scientific = matches!(chars.next(), Some('e' | 'E'))
+            && chars.next().is_some_and(|c| c.is_ascii_digit() || c == '.');
+
+        !['+', '-', '^'].contains(&last) && !scientific
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Parses a polynomial expression like [`TryFrom<&str>`], but the error tells where the
+    /// offending token is
+    ///
+    /// A `+` or `-` is the sign of an exponent right after `^`, like `x^-2`, and otherwise an
+    /// operator. One more sign can follow an operator as the sign of the next term, so
+    /// `x - -3` is `x + 3`, but a third sign is an error. Floats can use scientific
+    /// notation, `1e-3x`, and a `*` can join the coefficient to the variable, `3*x^2`, so the
+    /// alternate form of `Display` is read back
+    ///
+    /// An expression without terms, blank or only a sign, is an `Empty expression` error,
+    /// [`parse_or_zero`](Polynomial::parse_or_zero) reads it as zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, SyntaxError};
+    /// let error = Polynomial::<i32>::parse("3x^ + 2").unwrap_err();
+    ///
+    /// assert_eq!(error.column, 3);
+    /// assert_eq!(error.token, "^");
+    /// assert_eq!(error.message, "Missing exponent");
+    /// assert_eq!(format!("{}", Polynomial::<i32>::parse("3x^2 + 2").unwrap()), "3x^2 + 2");
+    /// ```
+    pub fn parse(expr: &str) -> Result<Self, SyntaxError> {
+        let mut scanner = Scanner::new(expr);
+        let monos = scanner.parse()?;
+        if monos.is_empty() {
+            return Err(scanner.error(scanner.chars.len(), SyntaxErrorKind::Empty));
+        }
+
+        Ok(Polynomial::new(monos))
+    }
+
+    /// Same as [`parse`](Polynomial::parse), but an expression without terms, like `""`,
+    /// `"   "` or `"+"`, is the zero polynomial instead of an error
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, SyntaxErrorKind};
+    /// assert_eq!(Polynomial::<i32>::parse_or_zero("  "), Ok(Polynomial::zero()));
+    /// assert_eq!(Polynomial::<i32>::parse("  ").unwrap_err().kind, SyntaxErrorKind::Empty);
+    /// assert!(Polynomial::<i32>::parse_or_zero("x^").is_err());
+    /// ```
+    pub fn parse_or_zero(expr: &str) -> Result<Self, SyntaxError> {
+        match Polynomial::parse(expr) {
+            Err(error) if error.kind == SyntaxErrorKind::Empty => Ok(Polynomial::zero()),
+            result => result,
+        }
+    }
+
+    /// Parse every polynomial of a document, one per line or separated by `;`.
+    /// Blank lines and empty segments are skipped, errors are reported by line
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{ParseError, Polynomial};
+    /// let text = "x^2 - 1; 2x\n\n3y + 1";
+    /// let result: Vec<Result<Polynomial<i32>, ParseError>> = Polynomial::parse_all(text);
+    ///
+    /// assert_eq!(result.len(), 3);
+    /// assert_eq!(result[1], Ok(Polynomial::try_from("2x").unwrap()));
+    /// assert_eq!(result[2].as_ref().unwrap_err().line, 3);
+    /// ```
+    pub fn parse_all(text: &str) -> Vec<Result<Self, ParseError>> {
+        text.lines()
+            .enumerate()
+            .flat_map(|(i, line)| line.split(';').map(move |segment| (i + 1, segment)))
+            .filter(|(_, segment)| !segment.trim().is_empty())
+            .map(|(line, segment)| {
+                Polynomial::try_from(segment).map_err(|message| ParseError { line, message })
+            })
+            .collect()
+    }
+
+    /// Parse a polynomial expression reading it incrementally, only the current term and the
+    /// sum of each exponent are kept in memory
+    ///
+    /// The input is decoded as UTF-8, chars split between reads are joined. Returns an error
+    /// if the input isn't valid UTF-8
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let input = "3x^2 + 2x\n - 7 + x^2".as_bytes();
+    /// let poly: Polynomial<i32> = Polynomial::parse_from_reader(input).unwrap();
+    ///
+    /// assert_eq!(format!("{poly}"), "4x^2 + 2x - 7");
+    /// ```
+    pub fn parse_from_reader<R: BufRead>(mut reader: R) -> Result<Self, &'static str> {
+        let mut splitter = TermSplitter::default();
+        let mut sums: BTreeMap<i32, T> = BTreeMap::new();
+        let mut empty = true;
+        let mut add_term = |term: &str| -> Result<(), &'static str> {
+            let monos: Vec<Monomial<T>> = Scanner::new(term).parse().map_err(|e| e.message)?;
+            empty &= monos.is_empty();
+            for mono in monos {
+                let sum = sums.entry(mono.get_exp()).or_insert_with(T::zero);
+                *sum = *sum + mono.get_value();
+            }
+            Ok(())
+        };
+
+        // Bytes read but not decoded yet, the start of a char split between reads
+        let mut pending: Vec<u8> = Vec::new();
+        loop {
+            let buf = reader.fill_buf().map_err(|_| "Not readable input")?;
+            if buf.is_empty() {
+                break;
+            }
+
+            pending.extend_from_slice(buf);
+            let len = buf.len();
+            reader.consume(len);
+
+            let valid = match std::str::from_utf8(&pending) {
+                Ok(text) => text.len(),
+                Err(e) if e.error_len().is_none() => e.valid_up_to(),
+                Err(_) => return Err("Not valid UTF-8"),
+            };
+            let text = std::str::from_utf8(&pending[..valid]).expect("valid prefix");
+            for c in text.chars() {
+                if let Some(term) = splitter.push(c) {
+                    add_term(&term)?;
+                }
+            }
+            pending.drain(..valid);
+        }
+
+        if !pending.is_empty() {
+            return Err("Not valid UTF-8");
+        }
+
+        if let Some(term) = splitter.finish() {
+            add_term(&term)?;
+        }
+
+        if empty {
+            return Err(SyntaxErrorKind::Empty.message());
+        }
+
+        let mono_vec = sums
+            .into_iter()
+            .map(|(exp, value)| Monomial::new(value, exp))
+            .collect();
+
+        Ok(Polynomial::new(mono_vec))
+    }
+}
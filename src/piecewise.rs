@@ -0,0 +1,182 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Function defined by a different [`Polynomial`] on each interval between consecutive
+/// breakpoints. The piece `i` covers `[breakpoints[i], breakpoints[i + 1])`, the last one
+/// includes its end
+#[derive(Debug, Clone)]
+pub struct PiecewisePolynomial<T> {
+    breakpoints: Vec<T>,
+    pieces: Vec<Polynomial<T>>,
+}
+
+impl<T: MonomialValue> PiecewisePolynomial<T> {
+    /// Constructs a new `PiecewisePolynomial<T>`
+    ///  - `breakpoints`: Interval limits in strictly ascending order
+    ///  - `pieces`: One polynomial per interval, one less than breakpoints
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PiecewisePolynomial, Polynomial};
+    /// let pieces = vec![
+    ///     Polynomial::try_from("-x").unwrap(),
+    ///     Polynomial::try_from("x^2").unwrap(),
+    /// ];
+    /// let abs: PiecewisePolynomial<i32> = PiecewisePolynomial::new(vec![-5, 0, 5], pieces).unwrap();
+    ///
+    /// assert_eq!(abs.eval(-3), Some(3));
+    /// assert_eq!(abs.eval(3), Some(9));
+    /// assert_eq!(abs.eval(6), None);
+    /// ```
+    pub fn new(breakpoints: Vec<T>, pieces: Vec<Polynomial<T>>) -> Result<Self, &'static str> {
+        if pieces.is_empty() || breakpoints.len() != pieces.len() + 1 {
+            return Err("There must be one breakpoint more than pieces");
+        }
+
+        if breakpoints.windows(2).any(|w| w[0] >= w[1]) {
+            return Err("Breakpoints must be in strictly ascending order");
+        }
+
+        Ok(PiecewisePolynomial {
+            breakpoints,
+            pieces,
+        })
+    }
+
+    pub fn breakpoints(&self) -> &[T] {
+        &self.breakpoints
+    }
+
+    pub fn pieces(&self) -> &[Polynomial<T>] {
+        &self.pieces
+    }
+
+    /// Returns the index of the piece covering `x`, or [`None`] outside of the domain
+    pub fn piece_index(&self, x: T) -> Option<usize> {
+        let last = self.breakpoints.len() - 1;
+        if x < self.breakpoints[0] || x > self.breakpoints[last] {
+            return None;
+        }
+
+        let index = self.breakpoints.partition_point(|b| *b <= x);
+        Some(index.clamp(1, last) - 1)
+    }
+
+    /// Evaluates the piece covering `x`, or returns [`None`] outside of the domain
+    pub fn eval(&self, x: T) -> Option<T> {
+        self.piece_index(x).map(|i| self.pieces[i].eval(x))
+    }
+
+    /// Returns the derivative of every piece, the breakpoints are kept
+    pub fn derivative(&self) -> Self {
+        PiecewisePolynomial {
+            breakpoints: self.breakpoints.clone(),
+            pieces: self.pieces.iter().map(Polynomial::derivative).collect(),
+        }
+    }
+
+    /// Returns the continuous antiderivative that is zero at the first breakpoint, every
+    /// piece gets the constant that joins it with the end of the previous one
+    ///
+    /// Returns [`None`] if any piece has a `x^-1` term
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PiecewisePolynomial, Polynomial};
+    /// let pieces = vec![Polynomial::try_from("1").unwrap(), Polynomial::try_from("2x").unwrap()];
+    /// let poly: PiecewisePolynomial<f64> = PiecewisePolynomial::new(vec![0.0, 1.0, 2.0], pieces).unwrap();
+    /// let integral = poly.integral().unwrap();
+    ///
+    /// assert_eq!(integral.eval(1.0), Some(1.0));
+    /// assert_eq!(integral.eval(2.0), Some(4.0));
+    /// assert!(integral.is_continuous());
+    /// ```
+    pub fn integral(&self) -> Option<Self> {
+        let mut pieces: Vec<Polynomial<T>> = Vec::new();
+        let mut end = T::zero();
+
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let [start, next] = [self.breakpoints[i], self.breakpoints[i + 1]];
+            let integral = piece.integral()?;
+            let constant = Polynomial::constant(end - integral.eval(start));
+            let integral = integral + constant.with_var(piece.get_var());
+
+            end = integral.eval(next);
+            pieces.push(integral);
+        }
+
+        Some(PiecewisePolynomial {
+            breakpoints: self.breakpoints.clone(),
+            pieces,
+        })
+    }
+
+    /// Returns the distinct real roots of every piece inside its own interval, in ascending
+    /// order
+    ///
+    /// Returns an error if any piece is zero or has negative exponents
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PiecewisePolynomial, Polynomial};
+    /// let pieces = vec![Polynomial::try_from("x + 1").unwrap(), Polynomial::try_from("x - 1").unwrap()];
+    /// let poly: PiecewisePolynomial<i32> = PiecewisePolynomial::new(vec![-2, 0, 2], pieces).unwrap();
+    ///
+    /// assert_eq!(poly.roots(), Ok(vec![-1.0, 1.0]));
+    /// ```
+    pub fn roots(&self) -> Result<Vec<f64>, &'static str> {
+        let mut roots: Vec<f64> = Vec::new();
+
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let [start, end] = [self.breakpoints[i], self.breakpoints[i + 1]]
+                .map(|b| b.to_f64().ok_or("Not valid breakpoint"));
+            let (start, end) = (start?, end?);
+
+            for root in piece.roots_in_interval(start, end)? {
+                let last_piece = i == self.pieces.len() - 1;
+                if (root < end || last_piece) && roots.last() != Some(&root) {
+                    roots.push(root);
+                }
+            }
+        }
+
+        Ok(roots)
+    }
+
+    /// Returns `true` if the pieces match at every inner breakpoint
+    pub fn is_continuous(&self) -> bool {
+        self.is_continuous_to(0)
+    }
+
+    /// Returns `true` if the pieces and their derivatives up to `order` match at every inner
+    /// breakpoint, `0` checks only the pieces. Coefficients are compared exactly
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{PiecewisePolynomial, Polynomial};
+    /// let pieces = vec![Polynomial::try_from("x^2").unwrap(), Polynomial::try_from("2x - 1").unwrap()];
+    /// let poly: PiecewisePolynomial<i32> = PiecewisePolynomial::new(vec![0, 1, 2], pieces).unwrap();
+    ///
+    /// assert!(poly.is_continuous_to(1));
+    /// assert!(!poly.is_continuous_to(2));
+    /// ```
+    pub fn is_continuous_to(&self, order: usize) -> bool {
+        let mut current = self.clone();
+
+        for _ in 0..=order {
+            let joined = current.pieces.windows(2).enumerate().all(|(i, w)| {
+                let b = current.breakpoints[i + 1];
+                w[0].eval(b) == w[1].eval(b)
+            });
+
+            if !joined {
+                return false;
+            }
+
+            current = current.derivative();
+        }
+
+        true
+    }
+}
+
+impl<T: MonomialValue> PartialEq for PiecewisePolynomial<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.breakpoints == other.breakpoints && self.pieces == other.pieces
+    }
+}
@@ -1,13 +1,14 @@
 use core::panic;
 use std::{
-    collections::HashMap,
+    cmp::{Ordering, Reverse},
+    collections::BTreeMap,
     fmt::Display,
-    ops::{Add, Div, Index, Mul, Neg},
+    ops::{Add, ControlFlow, Div, Index, Mul, Neg, RangeBounds},
 };
 
 use num::{Integer, Zero};
 
-use crate::{mono::Monomial, MonomialValue};
+use crate::{mono::Monomial, roots::RootsError, tuning, MonomialValue};
 
 /// Equations differents types
 #[derive(PartialEq, Debug)]
@@ -36,10 +37,29 @@ pub enum EquationType {
     Invalid,
 }
 
+/// Progress of the divisor search of [`Polynomial::roots_with_progress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootProgress {
+    /// Degree of the polynomial being searched, it decreases as roots are deflated
+    pub degree: i32,
+
+    /// Candidate divisors checked
+    pub checked: u64,
+
+    /// Candidate divisors to check for the current degree
+    pub total: u64,
+}
+
+type ProgressFn<'a> = dyn FnMut(RootProgress) -> ControlFlow<()> + 'a;
+
 /// [Polynomial](https://en.wikipedia.org/wiki/Polynomial) representation
-#[derive(Debug, PartialEq, Clone)]
+///
+/// Two polynomials are equal when they have the same terms, the variable symbol is only used
+/// to display them
+#[derive(Debug, Clone)]
 pub struct Polynomial<T> {
     mono_vec: Vec<Monomial<T>>,
+    var: char,
 }
 
 impl<T: MonomialValue> Polynomial<T> {
@@ -56,30 +76,120 @@ impl<T: MonomialValue> Polynomial<T> {
     /// let poly: Polynomial<i32> = Polynomial::new(mono_vec);
     /// ```
     pub fn new(mono_vec: Vec<Monomial<T>>) -> Polynomial<T> {
-        let mut poly = Polynomial { mono_vec };
+        let mut poly = Polynomial { mono_vec, var: 'x' };
         poly.collapse();
         poly
     }
 
-    /// Sum all Monomials with the same exponent and collapse in a simplificated
+    /// Constructs a new `Polynomial<T>` from monomials that are already canonical, skipping
+    /// the normalization of [`Polynomial::new`]
+    ///
+    /// The caller guarantees that exponents are strictly decreasing and no value is zero.
+    /// Breaking it isn't undefined behavior, but equality, evaluation and the rest of the
+    /// operations give wrong results; debug builds check it and panic
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, Monomial};
+    /// let mono_vec: Vec<Monomial<i32>> = vec![Monomial::new(2, 2), Monomial::new(10, 0)];
+    /// let poly: Polynomial<i32> = Polynomial::from_sorted_monomials_unchecked(mono_vec.clone());
+    ///
+    /// assert_eq!(poly, Polynomial::new(mono_vec));
+    /// ```
+    pub fn from_sorted_monomials_unchecked(mono_vec: Vec<Monomial<T>>) -> Polynomial<T> {
+        debug_assert!(
+            mono_vec.windows(2).all(|w| w[0].get_exp() > w[1].get_exp()),
+            "monomials must have strictly decreasing exponents"
+        );
+        debug_assert!(
+            mono_vec.iter().all(|m| !m.get_value().is_zero()),
+            "monomials must not be zero"
+        );
+
+        Polynomial { mono_vec, var: 'x' }
+    }
+
+    /// Returns the terms, keeping the allocation
+    pub(crate) fn into_mono_vec(self) -> Vec<Monomial<T>> {
+        self.mono_vec
+    }
+
+    /// Constructs the zero polynomial
+    ///
+    /// Zero is always represented without monomials, every constructor and operation
+    /// strips the zero terms so it can be compared with `==`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x - 3x").unwrap();
+    ///
+    /// assert_eq!(poly, Polynomial::zero());
+    /// assert_eq!(Polynomial::<i32>::default(), Polynomial::zero());
+    /// assert_eq!(Polynomial::<i32>::zero().len(), 0);
+    /// ```
+    pub fn zero() -> Polynomial<T> {
+        Polynomial {
+            mono_vec: Vec::new(),
+            var: 'x',
+        }
+    }
+
+    /// Returns the constant polynomial `c`, the zero polynomial if `c` is zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::constant(7);
+    ///
+    /// assert_eq!(format!("{poly}"), "7");
+    /// assert_eq!(Polynomial::constant(0), Polynomial::<i32>::zero());
+    /// ```
+    pub fn constant(c: T) -> Polynomial<T> {
+        Polynomial::new(vec![Monomial::new(c, 0)])
+    }
+
+    /// Returns the variable symbol used to display the polynomial, `x` by default
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(poly.get_var(), 'x');
+    /// ```
+    pub fn get_var(&self) -> char {
+        self.var
+    }
+
+    /// Returns the same polynomial using another variable symbol, it doesn't take part on
+    /// comparisons
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 1").unwrap().with_var('t');
+    ///
+    /// assert_eq!(format!("{poly}"), "t^2 - 1");
+    /// ```
+    pub fn with_var(mut self, var: char) -> Self {
+        self.var = var;
+        self
+    }
+
+    /// Sum all Monomials with the same exponent and collapse in a simplificated.
+    /// Groups are kept in a [`BTreeMap`] so the terms are always summed in the same order
     fn collapse(&mut self) {
-        let mut group_by_exp: HashMap<i32, Vec<Monomial<T>>> = HashMap::new();
+        let mut group_by_exp: BTreeMap<Reverse<i32>, Vec<Monomial<T>>> = BTreeMap::new();
         for mono in self.mono_vec.iter() {
             group_by_exp
-                .entry(mono.get_exp())
-                .or_insert_with(Vec::new)
+                .entry(Reverse(mono.get_exp()))
+                .or_default()
                 .push(*mono);
         }
 
         let mut mono_vec: Vec<Monomial<T>> = group_by_exp
-            .into_iter()
-            .map(|(_, m)| m.into_iter().sum::<Monomial<T>>())
+            .into_values()
+            .map(|m| m.into_iter().sum::<Monomial<T>>())
             .collect();
 
         mono_vec.retain(|&m| m.get_value() != T::zero());
 
-        mono_vec.sort_by(|m1, m2| m2.get_exp().cmp(&m1.get_exp()));
-
         self.mono_vec = mono_vec;
     }
 
@@ -116,6 +226,132 @@ impl<T: MonomialValue> Polynomial<T> {
         self.mono_vec.len()
     }
 
+    /// Returns `true` if the Polynomial contains no Monomials
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x - 3x").unwrap();
+    ///
+    /// assert!(poly.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.mono_vec.is_empty()
+    }
+
+    /// Returns the number of terms with nonzero coefficient
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^10 + 3x - 1").unwrap();
+    ///
+    /// assert_eq!(poly.term_count(), 3);
+    /// ```
+    pub fn term_count(&self) -> usize {
+        self.into_iter()
+            .filter(|m| !m.get_value().is_zero())
+            .count()
+    }
+
+    /// Returns the ratio between the [`term_count`](Polynomial::term_count) and the number of
+    /// coefficients of a dense representation, `degree + 1`, from the lowest exponent if it's
+    /// negative. Zero for the zero polynomial
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 + 3x - 1").unwrap();
+    ///
+    /// assert_eq!(poly.density(), 0.75);
+    /// ```
+    pub fn density(&self) -> f64 {
+        let terms = self.term_count();
+        if terms == 0 {
+            return 0f64;
+        }
+
+        let high = self.max_exp().get_exp() as i64;
+        let low = self
+            .into_iter()
+            .map(|m| m.get_exp())
+            .min()
+            .unwrap_or(0)
+            .min(0) as i64;
+
+        terms as f64 / (high - low + 1) as f64
+    }
+
+    /// Returns the bytes used by the polynomial, the struct itself and the allocated
+    /// capacity of its terms
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let mut poly: Polynomial<i64> = Polynomial::try_from("x^3 + 3x - 1").unwrap();
+    /// poly.shrink_to_fit();
+    ///
+    /// assert!(poly.memory_bytes() >= 3 * std::mem::size_of::<i64>());
+    /// ```
+    pub fn memory_bytes(&self) -> usize {
+        size_of::<Self>() + self.mono_vec.capacity() * size_of::<Monomial<T>>()
+    }
+
+    /// Releases the unused capacity of the terms, like after removing terms with
+    /// [`truncate`](Polynomial::truncate)
+    pub fn shrink_to_fit(&mut self) {
+        self.mono_vec.shrink_to_fit();
+    }
+
+    /// Returns `true` if every coefficient is zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("0").unwrap();
+    ///
+    /// assert!(poly.is_zero());
+    /// assert!(!Polynomial::<i32>::try_from("x").unwrap().is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.into_iter().all(|m| m.get_value().is_zero())
+    }
+
+    /// Returns `true` if the polynomial doesn't depend on the variable, zero included
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("7").unwrap();
+    ///
+    /// assert!(poly.is_constant());
+    /// assert!(!Polynomial::<i32>::try_from("x + 7").unwrap().is_constant());
+    /// ```
+    pub fn is_constant(&self) -> bool {
+        self.into_iter()
+            .all(|m| m.get_exp() == 0 || m.get_value().is_zero())
+    }
+
+    /// Returns `true` if the leading coefficient is one
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 + 2x").unwrap();
+    ///
+    /// assert!(poly.is_monic());
+    /// assert!(!Polynomial::<i32>::try_from("2x^2 + 2x").unwrap().is_monic());
+    /// ```
+    pub fn is_monic(&self) -> bool {
+        !self.is_zero() && self.max_exp().get_value().is_one()
+    }
+
+    /// Returns `true` if the polynomial has degree one
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("2x - 1").unwrap();
+    ///
+    /// assert!(poly.is_linear());
+    /// assert!(!Polynomial::<i32>::try_from("x^2").unwrap().is_linear());
+    /// ```
+    pub fn is_linear(&self) -> bool {
+        self.max_exp().get_exp() == 1 && self.into_iter().all(|m| (0..=1).contains(&m.get_exp()))
+    }
+
     /// Returns the equation type
     /// # Examples
     /// ```
@@ -187,9 +423,10 @@ impl<T: MonomialValue> Polynomial<T> {
     ///
     /// ```
     pub fn div_mono(self, rhs: Monomial<T>) -> Self {
+        let var = self.var;
         let mono_vec = self.into_iter().map(|m| m / rhs).collect();
 
-        Polynomial::new(mono_vec)
+        Polynomial::new(mono_vec).with_var(var)
     }
 
     /// Returns a new polynomial as result of multiplying a monomial
@@ -205,9 +442,334 @@ impl<T: MonomialValue> Polynomial<T> {
     ///
     /// ```
     pub fn mul_mono(self, rhs: Monomial<T>) -> Self {
+        let var = self.var;
         let mono_vec = self.into_iter().map(|m| m * rhs).collect();
 
-        Polynomial::new(mono_vec)
+        Polynomial::new(mono_vec).with_var(var)
+    }
+
+    /// Drop every monomial with exponent greater or equal than `n`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let mut poly: Polynomial<i32> = Polynomial::try_from("x^3 + 2x^2 + 3x + 4").unwrap();
+    ///
+    /// poly.truncate(2);
+    ///
+    /// assert_eq!(format!("{poly}"), "3x + 4");
+    /// ```
+    pub fn truncate(&mut self, n: i32) {
+        self.mono_vec.retain(|m| m.get_exp() < n);
+    }
+
+    /// Returns a new polynomial as result of `p mod x^n`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 + 2x^2 + 3x + 4").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.rem_xn(3)), "2x^2 + 3x + 4");
+    /// assert_eq!(format!("{}", poly.rem_xn(0)), "0");
+    /// ```
+    pub fn rem_xn(&self, n: i32) -> Self {
+        let mut rem = self.clone();
+        rem.truncate(n);
+        rem
+    }
+
+    /// Returns the polynomial of every `k`-th coefficient starting at `offset`,
+    /// `sum a_(offset + j k) x^j`, so `p(x) = sum x^r q_r(x^k)` over the offsets `0..k`. The
+    /// even and odd parts are the strides `(2, 0)` and `(2, 1)`
+    ///
+    /// Returns an error if `k` isn't positive or an exponent of the result doesn't fit in
+    /// [`i32`], like `x^2147483647` with `(1, -1)`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^5 + 2x^4 + 3x^3 + 4x^2 + 5x + 6").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.coeffs_stride(2, 0).unwrap()), "2x^2 + 4x + 6");
+    /// assert_eq!(format!("{}", poly.coeffs_stride(2, 1).unwrap()), "x^2 + 3x + 5");
+    /// assert_eq!(format!("{}", poly.coeffs_stride(3, 2).unwrap()), "x + 4");
+    /// ```
+    pub fn coeffs_stride(&self, k: i32, offset: i32) -> Result<Self, &'static str> {
+        if k <= 0 {
+            return Err("The stride must be positive");
+        }
+
+        let mono_vec = self
+            .into_iter()
+            .filter(|m| (m.get_exp() as i64 - offset as i64).rem_euclid(k as i64) == 0)
+            .map(|m| {
+                let exp = (m.get_exp() as i64 - offset as i64).div_euclid(k as i64);
+                let exp = i32::try_from(exp).map_err(|_| "The exponents overflow")?;
+                Ok(Monomial::new(m.get_value(), exp))
+            })
+            .collect::<Result<_, &'static str>>()?;
+
+        Ok(Polynomial::new(mono_vec).with_var(self.var))
+    }
+
+    /// Returns the terms whose exponent is in `range`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^4 + 2x^3 + 3x^2 + 4x + 5").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.restrict_degrees(1..3)), "3x^2 + 4x");
+    /// assert_eq!(format!("{}", poly.restrict_degrees(3..)), "x^4 + 2x^3");
+    /// ```
+    pub fn restrict_degrees<R: RangeBounds<i32>>(&self, range: R) -> Self {
+        let mono_vec = self
+            .into_iter()
+            .filter(|m| range.contains(&m.get_exp()))
+            .copied()
+            .collect();
+
+        Polynomial::new(mono_vec).with_var(self.var)
+    }
+
+    /// Returns the polynomial `g` such that `p * g mod x^n == 1`, computed by Newton iteration
+    ///
+    /// The constant term must be invertible in `T`, for integer coefficients only `1` and `-1`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("1 - x").unwrap();
+    /// let inverse = poly.inverse_mod_xn(5).unwrap();
+    ///
+    /// assert_eq!(format!("{inverse}"), "x^4 + x^3 + x^2 + x + 1");
+    /// assert!(Polynomial::<i32>::try_from("x + 2").unwrap().inverse_mod_xn(5).is_err());
+    /// ```
+    pub fn inverse_mod_xn(&self, n: i32) -> Result<Self, &'static str> {
+        let constant = self.find_by_exp(0).get_value();
+        if constant.is_zero() || (T::one() / constant) * constant != T::one() {
+            return Err("Constant term is not invertible");
+        }
+
+        if n <= 0 {
+            return Ok(Polynomial::zero().with_var(self.var));
+        }
+
+        let two = Polynomial::new(vec![Monomial::new(T::one() + T::one(), 0)]);
+        let mut inverse = Polynomial::new(vec![Monomial::new(T::one() / constant, 0)]);
+        let mut precision = 1;
+
+        while precision < n {
+            precision = (precision * 2).min(n);
+
+            let product = (self.rem_xn(precision) * inverse.clone()).rem_xn(precision);
+            inverse = (inverse * (two.clone() + product.neg())).rem_xn(precision);
+        }
+
+        Ok(inverse.with_var(self.var))
+    }
+
+    /// Returns the expression written with `x` as variable, parsing it back with
+    /// [`TryFrom<&str>`] always results in an equal polynomial for every coefficient type,
+    /// floats included. `NaN` and the minimum value of signed integers can't be written
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::builder()
+    ///     .term(1.5, 2)
+    ///     .term(-0.1, -3)
+    ///     .var('t')
+    ///     .build();
+    /// let canonical = poly.to_canonical_string();
+    ///
+    /// assert_eq!(canonical, "1.5x^2 - 0.1x^-3");
+    /// assert_eq!(Polynomial::try_from(canonical.as_str()), Ok(poly));
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        self.clone().with_var('x').to_string()
+    }
+
+    /// Evaluates the polynomial at `x`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 5x + 6").unwrap();
+    ///
+    /// assert_eq!(poly.eval(1), 2);
+    /// assert_eq!(poly.eval(3), 0);
+    /// ```
+    pub fn eval(&self, x: T) -> T {
+        self.into_iter()
+            .map(|m| m.eval(x))
+            .fold(T::zero(), |acc, v| acc + v)
+    }
+
+    /// Evaluates the polynomial at a value of another ring by Horner's method, like a complex
+    /// number, an interval or a matrix, converting every coefficient with [`From`]
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// use num::Complex;
+    ///
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^2 + 1").unwrap();
+    ///
+    /// assert_eq!(poly.eval_as(Complex::new(0.0, 1.0)), Ok(Complex::new(0.0, 0.0)));
+    /// assert_eq!(Polynomial::<i32>::try_from("x^2 - 2").unwrap().eval_as(0.5f64), Ok(-1.75));
+    /// ```
+    pub fn eval_as<X>(&self, x: X) -> Result<X, &'static str>
+    where
+        X: From<T> + Add<Output = X> + Mul<Output = X> + Clone,
+    {
+        let coeffs = self.to_coeffs()?;
+        let Some((lead, rest)) = coeffs.split_last() else {
+            return Ok(X::from(T::zero()));
+        };
+
+        Ok(rest
+            .iter()
+            .rev()
+            .fold(X::from(*lead), |acc, c| acc * x.clone() + X::from(*c)))
+    }
+
+    /// Evaluates the polynomial at `a` by synthetic division by `x - a`, returning the pair
+    /// `(p(a), quotient)`. `p(a)` is the remainder of the division and the quotient is ready
+    /// to deflate a root
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    /// let (value, quotient) = poly.eval_via_remainder(1).unwrap();
+    ///
+    /// assert_eq!(value, 0);
+    /// assert_eq!(format!("{quotient}"), "x^2 - 5x + 6");
+    /// ```
+    pub fn eval_via_remainder(&self, a: T) -> Result<(T, Self), &'static str> {
+        let coeffs = self.to_coeffs()?;
+
+        let mut value = T::zero();
+        let mut quotient: Vec<T> = vec![T::zero(); coeffs.len().saturating_sub(1)];
+        for (exp, coeff) in coeffs.iter().enumerate().rev() {
+            if exp < quotient.len() {
+                quotient[exp] = value;
+            }
+            value = value * a + *coeff;
+        }
+
+        Ok((value, Polynomial::from_coeffs(&quotient).with_var(self.var)))
+    }
+
+    /// Returns the derivative of the polynomial
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 5x + 6").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.derivative()), "3x^2 - 5");
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mono_vec = self.into_iter().map(Monomial::derivative).collect();
+
+        Polynomial::new(mono_vec).with_var(self.var)
+    }
+
+    /// Returns the antiderivative with zero constant, or [`None`] if there is a `x^-1` term
+    ///
+    /// Integer coefficients are truncated by the division
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x^2 - 4x + 6").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.integral().unwrap()), "x^3 - 2x^2 + 6x");
+    /// ```
+    pub fn integral(&self) -> Option<Self> {
+        let mono_vec = self
+            .into_iter()
+            .map(Monomial::integral)
+            .collect::<Option<Vec<Monomial<T>>>>()?;
+
+        Some(Polynomial::new(mono_vec).with_var(self.var))
+    }
+
+    /// Check if the polynomial is the [`derivative`](Polynomial::derivative) of `other`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i32> = Polynomial::try_from("3x^2 - 4").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^3 - 4x + 7").unwrap();
+    ///
+    /// assert!(p.is_derivative_of(&q));
+    /// assert!(!q.is_derivative_of(&p));
+    /// ```
+    pub fn is_derivative_of(&self, other: &Self) -> bool {
+        *self == other.derivative()
+    }
+
+    /// Check if the polynomial is an antiderivative of `other`, for any integration constant.
+    /// Unlike comparing with [`integral`](Polynomial::integral) it isn't affected by the
+    /// truncation of integer coefficients
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i32> = Polynomial::try_from("2x + 1").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^2 + x - 10").unwrap();
+    ///
+    /// assert!(q.antiderivative_matches(&p));
+    /// assert!(!p.antiderivative_matches(&q));
+    /// ```
+    pub fn antiderivative_matches(&self, other: &Self) -> bool {
+        other.is_derivative_of(self)
+    }
+
+    /// Returns the stationary points `(x, p(x))` of the polynomial, that is, the roots of
+    /// its [`derivative`](Polynomial::derivative)
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 4x + 1").unwrap();
+    ///
+    /// assert_eq!(poly.extrema(), Some(vec![(2, -3)]));
+    /// ```
+    pub fn extrema(&self) -> Option<Vec<(T, T)>> {
+        let roots = self.derivative().roots()?;
+
+        Some(roots.into_iter().map(|x| (x, self.eval(x))).collect())
+    }
+
+    /// Returns the factorization of the polynomial as the product of its constant or
+    /// non-reducible part followed by the linear factors `x - r` of every exact root,
+    /// repeated by multiplicity
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("2x^3 - 2x^2 - 32x - 40").unwrap();
+    /// let factors: Vec<String> = poly.factors().iter().map(|f| format!("{f}")).collect();
+    ///
+    /// assert_eq!(factors, vec!["2", "x + 2", "x + 2", "x - 5"]);
+    /// ```
+    pub fn factors(&self) -> Vec<Polynomial<T>> {
+        let mut rest = self.clone();
+        let mut factors: Vec<Polynomial<T>> = Vec::new();
+
+        for root in self.roots().unwrap_or_default() {
+            let factor = Polynomial::new(vec![Monomial::new(T::one(), 1), Monomial::new(-root, 0)]);
+
+            loop {
+                let (quotient, remainder) = rest.clone() / factor.clone();
+                if !remainder.is_zero() {
+                    break;
+                }
+
+                factors.push(factor.clone());
+                rest = quotient;
+            }
+        }
+
+        if factors.is_empty() || rest != Polynomial::new(vec![Monomial::new(T::one(), 0)]) {
+            factors.insert(0, rest);
+        }
+
+        factors
     }
 
     /// Returns an [`Option`] containing the roots of the equation
@@ -220,20 +782,75 @@ impl<T: MonomialValue> Polynomial<T> {
     /// assert_eq!(poly.roots(), Some(vec![9]));
     /// ```
     pub fn roots(&self) -> Option<Vec<T>> {
-        match self.equation_type() {
+        self.roots_with_progress(|_| ControlFlow::Continue(()))
+            .unwrap_or_default()
+    }
+
+    /// Same as [`roots`](Polynomial::roots), reporting the progress of the divisor search used
+    /// for [`EquationType::BigExp`] equations. Returning [`ControlFlow::Break`] from `progress`
+    /// aborts the search with an error
+    /// # Examples
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    ///
+    /// let mut calls = 0;
+    /// let roots = poly.roots_with_progress(|progress| {
+    ///     calls += 1;
+    ///     assert!(progress.checked <= progress.total);
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!(roots, Ok(Some(vec![1, 2, 3])));
+    /// assert!(calls > 0);
+    ///
+    /// let cancelled = poly.roots_with_progress(|_| ControlFlow::Break(()));
+    /// assert!(cancelled.is_err());
+    /// ```
+    pub fn roots_with_progress<F>(&self, mut progress: F) -> Result<Option<Vec<T>>, &'static str>
+    where
+        F: FnMut(RootProgress) -> ControlFlow<()>,
+    {
+        self.roots_inner(&mut progress)
+    }
+
+    fn roots_inner(&self, progress: &mut ProgressFn) -> Result<Option<Vec<T>>, &'static str> {
+        // Zero is a root when there is no constant term, the solvers get `p / x^k`
+        let low = self.mono_vec.last().map_or(0, |m| m.get_exp());
+        if low > 0 {
+            let reduced = self.clone().div_mono(Monomial::new(T::one(), low));
+            let mut roots = match reduced.roots_inner(progress)? {
+                Some(roots) => roots,
+                // Only zero is left when `p / x^k` is a constant or has no real roots, other
+                // failures mean there may be roots that weren't found
+                None if reduced.max_exp().get_exp() == 0 => Vec::new(),
+                None if reduced.roots_failure() == RootsError::NoRealRoots => Vec::new(),
+                None => return Ok(None),
+            };
+            if !roots.contains(&T::zero()) {
+                roots.push(T::zero());
+                roots.sort_by(total_cmp);
+            }
+
+            return Ok(Some(roots));
+        }
+
+        let roots = match self.equation_type() {
             EquationType::Linear => Polynomial::<T>::linear_root(self),
             EquationType::Quadratic => Polynomial::<T>::quadratic_root(self),
             EquationType::Biquadratic => Polynomial::<T>::biquadratic_root(self),
             EquationType::BigExp2Terms => Polynomial::<T>::big_exp2_root(self),
-            EquationType::BigExp => Polynomial::<T>::big_exp_root(self),
+            EquationType::BigExp => return Polynomial::<T>::big_exp_root(self, progress),
             EquationType::Invalid => None,
-        }
+        };
+
+        Ok(roots)
     }
 
     fn linear_root(poly: &Self) -> Option<Vec<T>> {
         let len = poly.into_iter().len();
 
-        if len > 2 || len < 1 {
+        if !(1..=2).contains(&len) {
             panic!("{poly} is not a linear equation");
         }
 
@@ -262,6 +879,10 @@ impl<T: MonomialValue> Polynomial<T> {
             let mut linear = poly.clone();
             linear.mono_vec[0].exp = 1;
             let linear_result = Polynomial::<T>::linear_root(&linear)?[0].to_f64()?;
+            if linear_result < 0f64 {
+                return None;
+            }
+
             let sqrt = T::from(linear_result.sqrt())?;
 
             if sqrt.is_zero() {
@@ -271,7 +892,12 @@ impl<T: MonomialValue> Polynomial<T> {
             return Some(vec![sqrt.neg(), sqrt]);
         }
 
-        let sqrt = ((b * b) - (4f64 * a * c)).sqrt();
+        let discriminant = (b * b) - (4f64 * a * c);
+        if discriminant < 0f64 {
+            return None;
+        }
+
+        let sqrt = discriminant.sqrt();
 
         let result_1 = T::from((b.neg() + sqrt) / (2f64 * a))?;
         let result_2 = T::from((b.neg() - sqrt) / (2f64 * a))?;
@@ -281,7 +907,7 @@ impl<T: MonomialValue> Polynomial<T> {
         }
 
         let mut result = vec![result_1, result_2];
-        result.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        result.sort_by(total_cmp);
 
         Some(result)
     }
@@ -300,7 +926,12 @@ impl<T: MonomialValue> Polynomial<T> {
         let quadrtic_result = Polynomial::<T>::quadratic_root(&quadratic)?;
 
         if quadrtic_result.len() == 1 {
-            let result = T::from(quadrtic_result[0].to_f64()?.sqrt())?;
+            let square = quadrtic_result[0].to_f64()?;
+            if square < 0f64 {
+                return None;
+            }
+
+            let result = T::from(square.sqrt())?;
 
             return Some(vec![result.neg(), result]);
         }
@@ -316,7 +947,7 @@ impl<T: MonomialValue> Polynomial<T> {
                 let r2_1 = sqrt_converter(r2)?;
                 vec![r1_1.neg(), r2_1.neg(), r1_1, r2_1]
             };
-            result.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            result.sort_by(total_cmp);
 
             return Some(result);
         }
@@ -347,13 +978,16 @@ impl<T: MonomialValue> Polynomial<T> {
         }
 
         let mut result = vec![result_val.neg(), result_val];
-        result.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        result.sort_by(total_cmp);
 
         Some(result)
     }
 
-    fn big_exp_root(poly: &Self) -> Option<Vec<T>> {
-        let (root, rest) = Polynomial::<T>::find_root(poly);
+    fn big_exp_root(
+        poly: &Self,
+        progress: &mut ProgressFn,
+    ) -> Result<Option<Vec<T>>, &'static str> {
+        let (root, rest) = Polynomial::<T>::find_root(poly, progress)?;
 
         let mut roots: Vec<T> = Vec::new();
 
@@ -362,7 +996,7 @@ impl<T: MonomialValue> Polynomial<T> {
         }
 
         if let Some(poly) = rest {
-            if let Some(r) = poly.roots() {
+            if let Some(r) = poly.roots_inner(progress)? {
                 let generic: Vec<T> = r.into_iter().map(T::from).map(|o| o.unwrap()).collect();
 
                 roots.extend(generic);
@@ -370,19 +1004,32 @@ impl<T: MonomialValue> Polynomial<T> {
         }
 
         if roots.is_empty() {
-            return None;
+            return Ok(None);
         }
 
-        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        roots.sort_by(total_cmp);
 
-        Some(roots)
+        Ok(Some(roots))
     }
 
-    fn find_divs(value: u64) -> Vec<i64> {
+    fn find_divs(
+        value: u64,
+        degree: i32,
+        progress: &mut ProgressFn,
+    ) -> Result<Vec<i64>, &'static str> {
         let mut divs: Vec<i64> = Vec::new();
 
         for v in 1..=value {
-            if value % v == 0 {
+            let report = RootProgress {
+                degree,
+                checked: v,
+                total: value,
+            };
+            if progress(report).is_break() {
+                return Err("Root finding cancelled");
+            }
+
+            if value.is_multiple_of(v) {
                 divs.push(v as i64);
             }
         }
@@ -390,31 +1037,38 @@ impl<T: MonomialValue> Polynomial<T> {
         let negative: Vec<_> = divs.iter().map(|d| d.neg()).collect();
         divs.extend(negative);
 
-        divs
+        Ok(divs)
     }
 
-    fn find_root(poly: &Self) -> (Option<i64>, Option<Polynomial<i64>>) {
+    fn find_root(
+        poly: &Self,
+        progress: &mut ProgressFn,
+    ) -> Result<(Option<i64>, Option<Polynomial<i64>>), &'static str> {
         let root_base = match poly.find_by_exp(0).get_value().abs().to_u64() {
             Some(rb) => rb,
-            None => return (None, None),
+            None => return Ok((None, None)),
         };
 
-        let divs = Polynomial::<T>::find_divs(root_base);
+        let divs = Polynomial::<T>::find_divs(root_base, poly.max_exp().get_exp(), progress)?;
 
-        let mut target: Polynomial<i64> = Polynomial::default();
+        let mut target: Polynomial<i64> = Polynomial::zero();
         let mut root: Option<i64> = None;
 
         'div_loop: for div in divs {
             let max_exp = poly.max_exp().get_exp();
             let mut current = 0i64;
-            let mut current_poly: Polynomial<i64> = Polynomial::default();
+            let mut current_poly: Polynomial<i64> = Polynomial::zero();
             for (i, exp) in (0..=max_exp).rev().enumerate() {
                 let mono_val = match poly.find_by_exp(exp).get_value().to_i64() {
                     Some(val) => val,
-                    None => return (None, None),
+                    None => return Ok((None, None)),
                 };
 
-                let sum = mono_val + current;
+                // On overflow `div` isn't a root or the quotient doesn't fit in `i64`, either way
+                // it can't be deflated here so the divisor is skipped instead of wrapping
+                let Some(sum) = mono_val.checked_add(current) else {
+                    continue 'div_loop;
+                };
 
                 current_poly.push_raw(Monomial::new(sum, exp - 1));
 
@@ -425,25 +1079,34 @@ impl<T: MonomialValue> Polynomial<T> {
                     break 'div_loop;
                 }
 
-                current = sum * div;
+                current = match sum.checked_mul(div) {
+                    Some(current) => current,
+                    None => continue 'div_loop,
+                };
             }
         }
 
         if root.is_none() {
-            return (None, None);
+            return Ok((None, None));
         }
 
-        if target == Polynomial::<i64>::default() {
-            return (root, None);
+        if target.is_zero() {
+            return Ok((root, None));
         }
 
-        (root, Some(target))
+        Ok((root, Some(target)))
     }
 }
 
 impl<T: MonomialValue> Default for Polynomial<T> {
     fn default() -> Self {
-        Polynomial::new(vec![Monomial::default()])
+        Polynomial::zero()
+    }
+}
+
+impl<T: MonomialValue> From<Monomial<T>> for Polynomial<T> {
+    fn from(value: Monomial<T>) -> Self {
+        Polynomial::new(vec![value])
     }
 }
 
@@ -453,42 +1116,12 @@ impl<T: MonomialValue> From<Vec<Monomial<T>>> for Polynomial<T> {
     }
 }
 
+/// Parses a polynomial expression, see [`Polynomial::parse`] to know where it failed
 impl<T: MonomialValue> TryFrom<&str> for Polynomial<T> {
     type Error = &'static str;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let clean_value = value.trim().replace(" ", "");
-
-        if clean_value.len() == 1 {
-            let mono = Monomial::try_from(&clean_value as &str)?;
-            return Ok(Polynomial::new(vec![mono]));
-        }
-
-        let mut mono_vec: Vec<Monomial<T>> = Vec::new();
-        let mut tmp_mono_split = String::new();
-        for (i, char) in clean_value.char_indices() {
-            if i == 0 {
-                tmp_mono_split.push(char);
-                continue;
-            }
-
-            if vec!['-', '+'].contains(&char) {
-                mono_vec.push(Monomial::try_from(&tmp_mono_split as &str)?);
-                tmp_mono_split.clear();
-                tmp_mono_split.push(char);
-                continue;
-            }
-
-            if i == clean_value.len() - 1 {
-                tmp_mono_split.push(char);
-                mono_vec.push(Monomial::try_from(&tmp_mono_split as &str)?);
-                continue;
-            }
-
-            tmp_mono_split.push(char);
-        }
-
-        Ok(Polynomial::new(mono_vec))
+        Polynomial::parse(value).map_err(|error| error.message)
     }
 }
 
@@ -496,9 +1129,10 @@ impl<T: MonomialValue> Neg for Polynomial<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
+        let var = self.var;
         let mono_vec = self.into_iter().map(Monomial::neg).collect();
 
-        Polynomial::new(mono_vec)
+        Polynomial::new(mono_vec).with_var(var)
     }
 }
 
@@ -506,7 +1140,7 @@ impl<T: MonomialValue> Add for Polynomial<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Polynomial::new([self.mono_vec.clone(), rhs.mono_vec.clone()].concat())
+        Polynomial::new([self.mono_vec.clone(), rhs.mono_vec.clone()].concat()).with_var(self.var)
     }
 }
 
@@ -514,27 +1148,52 @@ impl<T: MonomialValue> Mul for Polynomial<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut result: Vec<Monomial<T>> = Vec::new();
-        for self_mono in &self {
-            for rhs_mono in &rhs {
-                result.push(*self_mono * *rhs_mono);
-            }
-        }
-
-        Polynomial::new(result)
+        Polynomial::<T>::mul_dispatch(&self, &rhs)
     }
 }
 
+/// Long division returning the quotient and the remainder, with the rules of
+/// [`checked_div`](Polynomial::checked_div) for negative exponents
+///
+/// # Panics
+/// If the divisor is zero or the exponent of the quotient overflows [`i32`], use
+/// [`checked_div`](Polynomial::checked_div) to get an error instead
 impl<T: MonomialValue> Div for Polynomial<T> {
     type Output = (Self, Self);
 
     fn div(self, rhs: Self) -> Self::Output {
-        let mut dividend = self;
-        let divider = rhs;
-        let mut quotient: Polynomial<T> = Polynomial::default();
+        assert!(!rhs.is_zero(), "attempt to divide by a zero polynomial");
+
+        let quotient_exp = self
+            .max_exp()
+            .get_exp()
+            .checked_sub(rhs.max_exp().get_exp())
+            .expect("exponent overflow in the quotient");
 
-        while dividend.max_exp().get_exp() >= divider.max_exp().get_exp() {
+        if quotient_exp >= 0 && quotient_exp as usize >= tuning::newton_division_threshold() {
+            if let Some(result) = Polynomial::<T>::newton_div(&self, &rhs) {
+                return result;
+            }
+        }
+
+        Polynomial::<T>::classical_div(self, rhs)
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Long division, one monomial of the quotient per iteration
+    fn classical_div(dividend: Self, divider: Self) -> (Self, Self) {
+        let mut dividend = dividend;
+        let mut quotient: Polynomial<T> = Polynomial::zero().with_var(dividend.var);
+
+        while !dividend.is_zero() && dividend.max_exp().get_exp() >= divider.max_exp().get_exp() {
             let result = dividend.max_exp() / divider.max_exp();
+
+            // Not divisible in `T`, like `x` by `2x` with integer coefficients
+            if result.get_value().is_zero() {
+                break;
+            }
+
             quotient.push_raw(result);
 
             dividend = dividend.clone() + (divider.clone().mul_mono(result)).neg();
@@ -544,28 +1203,88 @@ impl<T: MonomialValue> Div for Polynomial<T> {
 
         (quotient, dividend)
     }
-}
 
-impl<T: MonomialValue> TryFrom<Vec<T>> for Polynomial<T> {
-    type Error = &'static str;
+    /// Division multiplying the reversed dividend by the inverse of the reversed divider
+    /// `mod x^n`, returns [`None`] if the leading coefficient of the divider isn't invertible
+    /// or there are negative exponents
+    fn newton_div(dividend: &Self, divider: &Self) -> Option<(Self, Self)> {
+        if dividend.into_iter().chain(divider).any(|m| m.get_exp() < 0) {
+            return None;
+        }
 
-    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
-        let mut mono_vec: Vec<Monomial<T>> = Vec::new();
+        let dividend_exp = dividend.max_exp().get_exp();
+        let divider_exp = divider.max_exp().get_exp();
+        let len = dividend_exp - divider_exp + 1;
 
-        for (i, v) in value.iter().enumerate() {
-            if *v == T::zero() {
-                continue;
-            }
+        let inverse = divider.reverse(divider_exp).inverse_mod_xn(len).ok()?;
+        let quotient = (dividend.reverse(dividend_exp).rem_xn(len) * inverse)
+            .rem_xn(len)
+            .reverse(len - 1);
+        let remainder = dividend.clone() + (divider.clone() * quotient.clone()).neg();
 
-            let exp = value.len() - 1 - i;
-            mono_vec.push(Monomial::new(*v as T, exp as i32));
-        }
+        Some((quotient, remainder))
+    }
 
-        if mono_vec.is_empty() {
-            return Ok(Polynomial::default());
-        }
+    /// Returns `x^exp * p(1/x)`
+    fn reverse(&self, exp: i32) -> Self {
+        let mono_vec = self
+            .into_iter()
+            .map(|m| Monomial::new(m.get_value(), exp - m.get_exp()))
+            .collect();
 
-        Ok(Polynomial::new(mono_vec))
+        Polynomial::new(mono_vec).with_var(self.var)
+    }
+}
+
+/// Coefficients in descending order, the last one is the constant term. Use
+/// [`from_coeffs`](Polynomial::from_coeffs) for ascending order
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let poly: Polynomial<i32> = Polynomial::from(vec![3, 0, -1]);
+///
+/// assert_eq!(format!("{poly}"), "3x^2 - 1");
+/// ```
+impl<T: MonomialValue> From<Vec<T>> for Polynomial<T> {
+    fn from(value: Vec<T>) -> Self {
+        let mono_vec = value
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(exp, v)| Monomial::new(*v, exp as i32))
+            .collect();
+
+        Polynomial::new(mono_vec)
+    }
+}
+
+/// Constant polynomials from primitive numbers, same as [`Polynomial::constant`]. A generic
+/// `From<T>` would conflict with `TryFrom<&str>`
+macro_rules! impl_from_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Polynomial<$t> {
+                fn from(value: $t) -> Self {
+                    Polynomial::constant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_scalar!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Single term from its coefficient and exponent
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let poly: Polynomial<i32> = Polynomial::from((-4, 3));
+///
+/// assert_eq!(format!("{poly}"), "-4x^3");
+/// ```
+impl<T: MonomialValue> From<(T, i32)> for Polynomial<T> {
+    fn from((value, exp): (T, i32)) -> Self {
+        Polynomial::from(Monomial::new(value, exp))
     }
 }
 
@@ -595,6 +1314,78 @@ impl<'a, T: MonomialValue> IntoIterator for &'a Polynomial<T> {
     }
 }
 
+impl<T: MonomialValue> PartialEq for Polynomial<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mono_vec == other.mono_vec
+    }
+}
+
+impl<T: MonomialValue + Eq> Eq for Polynomial<T> {}
+
+impl<T: MonomialValue + Ord> PartialOrd for Polynomial<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Graded lexicographic order: the zero polynomial is the smallest, then polynomials are
+/// compared by degree and ties are broken by the coefficients from the highest exponent
+/// down, a missing term counts as zero. The variable is ignored like in `==`
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let mut polys: Vec<Polynomial<i32>> = ["x^2 - 1", "-x^3", "0", "x^2 + x", "5"]
+///     .iter()
+///     .map(|p| Polynomial::try_from(*p).unwrap())
+///     .collect();
+/// polys.sort();
+///
+/// let sorted: Vec<String> = polys.iter().map(|p| p.to_string()).collect();
+/// assert_eq!(sorted, ["0", "5", "x^2 - 1", "x^2 + x", "-x^3"]);
+/// ```
+impl<T: MonomialValue + Ord> Ord for Polynomial<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let degree = |p: &Self| p.mono_vec.first().map(|m| m.get_exp());
+        let by_degree = degree(self).cmp(&degree(other));
+        if by_degree != Ordering::Equal {
+            return by_degree;
+        }
+
+        let (mut lhs, mut rhs) = (self.into_iter().peekable(), other.into_iter().peekable());
+        loop {
+            let (a, b) = match (lhs.peek(), rhs.peek()) {
+                (None, None) => return Ordering::Equal,
+                (Some(a), Some(b)) if a.get_exp() == b.get_exp() => (
+                    lhs.next().map(Monomial::get_value),
+                    rhs.next().map(Monomial::get_value),
+                ),
+                (Some(a), b) if b.is_none_or(|b| a.get_exp() > b.get_exp()) => {
+                    (lhs.next().map(Monomial::get_value), Some(T::zero()))
+                }
+                _ => (Some(T::zero()), rhs.next().map(Monomial::get_value)),
+            };
+
+            match a.cmp(&b) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+    }
+}
+
+/// Writes the terms from the highest exponent, `3x^2 - x + 5`
+///
+/// Width and precision flags format every coefficient, and the alternate flag `{:#}` writes a
+/// strict form for parsers, with explicit `*`, exponents and signs and no spaces
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let poly: Polynomial<f64> = Polynomial::try_from("1.5x^2 - x + 5").unwrap();
+///
+/// assert_eq!(format!("{poly:#}"), "1.5*x^2-1*x^1+5*x^0");
+/// assert_eq!(format!("{poly:.2}"), "1.50x^2 - x + 5.00");
+/// assert_eq!(Polynomial::try_from(format!("{poly:#}").as_str()), Ok(poly));
+/// ```
 impl<T: MonomialValue> Display for Polynomial<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.mono_vec.is_empty() {
@@ -604,17 +1395,28 @@ impl<T: MonomialValue> Display for Polynomial<T> {
 
         for (i, mono) in self.mono_vec.iter().enumerate() {
             let sign = match mono.get_value() < T::zero() {
-                true if i == 0 => "-".to_string(),
-                true => " - ".to_string(),
-                false if i == 0 => "".to_string(),
-                false => " + ".to_string(),
+                true if i == 0 || f.alternate() => "-",
+                true => " - ",
+                false if i == 0 => "",
+                false if f.alternate() => "+",
+                false => " + ",
             };
 
             let mono_abs = Monomial::new(mono.get_value().abs(), mono.get_exp());
 
-            write!(f, "{sign}{mono_abs}")?;
+            write!(f, "{sign}")?;
+            mono_abs.fmt_var(f, self.var)?;
         }
 
         Ok(())
     }
 }
+
+/// Total order of the coefficients, values without partial order like `NaN` are compared
+/// with [`f64::total_cmp`] so sorting never panics
+pub(crate) fn total_cmp<T: MonomialValue>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let [a, b] = [a, b].map(|v| v.to_f64().unwrap_or(f64::NAN));
+        a.total_cmp(&b)
+    })
+}
@@ -0,0 +1,105 @@
+use num::Float;
+
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Max Newton steps to polish a node
+const POLISH_STEPS: usize = 20;
+
+impl<T: MonomialValue + Float> Polynomial<T> {
+    /// Returns the [Legendre polynomial](https://en.wikipedia.org/wiki/Legendre_polynomials)
+    /// `P_n`, orthogonal on `[-1, 1]`, from Bonnet's recurrence
+    /// `(k + 1) P_(k+1) = (2k + 1) x P_k - k P_(k-1)`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::legendre(3);
+    ///
+    /// assert_eq!(format!("{poly}"), "2.5x^3 - 1.5x");
+    /// ```
+    pub fn legendre(n: u32) -> Self {
+        let x = Polynomial::from((T::one(), 1));
+        let (mut previous, mut current) = (Polynomial::zero(), Polynomial::constant(T::one()));
+
+        for k in 0..n {
+            let k = T::from(k).unwrap();
+            let next = (x.clone() * current.clone() * Polynomial::constant(k + k + T::one())
+                + -previous * Polynomial::constant(k))
+                * Polynomial::constant((k + T::one()).recip());
+            (previous, current) = (current, next);
+        }
+
+        current
+    }
+}
+
+/// Numerical integration rules built from orthogonal polynomials
+pub struct Quadrature;
+
+impl Quadrature {
+    /// Returns the nodes and weights of the `n` points
+    /// [Gauss–Legendre rule](https://en.wikipedia.org/wiki/Gauss%E2%80%93Legendre_quadrature),
+    /// exact for polynomials of degree up to `2n - 1` on `[-1, 1]`. The nodes are the roots of
+    /// [`Polynomial::legendre`], in ascending order, and the weights are
+    /// `2 / ((1 - x^2) P_n'(x)^2)`. Zero points return empty vecs
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Quadrature;
+    /// let (nodes, weights) = Quadrature::gauss_legendre(3);
+    ///
+    /// // ∫ x^4 dx over [-1, 1]
+    /// let integral: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(4)).sum();
+    /// assert!((integral - 0.4).abs() < 1e-14);
+    /// ```
+    pub fn gauss_legendre(n: u32) -> (Vec<f64>, Vec<f64>) {
+        let poly: Polynomial<f64> = Polynomial::legendre(n);
+        let mut nodes = poly.roots_in_interval(-1f64, 1f64).unwrap_or_default();
+
+        // The monomial coefficients lose precision as `n` grows, the nodes are polished and
+        // the weights computed with the recurrence instead. When the root finder misses
+        // nodes the usual asymptotic guesses are polished
+        if nodes.len() != n as usize {
+            let n = n as f64;
+            nodes = (0..n as usize)
+                .map(|i| -(std::f64::consts::PI * (i as f64 + 0.75) / (n + 0.5)).cos())
+                .collect();
+        }
+        for x in nodes.iter_mut() {
+            for _ in 0..POLISH_STEPS {
+                let (value, slope) = legendre_eval(n, *x);
+                let step = value / slope;
+                if !step.is_finite() {
+                    break;
+                }
+
+                *x -= step;
+                if step.abs() <= f64::EPSILON {
+                    break;
+                }
+            }
+        }
+
+        let weights = nodes
+            .iter()
+            .map(|x| 2f64 / ((1f64 - x * x) * legendre_eval(n, *x).1.powi(2)))
+            .collect();
+
+        (nodes, weights)
+    }
+}
+
+/// Returns `P_n(x)` and `P_n'(x)` for `|x| < 1` by Bonnet's recurrence
+fn legendre_eval(n: u32, x: f64) -> (f64, f64) {
+    let (mut previous, mut current) = (0f64, 1f64);
+    for k in 0..n {
+        let k = k as f64;
+        let next = ((2f64 * k + 1f64) * x * current - k * previous) / (k + 1f64);
+        (previous, current) = (current, next);
+    }
+
+    let slope = match n {
+        0 => 0f64,
+        _ => n as f64 * (x * current - previous) / (x * x - 1f64),
+    };
+
+    (current, slope)
+}
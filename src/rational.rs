@@ -0,0 +1,116 @@
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Quotient of polynomials `N(x) / D(x)`. Common factors aren't cancelled
+#[derive(Debug, Clone)]
+pub struct RationalFunction<T> {
+    pub(crate) num: Polynomial<T>,
+    pub(crate) den: Polynomial<T>,
+}
+
+impl<T: MonomialValue> RationalFunction<T> {
+    /// Constructs a new `RationalFunction<T>`
+    ///
+    /// Returns an error if the denominator is zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, RationalFunction};
+    /// let num: Polynomial<i32> = Polynomial::try_from("x + 3").unwrap();
+    /// let den: Polynomial<i32> = Polynomial::try_from("x - 1").unwrap();
+    ///
+    /// assert!(RationalFunction::new(num.clone(), den).is_ok());
+    /// assert!(RationalFunction::new(num, Polynomial::zero()).is_err());
+    /// ```
+    pub fn new(num: Polynomial<T>, den: Polynomial<T>) -> Result<Self, &'static str> {
+        if den.is_zero() {
+            return Err("The denominator can't be zero");
+        }
+
+        Ok(RationalFunction { num, den })
+    }
+
+    /// Returns the numerator `N(x)`
+    pub fn numerator(&self) -> &Polynomial<T> {
+        &self.num
+    }
+
+    /// Returns the denominator `D(x)`
+    pub fn denominator(&self) -> &Polynomial<T> {
+        &self.den
+    }
+
+    /// Evaluates `N(x) / D(x)`, returns [`None`] at a root of the denominator. Integer values
+    /// are truncated by the division
+    pub fn eval(&self, x: T) -> Option<T> {
+        let den = self.den.eval(x);
+        match den.is_zero() {
+            true => None,
+            false => Some(self.num.eval(x) / den),
+        }
+    }
+}
+
+impl<T: MonomialValue> PartialEq for RationalFunction<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num == other.num && self.den == other.den
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Substitutes `x` by `num / den`, clearing the denominators. For a polynomial of degree
+    /// `n` the result is `sum a_k num^k den^(n - k) / den^n`, the usual form of a Möbius
+    /// transformation `(a x + b) / (c x + d)`. Negative exponents multiply both sides by the
+    /// power of `num` they need
+    ///
+    /// Returns an error if `den` is zero or the distance between the highest and lowest
+    /// exponent doesn't fit in [`i32`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 2").unwrap();
+    /// let num: Polynomial<i32> = Polynomial::try_from("x + 1").unwrap();
+    /// let den: Polynomial<i32> = Polynomial::try_from("x").unwrap();
+    /// let composed = poly.compose_rational(&num, &den).unwrap();
+    ///
+    /// assert_eq!(format!("{}", composed.numerator()), "-x^2 + 2x + 1");
+    /// assert_eq!(format!("{}", composed.denominator()), "x^2");
+    /// ```
+    pub fn compose_rational(
+        &self,
+        num: &Polynomial<T>,
+        den: &Polynomial<T>,
+    ) -> Result<RationalFunction<T>, &'static str> {
+        if den.is_zero() {
+            return Err("The denominator can't be zero");
+        }
+
+        let high = self.max_exp().get_exp().max(0);
+        let low = self.into_iter().last().map_or(0, |m| m.get_exp()).min(0);
+        let span = high
+            .checked_sub(low)
+            .ok_or("The exponents of the result overflow")? as usize;
+
+        let powers = |base: &Polynomial<T>| {
+            let mut powers = vec![Polynomial::new(vec![Monomial::new(T::one(), 0)])];
+            for _ in 0..span {
+                let next = powers[powers.len() - 1].clone() * base.clone();
+                powers.push(next);
+            }
+            powers
+        };
+        let (num_powers, den_powers) = (powers(num), powers(den));
+
+        let mut result = Polynomial::zero();
+        for mono in self {
+            let exp = mono.get_exp();
+            let term = num_powers[(exp - low) as usize].clone()
+                * den_powers[(high - exp) as usize].clone();
+            result = result + term * Polynomial::from(Monomial::new(mono.get_value(), 0));
+        }
+
+        RationalFunction::new(
+            result.with_var(num.get_var()),
+            (den_powers[high as usize].clone() * num_powers[(-low) as usize].clone())
+                .with_var(num.get_var()),
+        )
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    poly::{total_cmp, Polynomial},
+    sturm::horner,
+    MonomialValue,
+};
+
+/// Closed form `a_n = Σ q_i(n) r_i^n` of a linear recurrence, with a polynomial `q_i` in `n`
+/// of degree less than the multiplicity of each root `r_i`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosedForm {
+    terms: Vec<(f64, Vec<f64>)>,
+}
+
+impl ClosedForm {
+    /// Returns every distinct root with the coefficients of its polynomial in `n`, in
+    /// ascending order
+    pub fn terms(&self) -> &[(f64, Vec<f64>)] {
+        &self.terms
+    }
+
+    /// Returns the term `a_n`
+    pub fn eval(&self, n: u32) -> f64 {
+        self.terms
+            .iter()
+            .map(|(root, coeffs)| horner(coeffs, n as f64) * root.powi(n as i32))
+            .sum()
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the characteristic polynomial `x^k - c_1 x^(k-1) - ... - c_k` of the
+    /// [linear recurrence](https://en.wikipedia.org/wiki/Linear_recurrence_with_constant_coefficients)
+    /// `a_n = c_1 a_(n-1) + ... + c_k a_(n-k)`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // Fibonacci
+    /// let poly: Polynomial<i32> = Polynomial::characteristic_of_recurrence(&[1, 1]);
+    ///
+    /// assert_eq!(format!("{poly}"), "x^2 - x - 1");
+    /// ```
+    pub fn characteristic_of_recurrence(coeffs: &[T]) -> Self {
+        let mut dense: Vec<T> = coeffs.iter().rev().map(|c| -*c).collect();
+        dense.push(T::one());
+
+        Polynomial::from_coeffs(&dense)
+    }
+
+    /// Returns the closed form of the recurrence with this characteristic polynomial
+    /// starting with `initial`, one term per degree. The coefficients are solved from the
+    /// initial terms once every root and its multiplicity is found
+    ///
+    /// Returns [`None`] if the number of initial terms isn't the degree, there are complex
+    /// roots or not all roots could be found
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // a_n = 5 a_(n-1) - 6 a_(n-2), a_n = 2^n + 3^n
+    /// let poly: Polynomial<i32> = Polynomial::characteristic_of_recurrence(&[5, -6]);
+    /// let closed = poly.solve_recurrence(&[2, 5]).unwrap();
+    ///
+    /// assert_eq!(closed.terms(), &[(2.0, vec![1.0]), (3.0, vec![1.0])]);
+    /// assert_eq!(closed.eval(10), 60073.0);
+    /// ```
+    pub fn solve_recurrence(&self, initial: &[T]) -> Option<ClosedForm> {
+        let coeffs = self
+            .to_coeffs()
+            .ok()?
+            .into_iter()
+            .map(|c| c.to_f64())
+            .collect::<Option<Vec<f64>>>()?;
+        let initial = initial
+            .iter()
+            .map(|c| c.to_f64())
+            .collect::<Option<Vec<f64>>>()?;
+
+        let degree = coeffs.len().checked_sub(1)?;
+        if degree == 0 || initial.len() != degree {
+            return None;
+        }
+
+        let mut roots: Vec<f64> = self
+            .roots()?
+            .into_iter()
+            .map(|r| r.to_f64())
+            .collect::<Option<Vec<f64>>>()?;
+        roots.sort_by(total_cmp);
+        roots.dedup();
+
+        let multiplicities: Vec<usize> = roots.iter().map(|r| multiplicity(&coeffs, *r)).collect();
+        if multiplicities.contains(&0) || multiplicities.iter().sum::<usize>() != degree {
+            return None;
+        }
+
+        // One column per unknown `n^j r^n`, one row per initial term
+        let columns: Vec<(f64, i32)> = roots
+            .iter()
+            .zip(&multiplicities)
+            .flat_map(|(r, m)| (0..*m as i32).map(move |j| (*r, j)))
+            .collect();
+        let matrix: Vec<Vec<f64>> = (0..degree)
+            .map(|n| {
+                let n = n as i32;
+                columns
+                    .iter()
+                    .map(|(r, j)| (n as f64).powi(*j) * r.powi(n))
+                    .collect()
+            })
+            .collect();
+
+        let mut solution = solve_linear(matrix, initial)?.into_iter();
+        let terms = roots
+            .into_iter()
+            .zip(multiplicities)
+            .map(|(r, m)| (r, solution.by_ref().take(m).collect()))
+            .collect();
+
+        Some(ClosedForm { terms })
+    }
+}
+
+/// Number of derivatives of `coeffs` that vanish at `root`, relative to the size of their
+/// terms
+fn multiplicity(coeffs: &[f64], root: f64) -> usize {
+    let mut current = coeffs.to_vec();
+    let mut count = 0;
+
+    while !current.is_empty() {
+        let size: f64 = current
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c * root.powi(i as i32)).abs())
+            .sum();
+        if horner(&current, root).abs() > size * 1e-9 {
+            break;
+        }
+
+        count += 1;
+        current = current
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c * i as f64)
+            .collect();
+    }
+
+    count
+}
+
+/// Gaussian elimination with partial pivoting, [`None`] if the system is singular
+fn solve_linear(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+
+    for k in 0..size {
+        let pivot =
+            (k..size).max_by(|a, b| total_cmp(&matrix[*a][k].abs(), &matrix[*b][k].abs()))?;
+        if matrix[pivot][k] == 0f64 {
+            return None;
+        }
+
+        matrix.swap(k, pivot);
+        rhs.swap(k, pivot);
+
+        let (top, bottom) = matrix.split_at_mut(k + 1);
+        for (i, row) in bottom.iter_mut().enumerate() {
+            let factor = row[k] / top[k][k];
+            for (value, pivot_value) in row.iter_mut().zip(&top[k]).skip(k) {
+                *value -= factor * pivot_value;
+            }
+            rhs[k + 1 + i] -= factor * rhs[k];
+        }
+    }
+
+    for k in (0..size).rev() {
+        let known: f64 = (k + 1..size).map(|j| matrix[k][j] * rhs[j]).sum();
+        rhs[k] = (rhs[k] - known) / matrix[k][k];
+    }
+
+    Some(rhs)
+}
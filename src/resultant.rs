@@ -0,0 +1,326 @@
+use std::cmp::Ordering;
+
+use num::Signed;
+
+use crate::{
+    mono::Monomial,
+    poly::Polynomial,
+    sturm::{max_abs, rem, trim, SturmSequence},
+    MonomialValue,
+};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the [resultant](https://en.wikipedia.org/wiki/Resultant) of both polynomials,
+    /// the determinant of their Sylvester matrix computed by the fraction-free Bareiss
+    /// algorithm, so integer coefficients stay exact. It's zero if and only if they have a
+    /// common root, real or complex
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i64> = Polynomial::try_from("x^2 - 3x + 2").unwrap();
+    /// let q: Polynomial<i64> = Polynomial::try_from("x^2 - 1").unwrap();
+    /// let r: Polynomial<i64> = Polynomial::try_from("x + 2").unwrap();
+    ///
+    /// assert_eq!(p.resultant(&q), Ok(0));
+    /// assert_eq!(p.resultant(&r), Ok(12));
+    /// ```
+    pub fn resultant(&self, other: &Self) -> Result<T, &'static str> {
+        let [lhs, rhs] = [self, other].map(|p| {
+            p.to_coeffs().map(|mut c| {
+                c.reverse();
+                c
+            })
+        });
+        let [lhs, rhs] = [lhs?, rhs?];
+
+        if lhs.is_empty() || rhs.is_empty() {
+            return Ok(T::zero());
+        }
+
+        let [m, n] = [lhs.len() - 1, rhs.len() - 1];
+        let size = m + n;
+        let mut matrix = vec![vec![T::zero(); size]; size];
+        for i in 0..n {
+            matrix[i][i..i + m + 1].copy_from_slice(&lhs);
+        }
+        for i in 0..m {
+            matrix[n + i][i..i + n + 1].copy_from_slice(&rhs);
+        }
+
+        Ok(bareiss_det(matrix))
+    }
+
+    /// Returns `true` if both polynomials share a root, real or complex, that is, if their
+    /// [`resultant`](Polynomial::resultant) is zero. Returns `false` if there is any negative
+    /// exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i32> = Polynomial::try_from("x^2 + 1").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^3 + x^2 + x + 1").unwrap();
+    ///
+    /// assert!(p.has_common_root(&q));
+    /// assert!(!p.has_common_root(&Polynomial::try_from("x - 1").unwrap()));
+    /// ```
+    pub fn has_common_root(&self, other: &Self) -> bool {
+        self.resultant(other).is_ok_and(|r| r.is_zero())
+    }
+
+    /// Returns the distinct real roots shared by both polynomials in ascending order, the
+    /// roots of their greatest common divisor. Returns an empty vec if there is any negative
+    /// exponent or both are zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i32> = Polynomial::try_from("x^3 - 2x^2 - 5x + 6").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^2 + x - 2").unwrap();
+    /// let roots = p.common_roots(&q);
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0] + 2.0).abs() < 1e-12 && (roots[1] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn common_roots(&self, other: &Self) -> Vec<f64> {
+        let to_f64 = |p: &Self| -> Option<Vec<f64>> {
+            p.to_coeffs()
+                .ok()?
+                .into_iter()
+                .map(|c| c.to_f64())
+                .collect()
+        };
+
+        let (Some(mut lhs), Some(mut rhs)) = (to_f64(self), to_f64(other)) else {
+            return Vec::new();
+        };
+
+        while !rhs.is_empty() {
+            let scale = max_abs(&lhs);
+            (lhs, rhs) = (rhs.clone(), trim(rem(&lhs, &rhs), scale));
+        }
+
+        match SturmSequence::new(lhs) {
+            Some(sturm) => {
+                let bound = sturm.root_bound();
+                sturm.roots(-bound, bound)
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the [subresultant pseudo-remainder sequence](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor#Subresultant_pseudo-remainder_sequence)
+    /// starting with both polynomials, the one of higher degree first. Every pseudo-remainder
+    /// is divided exactly by the factor that keeps the coefficients as small as possible
+    /// without fractions, so integer coefficients stay exact. The last element is a
+    /// multiple of the greatest common divisor
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i64> = Polynomial::try_from("x^8 + x^6 - 3x^4 - 3x^3 + 8x^2 + 2x - 5").unwrap();
+    /// let q: Polynomial<i64> = Polynomial::try_from("3x^6 + 5x^4 - 4x^2 - 9x + 21").unwrap();
+    /// let prs: Vec<String> = p.subresultant_prs(&q).unwrap().iter().map(|p| p.to_string()).collect();
+    ///
+    /// assert_eq!(prs[2..], ["15x^4 - 3x^2 + 9", "65x^2 + 125x - 245", "9326x - 12300", "260708"]);
+    /// ```
+    pub fn subresultant_prs(&self, other: &Self) -> Result<Vec<Self>, &'static str> {
+        let [lhs, rhs] = [self.to_coeffs(), other.to_coeffs()];
+        let (mut a, mut b) = (lhs?, rhs?);
+        if a.len() < b.len() {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        let mut sequence = vec![a.clone(), b.clone()];
+        let mut psi = -T::one();
+        let mut prev_delta: Option<u32> = None;
+
+        while !b.is_empty() {
+            let delta = (a.len() - b.len()) as u32;
+            let lead = a[a.len() - 1];
+            let beta = match prev_delta {
+                None => pow(-T::one(), delta + 1),
+                Some(prev) => {
+                    psi = match prev {
+                        0 => psi,
+                        _ => pow(-lead, prev) / pow(psi, prev - 1),
+                    };
+                    -lead * pow(psi, delta)
+                }
+            };
+
+            let rem = pseudo_rem(&a, &b);
+            if rem.is_empty() {
+                break;
+            }
+
+            (a, b) = (b, rem.into_iter().map(|c| c / beta).collect());
+            prev_delta = Some(delta);
+            sequence.push(b.clone());
+        }
+
+        sequence.retain(|coeffs| !coeffs.is_empty());
+
+        Ok(sequence
+            .iter()
+            .map(|coeffs| Polynomial::from_coeffs(coeffs).with_var(self.get_var()))
+            .collect())
+    }
+
+    /// Returns the greatest common divisor computed without fractions: the gcd of both
+    /// [`content`](Polynomial::content)s times the primitive part of the last element of the
+    /// [`subresultant_prs`](Polynomial::subresultant_prs), with positive leading coefficient.
+    /// The zero polynomial is returned only if both are zero
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let p: Polynomial<i64> = Polynomial::try_from("4x^3 + 8x^2 - 4x - 8").unwrap();
+    /// let q: Polynomial<i64> = Polynomial::try_from("6x^2 + 18x + 12").unwrap();
+    ///
+    /// assert_eq!(format!("{}", p.gcd(&q).unwrap()), "2x^2 + 6x + 4");
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Result<Self, &'static str> {
+        let sequence = self.subresultant_prs(other)?;
+        let Some(last) = sequence.last() else {
+            return Ok(Polynomial::zero().with_var(self.get_var()));
+        };
+
+        let content = gcd_values(self.content(), other.content());
+        let content = Polynomial::from_coeffs(&[content]).with_var(self.get_var());
+
+        Ok(last.primitive_part() * content)
+    }
+
+    /// Returns the greatest common divisor of the coefficients, positive, or zero for the
+    /// zero polynomial
+    pub fn content(&self) -> T {
+        self.into_iter()
+            .fold(T::zero(), |acc, m| gcd_values(acc, m.get_value()))
+    }
+
+    /// Returns the polynomial divided by its [`content`](Polynomial::content), with positive
+    /// leading coefficient
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("-6x^2 + 4x - 2").unwrap();
+    ///
+    /// assert_eq!(poly.content(), 2);
+    /// assert_eq!(format!("{}", poly.primitive_part()), "3x^2 - 2x + 1");
+    /// ```
+    pub fn primitive_part(&self) -> Self {
+        let mut content = self.content();
+        if self.max_exp().get_value() < T::zero() {
+            content = -content;
+        }
+
+        match content.is_zero() {
+            true => self.clone(),
+            false => self.clone().div_mono(Monomial::new(content, 0)),
+        }
+    }
+}
+
+/// Pseudo-remainder `lc(rhs)^(deg lhs - deg rhs + 1) lhs mod rhs`, without fractions
+fn pseudo_rem<T: MonomialValue>(lhs: &[T], rhs: &[T]) -> Vec<T> {
+    let lead = rhs[rhs.len() - 1];
+    let mut rem = lhs.to_vec();
+    let mut missing = lhs.len() - rhs.len() + 1;
+
+    while rem.len() >= rhs.len() {
+        let factor = rem[rem.len() - 1];
+        let shift = rem.len() - rhs.len();
+        rem.iter_mut().for_each(|c| *c = *c * lead);
+        for (i, c) in rhs.iter().enumerate() {
+            rem[shift + i] = rem[shift + i] - factor * *c;
+        }
+
+        rem.pop();
+        while rem.last().is_some_and(|c| c.is_zero()) {
+            rem.pop();
+        }
+        missing -= 1;
+    }
+
+    let scale = pow(lead, missing as u32);
+    rem.into_iter().map(|c| c * scale).collect()
+}
+
+fn pow<T: MonomialValue>(base: T, exp: u32) -> T {
+    (0..exp).fold(T::one(), |acc, _| acc * base)
+}
+
+/// Greatest common divisor of two values by the Euclidean algorithm, positive
+fn gcd_values<T: MonomialValue>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+
+    a.abs()
+}
+
+/// Determinant by the fraction-free [Bareiss algorithm](https://en.wikipedia.org/wiki/Bareiss_algorithm),
+/// every division is exact for integers
+fn bareiss_det<T: MonomialValue>(mut matrix: Vec<Vec<T>>) -> T {
+    let size = matrix.len();
+    let (rank, sign) = bareiss(&mut matrix);
+
+    if rank < size {
+        return T::zero();
+    }
+
+    match size {
+        0 => T::one(),
+        _ => sign * matrix[size - 1][size - 1],
+    }
+}
+
+/// Fraction-free [Bareiss](https://en.wikipedia.org/wiki/Bareiss_algorithm) elimination to row
+/// echelon form in place, with partial pivoting and skipping the columns without pivot. Every
+/// entry is a minor of the matrix, so the divisions by the previous pivot are exact for
+/// integers. Returns the rank and the sign of the row permutation
+pub(crate) fn bareiss<N: Clone + Signed + PartialOrd>(matrix: &mut [Vec<N>]) -> (usize, N) {
+    let rows = matrix.len();
+    let cols = matrix.first().map_or(0, Vec::len);
+    let mut sign = N::one();
+    let mut prev = N::one();
+    let mut rank = 0;
+
+    for col in 0..cols {
+        if rank == rows {
+            break;
+        }
+
+        let pivot = (rank..rows)
+            .filter(|i| !matrix[*i][col].is_zero())
+            .max_by(|a, b| {
+                let [a, b] = [a, b].map(|i| matrix[*i][col].abs());
+                a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+            });
+        let Some(pivot) = pivot else {
+            continue;
+        };
+        if pivot != rank {
+            matrix.swap(rank, pivot);
+            sign = -sign;
+        }
+
+        let (done, rest) = matrix.split_at_mut(rank + 1);
+        let pivot_row = &done[rank];
+        for row in rest {
+            for j in col + 1..cols {
+                row[j] = (row[j].clone() * pivot_row[col].clone()
+                    - row[col].clone() * pivot_row[j].clone())
+                    / prev.clone();
+            }
+            row[col] = N::zero();
+        }
+
+        prev = pivot_row[col].clone();
+        rank += 1;
+    }
+
+    (rank, sign)
+}
@@ -0,0 +1,535 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use num::{BigInt, Complex, Integer, One, Rational64, ToPrimitive, Zero};
+
+use crate::{
+    mono::Monomial,
+    poly::{total_cmp, Polynomial},
+    EquationType, MonomialValue,
+};
+
+/// Reason why [`Polynomial::try_roots`] couldn't return any root
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootsError {
+    /// The equation has no real solution, like `x^2 + 1`
+    NoRealRoots,
+
+    /// None of the strategies can solve an equation of this degree with these coefficients,
+    /// constant polynomials have degree `0`
+    UnsupportedDegree(i32),
+
+    /// A coefficient or a root doesn't fit in the coefficient type or in [`i64`]
+    CoefficientOverflow,
+
+    /// The polynomial has terms with negative exponent
+    NegativeExponent,
+}
+
+impl Display for RootsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RootsError::NoRealRoots => write!(f, "The equation has no real roots"),
+            RootsError::UnsupportedDegree(d) => write!(f, "Equations of degree {d} not supported"),
+            RootsError::CoefficientOverflow => write!(f, "Coefficient overflow"),
+            RootsError::NegativeExponent => write!(f, "Negative exponents not supported"),
+        }
+    }
+}
+
+impl Error for RootsError {}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Same as [`roots`](Polynomial::roots), but the failures are described by a
+    /// [`RootsError`] instead of [`None`]. Roots that aren't real numbers (`NaN`) are discarded
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, RootsError};
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 5x + 6").unwrap();
+    /// assert_eq!(poly.try_roots(), Ok(vec![2, 3]));
+    ///
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 + 1").unwrap();
+    /// assert_eq!(poly.try_roots(), Err(RootsError::NoRealRoots));
+    ///
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 + 2x + 7").unwrap();
+    /// assert_eq!(poly.try_roots(), Err(RootsError::UnsupportedDegree(3)));
+    /// ```
+    pub fn try_roots(&self) -> Result<Vec<T>, RootsError> {
+        if self.into_iter().any(|m| m.get_exp() < 0) {
+            return Err(RootsError::NegativeExponent);
+        }
+
+        let degree = self.max_exp().get_exp();
+        if degree == 0 {
+            return Err(RootsError::UnsupportedDegree(0));
+        }
+
+        let Some(roots) = self.roots() else {
+            return Err(self.roots_failure());
+        };
+
+        let roots: Vec<T> = roots
+            .into_iter()
+            .filter(|r| r.partial_cmp(r).is_some())
+            .collect();
+        if roots.is_empty() {
+            return Err(RootsError::NoRealRoots);
+        }
+
+        Ok(roots)
+    }
+
+    /// Find out why [`roots`](Polynomial::roots) returned [`None`]
+    pub(crate) fn roots_failure(&self) -> RootsError {
+        let coeff = |exp: i32| self.find_by_exp(exp).get_value().to_f64();
+        let lead = self.max_exp();
+
+        if self.equation_type() == EquationType::BigExp {
+            return match self.into_iter().all(|m| m.get_value().to_i64().is_some()) {
+                true => RootsError::UnsupportedDegree(lead.get_exp()),
+                false => RootsError::CoefficientOverflow,
+            };
+        }
+
+        let no_real_roots = match self.equation_type() {
+            EquationType::Quadratic | EquationType::Biquadratic => {
+                let mid = lead.get_exp() / 2;
+                let [a, b, c] = [lead.get_exp(), mid, 0].map(coeff);
+                matches!((a, b, c), (Some(a), Some(b), Some(c)) if b * b - 4f64 * a * c < 0f64)
+            }
+            EquationType::BigExp2Terms => {
+                let value = coeff(0).zip(coeff(lead.get_exp())).map(|(c, a)| -c / a);
+                lead.get_exp().is_even() && value.is_some_and(|v| v < 0f64)
+            }
+            _ => false,
+        };
+
+        match no_real_roots {
+            true => RootsError::NoRealRoots,
+            false => RootsError::CoefficientOverflow,
+        }
+    }
+}
+
+/// Root tagged by how it was obtained
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Root<T> {
+    /// Root that makes the polynomial exactly zero in the coefficient type, like the ones
+    /// found by the rational root search and deflation
+    Exact(T),
+
+    /// Root produced by floating-point formulas
+    Approx {
+        /// Approximated value
+        value: f64,
+
+        /// Absolute value of the polynomial at `value`
+        error: f64,
+    },
+}
+
+impl<T: MonomialValue> Root<T> {
+    /// Returns the value of the root as [`f64`]
+    pub fn to_f64(&self) -> Option<f64> {
+        match self {
+            Root::Exact(v) => v.to_f64(),
+            Root::Approx { value, .. } => Some(*value),
+        }
+    }
+
+    /// Returns `true` if the root is [`Root::Exact`]
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Root::Exact(_))
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the real roots tagged as [`Root::Exact`] when they make the polynomial zero in
+    /// `T`, or [`Root::Approx`] when they are only a floating-point approximation, ordered by
+    /// value. Irrational roots of integer polynomials are also returned as approximations
+    /// instead of truncated
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, Root};
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 2").unwrap();
+    /// let roots = poly.roots_classified().unwrap();
+    ///
+    /// assert!(roots.iter().all(|r| !r.is_exact()));
+    /// assert!((roots[1].to_f64().unwrap() - 2f64.sqrt()).abs() < 1e-12);
+    ///
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 4").unwrap();
+    /// assert_eq!(poly.roots_classified(), Some(vec![Root::Exact(-2), Root::Exact(2)]));
+    /// ```
+    pub fn roots_classified(&self) -> Option<Vec<Root<T>>> {
+        let mut roots: Vec<Root<T>> = self
+            .roots()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| self.eval(*r).is_zero())
+            .map(Root::Exact)
+            .collect();
+        let exact: Vec<f64> = roots.iter().filter_map(Root::to_f64).collect();
+
+        let float_poly = Polynomial::<f64>::new(
+            self.into_iter()
+                .map(|m| Some(Monomial::new(m.get_value().to_f64()?, m.get_exp())))
+                .collect::<Option<Vec<Monomial<f64>>>>()?,
+        );
+
+        for value in float_poly.roots().unwrap_or_default() {
+            let known = exact
+                .iter()
+                .any(|e| (e - value).abs() <= 1e-9 * e.abs().max(1f64));
+            if value.is_nan() || known {
+                continue;
+            }
+
+            let error = float_poly.eval(value).abs();
+            roots.push(Root::Approx { value, error });
+        }
+
+        if roots.is_empty() {
+            return None;
+        }
+
+        roots.sort_by(|a, b| {
+            total_cmp(
+                &a.to_f64().unwrap_or(f64::NAN),
+                &b.to_f64().unwrap_or(f64::NAN),
+            )
+        });
+
+        Some(roots)
+    }
+}
+
+/// Exact roots `(p ± √d) / q` of an integer quadratic, `d` is negative for a complex pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadraticRoot {
+    /// Rational part numerator, `-b` before simplifying
+    pub p: i64,
+
+    /// Denominator, `2a` before simplifying, always positive
+    pub q: i64,
+
+    /// Radicand, the discriminant `b^2 - 4ac` before simplifying
+    pub d: i64,
+}
+
+impl QuadraticRoot {
+    /// Returns `true` if both roots are real numbers
+    pub fn is_real(&self) -> bool {
+        self.d >= 0
+    }
+
+    /// Returns `true` if the radicand is a perfect square, so both roots are rational
+    pub fn is_rational(&self) -> bool {
+        self.is_real() && self.d.isqrt().pow(2) == self.d
+    }
+
+    /// Returns the real roots `((p - √d) / q, (p + √d) / q)`, or [`None`] for a complex pair
+    pub fn to_f64(&self) -> Option<(f64, f64)> {
+        if !self.is_real() {
+            return None;
+        }
+
+        let sqrt = (self.d as f64).sqrt();
+        let [p, q] = [self.p, self.q].map(|v| v as f64);
+
+        Some(((p - sqrt) / q, (p + sqrt) / q))
+    }
+}
+
+impl Display for QuadraticRoot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} ± √{}) / {}", self.p, self.d, self.q)
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the roots of a quadratic `ax^2 + bx + c` with integer coefficients in exact
+    /// radical form `(-b ± √(b^2 - 4ac)) / 2a`, simplifying the common factors
+    ///
+    /// Returns [`None`] if it isn't a quadratic, any coefficient isn't an integer or the
+    /// discriminant or `2a` overflows [`i64`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, QuadraticRoot};
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 2x - 1").unwrap();
+    /// let root = poly.radical_roots().unwrap();
+    ///
+    /// assert_eq!(root, QuadraticRoot { p: 1, q: 1, d: 2 });
+    /// assert_eq!(format!("{root}"), "(1 ± √2) / 1");
+    ///
+    /// let poly: Polynomial<i32> = Polynomial::try_from("23x^2 + 90x + 100").unwrap();
+    /// assert!(!poly.radical_roots().unwrap().is_real());
+    /// ```
+    pub fn radical_roots(&self) -> Option<QuadraticRoot> {
+        let coeffs = self.to_coeffs().ok()?;
+        let [c, b, a] = <[T; 3]>::try_from(coeffs).ok()?.map(|v| {
+            let int = v.to_i64()?;
+            (T::from(int)? == v).then_some(int)
+        });
+        let [a, b, c] = [a?, b?, c?];
+
+        let d = b
+            .checked_mul(b)?
+            .checked_sub(a.checked_mul(c)?.checked_mul(4)?)?;
+        let sign = a.signum();
+        let p = b.checked_neg()?.checked_mul(sign)?;
+        let q = a.checked_mul(2)?.checked_mul(sign)?;
+
+        // Biggest common factor `k` of `p` and `q` with `k^2` dividing `d`
+        let mut k = 1i64;
+        let mut rest = p.gcd(&q);
+        let mut factor = 2i64;
+        while rest > 1 {
+            if factor.checked_mul(factor).is_none_or(|s| s > rest) {
+                factor = rest;
+            }
+
+            while rest % factor == 0 {
+                rest /= factor;
+                // Any `k^2` divides a zero discriminant, even if it doesn't fit
+                let square = k.checked_mul(factor).and_then(|kf| kf.checked_pow(2));
+                if d == 0 || square.is_some_and(|s| d % s == 0) {
+                    k *= factor;
+                }
+            }
+
+            factor += 1;
+        }
+
+        let d = match d {
+            0 => 0,
+            _ => d / k.checked_mul(k)?,
+        };
+
+        Some(QuadraticRoot {
+            p: p / k,
+            q: q / k,
+            d,
+        })
+    }
+
+    /// Returns the rational roots as exact fractions in ascending order, repeated by
+    /// multiplicity. Every candidate `p / q` of the
+    /// [rational root theorem](https://en.wikipedia.org/wiki/Rational_root_theorem), with `p`
+    /// dividing the lowest coefficient and `q` the leading one, is checked with big integers
+    /// and deflated, so roots like `1/2` aren't lost to `T` like in [`roots`](Polynomial::roots)
+    ///
+    /// Returns [`RootsError::CoefficientOverflow`] if a coefficient isn't an integer that fits
+    /// in [`i64`] and [`RootsError::NegativeExponent`] if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use num::Rational64;
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("4x^3 - 4x^2 - 7x - 2").unwrap();
+    ///
+    /// // The double root `-1/2` is truncated in `i32`
+    /// assert_eq!(poly.roots(), Some(vec![0, 2]));
+    /// assert_eq!(
+    ///     poly.rational_roots(),
+    ///     Ok(vec![Rational64::new(-1, 2), Rational64::new(-1, 2), Rational64::from(2)])
+    /// );
+    /// ```
+    pub fn rational_roots(&self) -> Result<Vec<Rational64>, RootsError> {
+        let coeffs = self
+            .to_coeffs()
+            .map_err(|_| RootsError::NegativeExponent)?
+            .into_iter()
+            .map(|c| {
+                let int = c.to_i64().ok_or(RootsError::CoefficientOverflow)?;
+                match T::from(int) == Some(c) {
+                    true => Ok(BigInt::from(int)),
+                    false => Err(RootsError::CoefficientOverflow),
+                }
+            })
+            .collect::<Result<Vec<BigInt>, RootsError>>()?;
+
+        // Zero roots first, the rest have a non zero lowest coefficient
+        let zeros = coeffs.iter().take_while(|c| c.is_zero()).count();
+        let mut rest = coeffs[zeros..].to_vec();
+        let mut roots = vec![Rational64::zero(); zeros];
+        let (Some(low), Some(lead)) = (rest.first(), rest.last()) else {
+            return Ok(Vec::new());
+        };
+
+        let (numerators, denominators) = (divisors(low), divisors(lead));
+        let mut candidates: Vec<Rational64> = numerators
+            .iter()
+            .flat_map(|p| denominators.iter().map(move |q| (*p, *q)))
+            .filter(|(p, q)| p.gcd(q) == 1)
+            .flat_map(|(p, q)| [Rational64::new(-p, q), Rational64::new(p, q)])
+            .collect();
+        candidates.sort();
+
+        for root in candidates {
+            let (p, q) = (BigInt::from(*root.numer()), BigInt::from(*root.denom()));
+            while rest.len() > 1 {
+                if !homogeneous_eval(&rest, &p, &q).is_zero() {
+                    break;
+                }
+
+                rest = deflate(&rest, &p, &q);
+                roots.push(root);
+            }
+        }
+        roots.sort();
+
+        Ok(roots)
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns both roots of a quadratic `ax^2 + bx + c` as complex numbers, ordered by real
+    /// and then imaginary part. A negative discriminant gives the conjugate pair
+    /// `(-b ± i√(4ac - b^2)) / 2a` instead of no roots
+    ///
+    /// Returns [`None`] if it isn't a quadratic
+    /// # Examples
+    /// ```
+    /// # use num::Complex;
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 + 2x + 5").unwrap();
+    ///
+    /// assert_eq!(
+    ///     poly.roots_complex(),
+    ///     Some(vec![Complex::new(-1.0, -2.0), Complex::new(-1.0, 2.0)])
+    /// );
+    /// ```
+    pub fn roots_complex(&self) -> Option<Vec<Complex<f64>>> {
+        let coeffs = self.to_coeffs().ok()?;
+        let [c, b, a] = <[T; 3]>::try_from(coeffs).ok()?.map(|v| v.to_f64());
+        let [a, b, c] = [a?, b?, c?];
+
+        let sqrt = Complex::new(b * b - 4f64 * a * c, 0f64).sqrt();
+        let mut roots = vec![(-b - sqrt) / (2f64 * a), (-b + sqrt) / (2f64 * a)];
+        roots.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+
+        Some(roots)
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Improves an approximation `x` of a simple root with up to `iterations` steps of
+    /// [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method) `x - p(x) / p'(x)`,
+    /// stopping early when the step is zero. Roots found in [`f64`] can be refined with a
+    /// more precise coefficient type like [`DoubleDouble`](crate::DoubleDouble)
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{DoubleDouble, Polynomial};
+    /// let poly: Polynomial<DoubleDouble> = Polynomial::try_from("x^2 - 2").unwrap();
+    /// let root = poly.newton_refine(DoubleDouble::from(2f64.sqrt()), 5);
+    ///
+    /// assert_eq!(format!("{root}"), "1.4142135623730950488016887242097");
+    /// ```
+    pub fn newton_refine(&self, mut x: T, iterations: u32) -> T {
+        let derivative = self.derivative();
+        for _ in 0..iterations {
+            let slope = derivative.eval(x);
+            if slope.is_zero() {
+                break;
+            }
+
+            let step = self.eval(x) / slope;
+            if step.is_zero() {
+                break;
+            }
+            x = x - step;
+        }
+
+        x
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the points `(x, y)` where both curves meet, the [`roots`](Polynomial::roots)
+    /// of `p - q` evaluated on `p`, ordered by `x` without repetitions. Only the crossings
+    /// exactly representable in `T` are returned: roots truncated or rounded by `T`, like
+    /// `sqrt(2)`, are discarded because `p(x) != q(x)`. Equal polynomials and parallel curves
+    /// return an empty vec
+    ///
+    /// Returns an error if the roots of `p - q` can't be found, like in
+    /// [`try_roots`](Polynomial::try_roots)
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, RootsError};
+    /// let p: Polynomial<i32> = Polynomial::try_from("x^2").unwrap();
+    /// let q: Polynomial<i32> = Polynomial::try_from("x + 2").unwrap();
+    ///
+    /// assert_eq!(p.intersections(&q), Ok(vec![(-1, 1), (2, 4)]));
+    ///
+    /// let q: Polynomial<i32> = Polynomial::try_from("x^5 + x + 1").unwrap();
+    /// assert_eq!(p.intersections(&q), Err(RootsError::UnsupportedDegree(5)));
+    /// ```
+    pub fn intersections(&self, other: &Self) -> Result<Vec<(T, T)>, RootsError> {
+        let difference = self.clone() + -other.clone();
+        if difference.max_exp().get_exp() == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut roots = match difference.try_roots() {
+            Ok(roots) => roots,
+            Err(RootsError::NoRealRoots) => Vec::new(),
+            Err(error) => return Err(error),
+        };
+        roots.dedup();
+
+        Ok(roots
+            .into_iter()
+            .map(|x| (x, self.eval(x)))
+            .filter(|(x, y)| *y == other.eval(*x))
+            .collect())
+    }
+}
+
+/// `q^n P(p / q) = sum a_k p^k q^(n - k)`, by Horner's method on the homogeneous form
+fn homogeneous_eval(coeffs: &[BigInt], p: &BigInt, q: &BigInt) -> BigInt {
+    let Some((lead, rest)) = coeffs.split_last() else {
+        return BigInt::zero();
+    };
+
+    let mut q_power = BigInt::one();
+    rest.iter().rev().fold(lead.clone(), |acc, c| {
+        q_power *= q;
+        acc * p + c * &q_power
+    })
+}
+
+/// Quotient of the exact division by `q x - p`, coefficients in ascending order
+fn deflate(coeffs: &[BigInt], p: &BigInt, q: &BigInt) -> Vec<BigInt> {
+    let n = coeffs.len() - 1;
+    let mut quotient = vec![BigInt::zero(); n];
+    quotient[n - 1] = &coeffs[n] / q;
+    for k in (1..n).rev() {
+        quotient[k - 1] = (&coeffs[k] + p * &quotient[k]) / q;
+    }
+
+    quotient
+}
+
+/// Positive divisors of `|value|` that fit in [`i64`], by trial division up to the square root
+fn divisors(value: &BigInt) -> Vec<i64> {
+    let Some(value) = value.magnitude().to_u64() else {
+        return Vec::new();
+    };
+
+    let mut divisors = Vec::new();
+    let mut d = 1u64;
+    while d.checked_mul(d).is_some_and(|square| square <= value) {
+        if value % d == 0 {
+            divisors.extend([d, value / d]);
+        }
+        d += 1;
+    }
+    divisors.sort();
+    divisors.dedup();
+
+    divisors
+        .into_iter()
+        .filter_map(|d| i64::try_from(d).ok())
+        .collect()
+}
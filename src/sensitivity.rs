@@ -0,0 +1,123 @@
+use crate::{mono::Monomial, poly::Polynomial};
+
+/// Relative size of `p'(r)` below which a root is taken as multiple
+const MULTIPLE_TOLERANCE: f64 = 1e-8;
+
+/// Newton steps to polish the roots isolated by the Sturm sequence
+const POLISH_STEPS: u32 = 3;
+
+/// First order sensitivity of a simple real root to the coefficients, returned by
+/// [`Polynomial::root_sensitivities`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootSensitivity {
+    /// The root `r`
+    pub root: f64,
+
+    /// `∂r/∂c_j = -r^j / p'(r)` for every coefficient `c_j`, in ascending order like
+    /// [`Polynomial::to_coeffs`]
+    pub gradient: Vec<f64>,
+}
+
+impl Polynomial<f64> {
+    /// Returns how every distinct simple real root moves when the coefficients change. From
+    /// `p(r) = 0` the root satisfies `∂r/∂c_j = -r^j / p'(r)`, so a noise `δ` in the
+    /// coefficients shifts it by about `gradient · δ`. Roots are in ascending order
+    ///
+    /// Multiple roots aren't differentiable with respect to the coefficients and are left out
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^2 - 3x + 2").unwrap();
+    /// let sensitivities = poly.root_sensitivities().unwrap();
+    ///
+    /// // p'(1) = -1 and p'(2) = 1
+    /// assert!((sensitivities[0].root - 1.0).abs() < 1e-12);
+    /// let expected = [[1.0, 1.0, 1.0], [-1.0, -2.0, -4.0]];
+    /// for (sensitivity, expected) in sensitivities.iter().zip(expected) {
+    ///     assert!(sensitivity.gradient.iter().zip(expected).all(|(d, e)| (d - e).abs() < 1e-12));
+    /// }
+    /// ```
+    pub fn root_sensitivities(&self) -> Result<Vec<RootSensitivity>, &'static str> {
+        let coeffs = self.to_coeffs()?;
+        let derivative = self.derivative();
+
+        let sensitivities = real_roots(self)?
+            .into_iter()
+            .filter_map(|root| {
+                let root = self.newton_refine(root, POLISH_STEPS);
+                let slope = derivative.eval(root);
+                let scale: f64 = (&derivative)
+                    .into_iter()
+                    .map(|m| (m.get_value() * root.powi(m.get_exp())).abs())
+                    .sum();
+                if slope.abs() <= MULTIPLE_TOLERANCE * scale {
+                    return None;
+                }
+
+                let gradient = (0..coeffs.len())
+                    .map(|j| -root.powi(j as i32) / slope)
+                    .collect();
+                Some(RootSensitivity { root, gradient })
+            })
+            .collect();
+
+        Ok(sensitivities)
+    }
+
+    /// Monte-Carlo estimate of the roots under coefficient noise: returns the distinct real
+    /// roots, in ascending order, of `samples` polynomials with every coefficient up to the
+    /// degree moved by `noise * rng()`. `rng` is called once per coefficient in ascending
+    /// order, so it picks the distribution, like standard normal samples of any random crate
+    ///
+    /// The number of real roots can change between samples when the noise moves a pair of
+    /// them into the complex plane
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^2 - 3x + 2").unwrap();
+    /// let mut signs = [1.0, -1.0].into_iter().cycle();
+    /// let samples = poly.perturbed_roots(1e-3, 10, || signs.next().unwrap()).unwrap();
+    ///
+    /// assert_eq!(samples.len(), 10);
+    /// assert!(samples.iter().all(|roots| (roots[1] - 2.0).abs() < 1e-2));
+    /// ```
+    pub fn perturbed_roots(
+        &self,
+        noise: f64,
+        samples: usize,
+        mut rng: impl FnMut() -> f64,
+    ) -> Result<Vec<Vec<f64>>, &'static str> {
+        let coeffs = self.to_coeffs()?;
+
+        (0..samples)
+            .map(|_| {
+                let perturbed: Vec<f64> = coeffs.iter().map(|c| c + noise * rng()).collect();
+                real_roots(&Polynomial::from_coeffs(&perturbed))
+            })
+            .collect()
+    }
+}
+
+/// Distinct real roots in ascending order. Roots at zero are factored out first, the Sturm
+/// search only sees the rest
+fn real_roots(poly: &Polynomial<f64>) -> Result<Vec<f64>, &'static str> {
+    let Some(low) = poly.into_iter().last().map(|m| m.get_exp()) else {
+        return Ok(Vec::new());
+    };
+
+    let rest = poly.clone().div_mono(Monomial::new(1f64, low));
+    let mut roots = match rest.max_root_magnitude_bound() {
+        Some(bound) => rest.roots_in_interval(-bound, bound)?,
+        None => Vec::new(),
+    };
+    if low > 0 {
+        roots.push(0f64);
+        roots.sort_by(f64::total_cmp);
+    }
+
+    Ok(roots)
+}
@@ -0,0 +1,110 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Code generation to evaluate the polynomial inside GPU shaders, as single precision floats
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns a [GLSL](https://registry.khronos.org/OpenGL/specs/gl/GLSLangSpec.4.60.html)
+    /// expression that evaluates the polynomial in
+    /// [Horner's form](https://en.wikipedia.org/wiki/Horner%27s_method), with the variable
+    /// of the polynomial as identifier
+    ///
+    /// Returns an error if there is any negative exponent or a coefficient isn't a finite
+    /// [`f32`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("3x^3 - 2x + 1").unwrap();
+    ///
+    /// assert_eq!(poly.to_glsl().unwrap(), "1.0 + x * (-2.0 + x * (x * 3.0))");
+    /// ```
+    pub fn to_glsl(&self) -> Result<String, &'static str> {
+        self.horner_expr("")
+    }
+
+    /// Returns a [WGSL](https://www.w3.org/TR/WGSL/) expression that evaluates the polynomial
+    /// in Horner's form, like [`to_glsl`](Polynomial::to_glsl) with `f32` literals
+    ///
+    /// Returns an error if there is any negative exponent or a coefficient isn't a finite
+    /// [`f32`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("0.5x^2 - 4").unwrap();
+    ///
+    /// assert_eq!(poly.to_wgsl().unwrap(), "-4.0f + x * (x * 0.5f)");
+    /// ```
+    pub fn to_wgsl(&self) -> Result<String, &'static str> {
+        self.horner_expr("f")
+    }
+
+    /// Returns the coefficients in ascending order packed in `vec4`s, the layout of a
+    /// `vec4 coeffs[N]` (GLSL) or `array<vec4<f32>, N>` (WGSL) uniform, whose elements have a
+    /// stride of 16 bytes. The coefficient of `x^i` is `coeffs[i / 4][i % 4]` and the last
+    /// element is padded with zeros
+    ///
+    /// Returns an error if there is any negative exponent or a coefficient isn't a finite
+    /// [`f32`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^5 + 2x - 3").unwrap();
+    ///
+    /// assert_eq!(
+    ///     poly.to_uniform_coeffs(),
+    ///     Ok(vec![[-3.0, 2.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]])
+    /// );
+    /// ```
+    pub fn to_uniform_coeffs(&self) -> Result<Vec<[f32; 4]>, &'static str> {
+        let coeffs = self.to_f32_coeffs()?;
+
+        Ok(coeffs
+            .chunks(4)
+            .map(|chunk| {
+                let mut packed = [0f32; 4];
+                packed[..chunk.len()].copy_from_slice(chunk);
+                packed
+            })
+            .collect())
+    }
+
+    /// Horner's form of the [`f32`] coefficients, every literal ends with `suffix`
+    fn horner_expr(&self, suffix: &str) -> Result<String, &'static str> {
+        let literals = self
+            .to_f32_coeffs()?
+            .into_iter()
+            .map(|c| (c != 0f32).then(|| format!("{c:?}{suffix}")))
+            .collect();
+
+        Ok(horner(literals, self.get_var()).unwrap_or(format!("0.0{suffix}")))
+    }
+
+    fn to_f32_coeffs(&self) -> Result<Vec<f32>, &'static str> {
+        self.to_coeffs()?
+            .into_iter()
+            .map(|c| {
+                c.to_f32()
+                    .filter(|c| c.is_finite())
+                    .ok_or("Not valid coefficient")
+            })
+            .collect()
+    }
+}
+
+/// Horner's form nested from the leading coefficient, the literals are in ascending order
+/// and [`None`] for the zero coefficients, which are skipped. Returns [`None`] if there are no
+/// literals
+pub(crate) fn horner(literals: Vec<Option<String>>, var: char) -> Option<String> {
+    let mut literals = literals.into_iter().rev();
+    let lead = literals.next()?.unwrap_or(String::from("0"));
+
+    Some(literals.fold(lead, |expr, literal| {
+        let inner = match expr.contains(' ') || expr.starts_with('-') {
+            true => format!("({expr})"),
+            false => expr,
+        };
+
+        match literal {
+            Some(literal) => format!("{literal} + {var} * {inner}"),
+            None => format!("{var} * {inner}"),
+        }
+    }))
+}
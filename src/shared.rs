@@ -0,0 +1,148 @@
+use std::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::{Arc, OnceLock},
+};
+
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Immutable [`Polynomial`] behind an [`Arc`], cheap to clone and to send across threads
+///
+/// It dereferences to the polynomial for the read-only API. The degree, the
+/// [`content_hash`](Polynomial::content_hash) and the roots are computed the first time they are
+/// requested and shared by every clone.
+/// # Examples
+/// ```
+/// # use rust_polynomial::Polynomial;
+/// let poly: Polynomial<i64> = Polynomial::try_from("x^2 - 9").unwrap();
+/// let shared = poly.into_shared();
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|x| {
+///         let shared = shared.clone();
+///         std::thread::spawn(move || shared.eval(x))
+///     })
+///     .collect();
+/// let values: Vec<i64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+///
+/// assert_eq!(values, vec![-9, -8, -5, 0]);
+/// assert_eq!(shared.roots(), Some(&vec![-3, 3]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedPoly<T> {
+    inner: Arc<Inner<T>>,
+}
+
+#[derive(Debug)]
+struct Inner<T> {
+    poly: Polynomial<T>,
+    degree: OnceLock<i32>,
+    hash: OnceLock<u64>,
+    roots: OnceLock<Option<Vec<T>>>,
+}
+
+impl<T: MonomialValue> SharedPoly<T> {
+    /// Constructs a new `SharedPoly<T>` with empty caches
+    pub fn new(poly: Polynomial<T>) -> SharedPoly<T> {
+        SharedPoly {
+            inner: Arc::new(Inner {
+                poly,
+                degree: OnceLock::new(),
+                hash: OnceLock::new(),
+                roots: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// Returns the shared polynomial
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.inner.poly
+    }
+
+    /// Returns the polynomial, cloning it only if there are other handles
+    pub fn into_inner(self) -> Polynomial<T> {
+        match Arc::try_unwrap(self.inner) {
+            Ok(inner) => inner.poly,
+            Err(inner) => inner.poly.clone(),
+        }
+    }
+
+    /// Cached exponent of [`Polynomial::max_exp`], `0` for the zero polynomial
+    pub fn degree(&self) -> i32 {
+        *self
+            .inner
+            .degree
+            .get_or_init(|| self.inner.poly.max_exp().get_exp())
+    }
+
+    /// Cached [`Polynomial::content_hash`]
+    pub fn content_hash(&self) -> u64 {
+        *self
+            .inner
+            .hash
+            .get_or_init(|| self.inner.poly.content_hash())
+    }
+
+    /// Cached [`Polynomial::roots`]
+    pub fn roots(&self) -> Option<&Vec<T>> {
+        self.inner
+            .roots
+            .get_or_init(|| self.inner.poly.roots())
+            .as_ref()
+    }
+
+    /// Returns `true` if both handles share the same polynomial
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Moves the polynomial into a [`SharedPoly`] to share it across threads
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("0.5x^3 - 2x").unwrap();
+    /// let shared = poly.clone().into_shared();
+    ///
+    /// assert_eq!(shared.degree(), 3);
+    /// assert_eq!(shared.content_hash(), poly.content_hash());
+    /// assert_eq!(shared.eval(2.0), 0.0);
+    /// ```
+    pub fn into_shared(self) -> SharedPoly<T> {
+        SharedPoly::new(self)
+    }
+}
+
+impl<T> Deref for SharedPoly<T> {
+    type Target = Polynomial<T>;
+
+    fn deref(&self) -> &Polynomial<T> {
+        &self.inner.poly
+    }
+}
+
+impl<T: MonomialValue> From<Polynomial<T>> for SharedPoly<T> {
+    fn from(value: Polynomial<T>) -> Self {
+        SharedPoly::new(value)
+    }
+}
+
+impl<T: MonomialValue> PartialEq for SharedPoly<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.poly == other.inner.poly
+    }
+}
+
+impl<T: MonomialValue> Hash for SharedPoly<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.content_hash());
+    }
+}
+
+impl<T: MonomialValue> Display for SharedPoly<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.poly.fmt(f)
+    }
+}
@@ -0,0 +1,141 @@
+use num::Complex;
+
+use crate::{mono::Monomial, norms::durand_kerner, poly::Polynomial};
+
+/// Relative size of the imaginary part of a root that is taken as real
+const REAL_TOLERANCE: f64 = 1e-6;
+
+/// Relative error allowed between `q1^2 + q2^2` and the polynomial
+const RESIDUAL_TOLERANCE: f64 = 1e-6;
+
+impl Polynomial<f64> {
+    /// Returns `true` if `p(x) >= 0` for every real `x`. A nonconstant polynomial must have
+    /// even degree and positive leading coefficient, and it must not change sign between its
+    /// real roots: the distinct roots are isolated with the Sturm sequence and the sign is
+    /// checked between them, never at a root
+    ///
+    /// Returns `false` if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let square: Polynomial<f64> = Polynomial::try_from("x^4 - 2x^3 + x^2").unwrap();
+    ///
+    /// assert!(square.is_globally_nonnegative());
+    /// assert!(!Polynomial::try_from("x^4 - 2x^3").unwrap().is_globally_nonnegative());
+    /// ```
+    pub fn is_globally_nonnegative(&self) -> bool {
+        if self.into_iter().any(|m| m.get_exp() < 0) {
+            return false;
+        }
+
+        let lead = self.max_exp();
+        if lead.get_exp() == 0 {
+            return lead.get_value() >= 0f64;
+        }
+        if lead.get_exp() % 2 == 1 || lead.get_value() < 0f64 {
+            return false;
+        }
+
+        // A root at zero of odd multiplicity changes the sign, the rest is searched without it
+        let low = self.into_iter().last().map_or(0, |m| m.get_exp());
+        if low % 2 == 1 {
+            return false;
+        }
+        let rest = self.clone().div_mono(Monomial::new(1f64, low));
+        if rest.max_exp().get_exp() == 0 {
+            return true;
+        }
+
+        let Some(bound) = rest.max_root_magnitude_bound() else {
+            return false;
+        };
+        let Ok(roots) = rest.roots_in_interval(-bound, bound) else {
+            return false;
+        };
+
+        // The sign is constant between consecutive roots, and positive outside them
+        roots
+            .windows(2)
+            .all(|w| rest.eval((w[0] + w[1]) / 2f64) >= 0f64)
+    }
+
+    /// Returns `(q1, q2)` with `p = q1^2 + q2^2`, a sum of squares certifying that the
+    /// polynomial is [globally nonnegative](Polynomial::is_globally_nonnegative). Every
+    /// nonnegative univariate polynomial has one: `p = |q|^2`, where `q` takes one root of
+    /// every conjugate pair and half of every real root of even multiplicity, and `q1` and
+    /// `q2` are its real and imaginary parts
+    ///
+    /// The complex roots are approximated, so the decomposition is only returned if
+    /// `q1^2 + q2^2` matches the coefficients up to a relative error of `1e-6`. Real roots of
+    /// multiplicity two are found to about half of the precision, so higher multiplicities
+    /// can fail. Returns [`None`] if the polynomial isn't nonnegative or the roots couldn't
+    /// be paired
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^2 + 2x + 5").unwrap();
+    /// let (q1, q2) = poly.sos_decomposition().unwrap();
+    ///
+    /// // (x + 1)^2 + 2^2
+    /// assert_eq!(format!("{q1}"), "x + 1");
+    /// assert_eq!(format!("{}", q2.clone() * q2), "4");
+    /// ```
+    pub fn sos_decomposition(&self) -> Option<(Self, Self)> {
+        if !self.is_globally_nonnegative() {
+            return None;
+        }
+
+        let coeffs = self.to_coeffs().ok()?;
+        let Some(&lead) = coeffs.last() else {
+            return Some((Polynomial::zero(), Polynomial::zero()));
+        };
+
+        // Roots at zero are exact, half of them go to `q` as a power of `x`
+        let zeros = coeffs.iter().take_while(|c| **c == 0f64).count();
+        let roots = durand_kerner(&coeffs[zeros..]);
+        let is_real = |z: &Complex<f64>| z.im.abs() <= REAL_TOLERANCE * z.norm().max(1f64);
+
+        // One root of every conjugate pair, and every other real root by position
+        let mut half: Vec<Complex<f64>> = roots
+            .iter()
+            .filter(|z| !is_real(z) && z.im > 0f64)
+            .copied()
+            .collect();
+        let mut real: Vec<f64> = roots.iter().filter(|z| is_real(z)).map(|z| z.re).collect();
+        if real.len() % 2 == 1 {
+            return None;
+        }
+        real.sort_by(f64::total_cmp);
+        half.extend(
+            real.chunks(2)
+                .map(|pair| Complex::new((pair[0] + pair[1]) / 2f64, 0f64)),
+        );
+
+        let q = half
+            .iter()
+            .fold(vec![Complex::new(lead.sqrt(), 0f64)], |q, root| {
+                let mut next = vec![Complex::new(0f64, 0f64); q.len() + 1];
+                for (i, c) in q.iter().enumerate() {
+                    next[i + 1] += c;
+                    next[i] -= c * root;
+                }
+                next
+            });
+
+        let [q1, q2] = [|c: &Complex<f64>| c.re, |c: &Complex<f64>| c.im].map(|part| {
+            let monos = q
+                .iter()
+                .enumerate()
+                .map(|(exp, c)| Monomial::new(part(c), (exp + zeros / 2) as i32));
+            Polynomial::new(monos.collect()).with_var(self.get_var())
+        });
+
+        let sum = q1.clone() * q1.clone() + q2.clone() * q2.clone();
+        let scale = coeffs.iter().fold(0f64, |acc, c| acc.max(c.abs()));
+        let residual = (sum + -self.clone())
+            .into_iter()
+            .fold(0f64, |acc, m| acc.max(m.get_value().abs()));
+
+        (residual <= RESIDUAL_TOLERANCE * scale).then_some((q1, q2))
+    }
+}
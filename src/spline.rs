@@ -0,0 +1,136 @@
+use num::Float;
+
+use crate::{piecewise::PiecewisePolynomial, poly::Polynomial, MonomialValue};
+
+/// Condition at both ends of a cubic spline
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SplineBoundary<T> {
+    /// Zero second derivative at both ends
+    #[default]
+    Natural,
+
+    /// Given first derivative at the start and at the end
+    Clamped(T, T),
+}
+
+/// [Cubic spline](https://en.wikipedia.org/wiki/Spline_interpolation) interpolation, the
+/// result is a [`PiecewisePolynomial`] with a cubic between every pair of consecutive points,
+/// continuous up to the second derivative
+pub struct Spline;
+
+impl Spline {
+    /// Returns the natural cubic spline through `points`
+    ///
+    /// Returns an error if there are less than two points or the `x` values aren't in
+    /// strictly ascending order
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Spline;
+    /// let spline = Spline::fit(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]).unwrap();
+    ///
+    /// assert_eq!(spline.eval(1.0), Some(1.0));
+    /// assert_eq!(spline.eval(0.5), Some(0.6875));
+    /// assert!(spline.is_continuous_to(2));
+    /// ```
+    pub fn fit<T: MonomialValue + Float>(
+        points: &[(T, T)],
+    ) -> Result<PiecewisePolynomial<T>, &'static str> {
+        Spline::fit_with(points, SplineBoundary::Natural)
+    }
+
+    /// Returns the cubic spline through `points` with the given [`SplineBoundary`]
+    ///
+    /// Returns an error if there are less than two points or the `x` values aren't in
+    /// strictly ascending order
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Spline, SplineBoundary};
+    /// let points: [(f64, f64); 3] = [(0.0, 0.0), (1.0, 1.0), (2.0, 8.0)];
+    /// let spline = Spline::fit_with(&points, SplineBoundary::Clamped(0.0, 12.0)).unwrap();
+    ///
+    /// // Clamped with the derivatives of `x^3` reproduces it
+    /// assert!((spline.eval(1.5).unwrap() - 3.375).abs() < 1e-12);
+    /// ```
+    pub fn fit_with<T: MonomialValue + Float>(
+        points: &[(T, T)],
+        boundary: SplineBoundary<T>,
+    ) -> Result<PiecewisePolynomial<T>, &'static str> {
+        if points.len() < 2 {
+            return Err("At least two points are needed");
+        }
+
+        if points.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return Err("Points must be in strictly ascending order of x");
+        }
+
+        let n = points.len() - 1;
+        let [two, six] = [2, 6].map(|v| T::from(v).unwrap());
+        let h: Vec<T> = points.windows(2).map(|w| w[1].0 - w[0].0).collect();
+        let slope: Vec<T> = points
+            .windows(2)
+            .zip(&h)
+            .map(|(w, h)| (w[1].1 - w[0].1) / *h)
+            .collect();
+
+        // Tridiagonal system for the second derivatives `m`
+        let mut lower = vec![T::zero(); n + 1];
+        let mut diag = vec![T::one(); n + 1];
+        let mut upper = vec![T::zero(); n + 1];
+        let mut rhs = vec![T::zero(); n + 1];
+
+        for i in 1..n {
+            lower[i] = h[i - 1];
+            diag[i] = two * (h[i - 1] + h[i]);
+            upper[i] = h[i];
+            rhs[i] = six * (slope[i] - slope[i - 1]);
+        }
+
+        if let SplineBoundary::Clamped(start, end) = boundary {
+            diag[0] = two * h[0];
+            upper[0] = h[0];
+            rhs[0] = six * (slope[0] - start);
+            lower[n] = h[n - 1];
+            diag[n] = two * h[n - 1];
+            rhs[n] = six * (end - slope[n - 1]);
+        }
+
+        let m = solve_tridiagonal(&lower, &diag, &upper, rhs);
+
+        let pieces = (0..n)
+            .map(|i| {
+                let b = slope[i] - h[i] * (two * m[i] + m[i + 1]) / six;
+                let c = m[i] / two;
+                let d = (m[i + 1] - m[i]) / (six * h[i]);
+
+                Polynomial::from_coeffs(&[points[i].1, b, c, d]).shift(-points[i].0)
+            })
+            .collect::<Result<Vec<Polynomial<T>>, &'static str>>()?;
+
+        PiecewisePolynomial::new(points.iter().map(|p| p.0).collect(), pieces)
+    }
+}
+
+/// [Thomas algorithm](https://en.wikipedia.org/wiki/Tridiagonal_matrix_algorithm)
+fn solve_tridiagonal<T: MonomialValue>(
+    lower: &[T],
+    diag: &[T],
+    upper: &[T],
+    mut rhs: Vec<T>,
+) -> Vec<T> {
+    let n = diag.len();
+    let mut upper_prime = vec![T::zero(); n];
+
+    upper_prime[0] = upper[0] / diag[0];
+    rhs[0] = rhs[0] / diag[0];
+    for i in 1..n {
+        let denom = diag[i] - lower[i] * upper_prime[i - 1];
+        upper_prime[i] = upper[i] / denom;
+        rhs[i] = (rhs[i] - lower[i] * rhs[i - 1]) / denom;
+    }
+
+    for i in (0..n - 1).rev() {
+        rhs[i] = rhs[i] - upper_prime[i] * rhs[i + 1];
+    }
+
+    rhs
+}
@@ -0,0 +1,106 @@
+use crate::{poly::Polynomial, sturm::max_abs, MonomialValue};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns `true` if every root, real or complex, has negative real part, without finding
+    /// them. The first column of the
+    /// [Routh array](https://en.wikipedia.org/wiki/Routh%E2%80%93Hurwitz_stability_criterion)
+    /// must have no sign changes nor zeros, so roots on the imaginary axis aren't stable
+    ///
+    /// Returns `false` for the zero polynomial or if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 + 6x^2 + 11x + 6").unwrap();
+    ///
+    /// assert!(poly.is_hurwitz_stable());
+    /// assert!(!Polynomial::<i32>::try_from("x^3 + x^2 + 2x + 8").unwrap().is_hurwitz_stable());
+    /// ```
+    pub fn is_hurwitz_stable(&self) -> bool {
+        hurwitz_stable(self)
+    }
+
+    /// Returns `true` if every root, real or complex, lies strictly inside the unit circle,
+    /// without finding them. The [Schur–Cohn test](https://en.wikipedia.org/wiki/Jury_stability_criterion)
+    /// checks `|a_0| < |a_n|` and repeats on `(a_n p(x) - a_0 x^n p(1/x)) / x`, one degree
+    /// less each time
+    ///
+    /// Returns `false` for the zero polynomial or if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("4x^2 - 1").unwrap();
+    ///
+    /// assert!(poly.is_schur_stable());
+    /// assert!(!Polynomial::<i32>::try_from("x^2 - 1").unwrap().is_schur_stable());
+    /// ```
+    pub fn is_schur_stable(&self) -> bool {
+        let Ok(coeffs) = self.to_coeffs() else {
+            return false;
+        };
+        let Some(mut coeffs) = coeffs
+            .into_iter()
+            .map(|c| c.to_f64())
+            .collect::<Option<Vec<f64>>>()
+        else {
+            return false;
+        };
+
+        if coeffs.is_empty() {
+            return false;
+        }
+
+        while coeffs.len() > 1 {
+            let n = coeffs.len() - 1;
+            let scale = max_abs(&coeffs);
+            let [first, lead] = [coeffs[0], coeffs[n]].map(|c| c / scale);
+            if first.abs() >= lead.abs() * (1f64 - 1e-12) {
+                return false;
+            }
+
+            coeffs = (0..n)
+                .map(|i| (lead * coeffs[i + 1] - first * coeffs[n - 1 - i]) / scale)
+                .collect();
+        }
+
+        true
+    }
+}
+
+fn hurwitz_stable<T: MonomialValue>(poly: &Polynomial<T>) -> bool {
+    let Ok(coeffs) = poly.to_coeffs() else {
+        return false;
+    };
+    let Some(mut coeffs) = coeffs
+        .into_iter()
+        .map(|c| c.to_f64())
+        .collect::<Option<Vec<f64>>>()
+    else {
+        return false;
+    };
+
+    if coeffs.is_empty() {
+        return false;
+    }
+
+    coeffs.reverse();
+    let tolerance = max_abs(&coeffs) * 1e-12;
+    let mut prev: Vec<f64> = coeffs.iter().step_by(2).copied().collect();
+    let mut row: Vec<f64> = coeffs.iter().skip(1).step_by(2).copied().collect();
+    let sign = prev[0] > 0f64;
+
+    for _ in 1..coeffs.len() {
+        let pivot = row.first().copied().unwrap_or(0f64);
+        if pivot.abs() <= tolerance || (pivot > 0f64) != sign {
+            return false;
+        }
+
+        let at = |r: &[f64], j: usize| r.get(j).copied().unwrap_or(0f64);
+        let next = (0..prev.len().saturating_sub(1))
+            .map(|j| (pivot * at(&prev, j + 1) - prev[0] * at(&row, j + 1)) / pivot)
+            .collect();
+
+        prev = std::mem::replace(&mut row, next);
+    }
+
+    true
+}
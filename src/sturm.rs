@@ -0,0 +1,369 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Max bisections to refine a root
+const MAX_ITERATIONS: usize = 200;
+
+/// Error of the queries about the roots of the zero polynomial
+const ZERO: &str = "The zero polynomial has infinite roots";
+
+/// [Sturm sequence](https://en.wikipedia.org/wiki/Sturm%27s_theorem) of a polynomial, stored
+/// as dense [`f64`] coefficients in ascending order of the [`balance`]d polynomial `p(s x)`,
+/// every bound is divided by `s` and every root multiplied back
+pub(crate) struct SturmSequence {
+    chain: Vec<Vec<f64>>,
+    scale: f64,
+}
+
+impl SturmSequence {
+    /// Builds the sequence `p, p', -rem(p, p'), ...`, returns [`None`] for the zero polynomial
+    pub(crate) fn new(coeffs: Vec<f64>) -> Option<SturmSequence> {
+        let (coeffs, balance_scale) = balance(coeffs);
+        let scale = max_abs(&coeffs);
+        let coeffs = trim(coeffs, scale);
+        if coeffs.is_empty() {
+            return None;
+        }
+
+        let derivative: Vec<f64> = coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c * i as f64)
+            .collect();
+
+        let scale = max_abs(&derivative);
+        let mut chain = vec![coeffs, trim(derivative, scale)];
+        while chain[chain.len() - 1].len() > 1 {
+            let [prev, last] = [&chain[chain.len() - 2], &chain[chain.len() - 1]];
+            let rem: Vec<f64> = trim(rem(prev, last).iter().map(|c| -c).collect(), max_abs(prev));
+            if rem.is_empty() {
+                break;
+            }
+
+            chain.push(rem);
+        }
+
+        chain.retain(|p| !p.is_empty());
+
+        Some(SturmSequence {
+            chain,
+            scale: balance_scale,
+        })
+    }
+
+    /// Evaluates the first polynomial of the sequence, the value is scaled by a positive
+    /// factor but keeps the sign and the zeros
+    pub(crate) fn eval(&self, x: f64) -> f64 {
+        horner(&self.chain[0], x / self.scale)
+    }
+
+    /// Number of sign changes of the sequence at `x`
+    fn variations(&self, x: f64) -> usize {
+        let signs: Vec<bool> = self
+            .chain
+            .iter()
+            .map(|p| horner(p, x))
+            .filter(|v| *v != 0f64)
+            .map(|v| v > 0f64)
+            .collect();
+
+        signs.windows(2).filter(|w| w[0] != w[1]).count()
+    }
+
+    /// Number of distinct real roots in `(a, b]`
+    pub(crate) fn count(&self, a: f64, b: f64) -> usize {
+        self.count_scaled(a / self.scale, b / self.scale)
+    }
+
+    /// Returns the distinct roots in `(a, b]` in ascending order, isolating them by bisection
+    /// and refining each one until the interval can't be split anymore
+    pub(crate) fn roots(&self, a: f64, b: f64) -> Vec<f64> {
+        let [a, b] = [a, b].map(|v| v / self.scale);
+        let mut roots: Vec<f64> = Vec::new();
+        self.isolate(a, b, self.count_scaled(a, b), &mut roots);
+        roots.into_iter().map(|r| r * self.scale).collect()
+    }
+
+    /// Returns the smallest root in `(a, b]`, only the leftmost interval with roots is split
+    pub(crate) fn first_root(&self, a: f64, b: f64) -> Option<f64> {
+        let [mut a, mut b] = [a, b].map(|v| v / self.scale);
+        let mut count = self.count_scaled(a, b);
+        if count == 0 {
+            return None;
+        }
+
+        while count > 1 {
+            let mid = a + (b - a) / 2f64;
+            if mid <= a || mid >= b {
+                break;
+            }
+
+            match self.count_scaled(a, mid) {
+                0 => a = mid,
+                left => (b, count) = (mid, left),
+            }
+        }
+
+        Some(self.refine(a, b) * self.scale)
+    }
+
+    /// Bound of the absolute value of every root,
+    /// [Cauchy's bound](https://en.wikipedia.org/wiki/Geometrical_properties_of_polynomial_roots#Lagrange's_and_Cauchy's_bounds)
+    pub(crate) fn root_bound(&self) -> f64 {
+        let coeffs = &self.chain[0];
+        let lead = coeffs[coeffs.len() - 1].abs();
+
+        let bound = 1f64
+            + coeffs[..coeffs.len() - 1]
+                .iter()
+                .fold(0f64, |acc, c| acc.max(c.abs() / lead));
+
+        bound * self.scale
+    }
+
+    fn count_scaled(&self, a: f64, b: f64) -> usize {
+        self.variations(a).saturating_sub(self.variations(b))
+    }
+
+    fn isolate(&self, a: f64, b: f64, count: usize, roots: &mut Vec<f64>) {
+        if count == 0 {
+            return;
+        }
+
+        if count == 1 {
+            roots.push(self.refine(a, b));
+            return;
+        }
+
+        let mid = a + (b - a) / 2f64;
+        if mid <= a || mid >= b {
+            roots.push(b);
+            return;
+        }
+
+        let left = self.count_scaled(a, mid);
+        self.isolate(a, mid, left, roots);
+        self.isolate(mid, b, count - left, roots);
+    }
+
+    /// Bisect an interval with one root until it can't be split anymore
+    fn refine(&self, mut a: f64, mut b: f64) -> f64 {
+        for _ in 0..MAX_ITERATIONS {
+            let mid = a + (b - a) / 2f64;
+            if mid <= a || mid >= b {
+                break;
+            }
+
+            match self.count_scaled(a, mid) {
+                1 => b = mid,
+                _ => a = mid,
+            }
+        }
+
+        b
+    }
+}
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the number of distinct real roots in the closed interval `[a, b]`, computed by
+    /// Sturm's theorem without finding them
+    ///
+    /// Returns an error for the zero polynomial or if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - x").unwrap();
+    ///
+    /// assert_eq!(poly.count_roots_in(-0.5, 2.0), Ok(2));
+    /// assert_eq!(poly.count_roots_in(-1.0, 1.0), Ok(3));
+    /// ```
+    pub fn count_roots_in(&self, a: f64, b: f64) -> Result<usize, &'static str> {
+        let sturm = self.sturm_sequence()?;
+        let at_a = usize::from(sturm.eval(a) == 0f64);
+
+        Ok(sturm.count(a, b) + at_a)
+    }
+
+    /// Returns an upper bound of the number of real roots in the interval `(a, b]`, counted
+    /// with multiplicity, by the
+    /// [Budan–Fourier theorem](https://en.wikipedia.org/wiki/Budan%27s_theorem): the sign
+    /// variations of `p, p', p'', ...` lost from `a` to `b`. The bound exceeds the count by an
+    /// even number, so an odd bound has a root and zero has none. It only needs the
+    /// derivatives, cheaper than [`count_roots_in`](Polynomial::count_roots_in) as a filter
+    ///
+    /// Returns an error for the zero polynomial or if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 3x^2 + 4").unwrap();
+    ///
+    /// assert_eq!(poly.budan_fourier_count(-2.0, 0.0), Ok(1));
+    /// assert_eq!(poly.budan_fourier_count(0.0, 3.0), Ok(2));
+    /// assert_eq!(poly.budan_fourier_count(3.0, 10.0), Ok(0));
+    /// ```
+    pub fn budan_fourier_count(&self, a: f64, b: f64) -> Result<usize, &'static str> {
+        let mut coeffs = self.dense_f64_coeffs()?;
+        if coeffs.iter().all(|c| *c == 0f64) {
+            return Err(ZERO);
+        }
+
+        let mut chain = Vec::new();
+        while !coeffs.is_empty() {
+            let derivative = coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * i as f64)
+                .collect();
+            chain.push(std::mem::replace(&mut coeffs, derivative));
+        }
+
+        let variations = |x: f64| {
+            let signs: Vec<bool> = chain
+                .iter()
+                .map(|p| horner(p, x))
+                .filter(|v| *v != 0f64)
+                .map(|v| v > 0f64)
+                .collect();
+
+            signs.windows(2).filter(|w| w[0] != w[1]).count()
+        };
+
+        Ok(variations(a).saturating_sub(variations(b)))
+    }
+
+    /// Returns the distinct real roots in the closed interval `[a, b]` in ascending order.
+    /// The roots are counted by Sturm's theorem first, so only the interval is searched and
+    /// no work is done if there isn't any root
+    ///
+    /// Returns an error for the zero polynomial or if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 2x").unwrap();
+    /// let roots = poly.roots_in_interval(0.0, 1.5).unwrap();
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert_eq!(roots[0], 0.0);
+    /// assert!((roots[1] - 2f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn roots_in_interval(&self, a: f64, b: f64) -> Result<Vec<f64>, &'static str> {
+        let sturm = self.sturm_sequence()?;
+
+        let mut roots = sturm.roots(a, b);
+        if sturm.eval(a) == 0f64 {
+            roots.insert(0, a);
+        }
+
+        Ok(roots)
+    }
+
+    /// Returns the smallest real root greater than zero, only the leftmost interval with
+    /// roots between zero and the root bound is isolated and refined
+    ///
+    /// Returns [`None`] if there isn't any positive root, the polynomial is zero or there is
+    /// any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^3 - 3x^2 - x + 3").unwrap();
+    ///
+    /// assert!((poly.first_positive_root().unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn first_positive_root(&self) -> Option<f64> {
+        let sturm = self.sturm_sequence().ok()?;
+        sturm.first_root(0f64, sturm.root_bound())
+    }
+
+    pub(crate) fn sturm_sequence(&self) -> Result<SturmSequence, &'static str> {
+        SturmSequence::new(self.dense_f64_coeffs()?).ok_or(ZERO)
+    }
+
+    fn dense_f64_coeffs(&self) -> Result<Vec<f64>, &'static str> {
+        self.to_coeffs()?
+            .into_iter()
+            .map(|c| c.to_f64().ok_or("Not valid coefficient"))
+            .collect()
+    }
+}
+
+/// Rescales `x` to `s x` so the lowest and the highest non zero coefficients have similar
+/// size, which keeps tiny or huge roots near one. `s` is a power of two, clamped so its powers
+/// stay finite, and the coefficients are only scaled and normalized if every result is zero
+/// or normal, so there is no rounding error. Otherwise the step is skipped. Returns the
+/// coefficients of `p(s x)`, normalized to a largest coefficient near one, and `s`
+pub(crate) fn balance(mut coeffs: Vec<f64>) -> (Vec<f64>, f64) {
+    let Some(high) = coeffs.iter().rposition(|c| *c != 0f64) else {
+        return (coeffs, 1f64);
+    };
+    let low = coeffs.iter().position(|c| *c != 0f64).unwrap_or(high);
+    if low == high {
+        return (coeffs, 1f64);
+    }
+
+    // `|a_low| s^low = |a_high| s^high`
+    let ratio = (coeffs[low] / coeffs[high]).abs();
+    let exp = (ratio.log2() / (high - low) as f64).round();
+    if !exp.is_finite() {
+        return (coeffs, 1f64);
+    }
+
+    // `2^(exp i)` is normal for every `i <= high`
+    let limit = (f64::MAX_EXP - 2) / high as i32;
+    let scale = 2f64.powi((exp as i32).clamp(-limit, limit));
+    let exact = |c: &f64| *c == 0f64 || c.is_normal();
+    let scaled: Vec<f64> = coeffs
+        .iter()
+        .enumerate()
+        .map(|(i, c)| c * scale.powi(i as i32))
+        .collect();
+    if !scaled.iter().all(exact) {
+        return (coeffs, 1f64);
+    }
+    coeffs = scaled;
+
+    let norm = max_abs(&coeffs);
+    if norm.is_normal() {
+        let norm = 2f64.powi(norm.log2().round() as i32);
+        if coeffs.iter().map(|c| c / norm).all(|c| exact(&c)) {
+            coeffs.iter_mut().for_each(|c| *c /= norm);
+        }
+    }
+
+    (coeffs, scale)
+}
+
+pub(crate) fn max_abs(coeffs: &[f64]) -> f64 {
+    coeffs.iter().fold(0f64, |acc, c| acc.max(c.abs()))
+}
+
+/// Remove the leading coefficients that are zero relative to `scale`, rounding errors of the
+/// remainders are dropped this way
+pub(crate) fn trim(mut coeffs: Vec<f64>, scale: f64) -> Vec<f64> {
+    while coeffs.last().is_some_and(|c| c.abs() <= scale * 1e-12) {
+        coeffs.pop();
+    }
+
+    coeffs
+}
+
+pub(crate) fn horner(coeffs: &[f64], x: f64) -> f64 {
+    coeffs.iter().rev().fold(0f64, |acc, c| acc * x + c)
+}
+
+/// Remainder of the long division of `lhs` by `rhs`
+pub(crate) fn rem(lhs: &[f64], rhs: &[f64]) -> Vec<f64> {
+    let mut rem = lhs.to_vec();
+    let lead = rhs[rhs.len() - 1];
+
+    while rem.len() >= rhs.len() {
+        let factor = rem[rem.len() - 1] / lead;
+        let shift = rem.len() - rhs.len();
+        for (i, c) in rhs.iter().enumerate() {
+            rem[shift + i] -= factor * c;
+        }
+        rem.pop();
+    }
+
+    rem
+}
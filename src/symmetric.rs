@@ -0,0 +1,117 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Symmetric functions of the roots, related by
+/// [Newton's identities](https://en.wikipedia.org/wiki/Newton%27s_identities). Every root,
+/// real or complex, counts as many times as its multiplicity
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the elementary symmetric functions `e_1, ..., e_n` of the roots, `e_i` is
+    /// `(-1)^i` times the coefficient of `x^(n-i)` divided by the leading one. Integer
+    /// coefficients are truncated by the division
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // Roots 1, 2 and 3
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    ///
+    /// assert_eq!(poly.elementary_symmetric(), Ok(vec![6, 11, 6]));
+    /// ```
+    pub fn elementary_symmetric(&self) -> Result<Vec<T>, &'static str> {
+        let coeffs = self.to_coeffs()?;
+        let Some(lead) = coeffs.last().copied() else {
+            return Ok(Vec::new());
+        };
+
+        let elementary = coeffs
+            .iter()
+            .rev()
+            .skip(1)
+            .enumerate()
+            .map(|(i, c)| match i % 2 {
+                0 => -*c / lead,
+                _ => *c / lead,
+            })
+            .collect();
+
+        Ok(elementary)
+    }
+
+    /// Returns the power sums `p_1, ..., p_k` of the roots, `p_j` is the sum of their `j`-th
+    /// powers, from the coefficients without finding the roots. Every step divides by the
+    /// leading coefficient, so integer coefficients are truncated unless it's `±1`
+    ///
+    /// Returns an error for the zero polynomial or if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // Roots 1, 2 and 3
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    ///
+    /// assert_eq!(poly.power_sums(4), Ok(vec![6, 14, 36, 98]));
+    /// ```
+    pub fn power_sums(&self, k: usize) -> Result<Vec<T>, &'static str> {
+        let mut coeffs = self.to_coeffs()?;
+        coeffs.reverse();
+        if coeffs.is_empty() {
+            return Err("The zero polynomial has infinite roots");
+        }
+
+        let degree = coeffs.len() - 1;
+        let mut sums: Vec<T> = Vec::with_capacity(k);
+        for j in 1..=k {
+            // c_0 p_j + c_1 p_(j-1) + ... + c_(j-1) p_1 + j c_j = 0
+            let mut acc = match j <= degree {
+                true => T::from(j).ok_or("Not valid power")? * coeffs[j],
+                false => T::zero(),
+            };
+            for i in 1..j.min(degree + 1) {
+                acc = acc + coeffs[i] * sums[j - i - 1];
+            }
+
+            sums.push(-acc / coeffs[0]);
+        }
+
+        Ok(sums)
+    }
+
+    /// Returns the monic polynomial of degree `sums.len()` whose roots have the power sums
+    /// `sums`, the inverse of [`power_sums`](Polynomial::power_sums). The identities divide
+    /// by `1, 2, ..., n`, which is exact for integer roots
+    ///
+    /// Returns an error if the number of sums doesn't fit in `T`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::from_power_sums(&[6, 14, 36]).unwrap();
+    ///
+    /// assert_eq!(format!("{poly}"), "x^3 - 6x^2 + 11x - 6");
+    /// ```
+    pub fn from_power_sums(sums: &[T]) -> Result<Self, &'static str> {
+        // e_j = (e_(j-1) p_1 - e_(j-2) p_2 + ... ± p_j) / j
+        let mut elementary: Vec<T> = vec![T::one()];
+        for j in 1..=sums.len() {
+            let acc = (1..=j).fold(T::zero(), |acc, i| {
+                let term = elementary[j - i] * sums[i - 1];
+                match i % 2 {
+                    1 => acc + term,
+                    _ => acc - term,
+                }
+            });
+
+            elementary.push(acc / T::from(j).ok_or("Not valid power")?);
+        }
+
+        let coeffs: Vec<T> = elementary
+            .into_iter()
+            .enumerate()
+            .map(|(i, e)| match i % 2 {
+                0 => e,
+                _ => -e,
+            })
+            .rev()
+            .collect();
+
+        Ok(Polynomial::from_coeffs(&coeffs))
+    }
+}
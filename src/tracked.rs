@@ -0,0 +1,241 @@
+use std::{fmt::Display, ops::Neg};
+
+use crate::{division::DivError, poly::Polynomial, MonomialValue};
+
+/// Operation recorded by a [`Tracked`] value, with the operand needed to replay it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation<P> {
+    /// Parsed from the expression
+    Parsed(String),
+
+    /// Constructed from the value
+    Created(P),
+
+    /// Added the operand
+    Added(P),
+
+    /// Multiplied by the operand
+    MultipliedBy(P),
+
+    /// Replaced by the quotient of the division by the operand
+    DividedBy(P),
+
+    /// Replaced by the remainder of the division by the operand
+    RemainderBy(P),
+
+    /// Negated
+    Negated,
+
+    /// Replaced by its derivative
+    Differentiated,
+
+    /// Replaced by its antiderivative with zero constant
+    Integrated,
+}
+
+impl<T: MonomialValue> Display for Operation<Polynomial<T>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Parsed(expr) => write!(f, "parsed from \"{expr}\""),
+            Operation::Created(poly) => write!(f, "created as {poly}"),
+            Operation::Added(poly) => write!(f, "added {poly}"),
+            Operation::MultipliedBy(poly) => write!(f, "multiplied by {poly}"),
+            Operation::DividedBy(poly) => write!(f, "divided by {poly}"),
+            Operation::RemainderBy(poly) => write!(f, "remainder by {poly}"),
+            Operation::Negated => write!(f, "negated"),
+            Operation::Differentiated => write!(f, "differentiated"),
+            Operation::Integrated => write!(f, "integrated"),
+        }
+    }
+}
+
+/// Wrapper that records every operation applied to the value since it was created, so the
+/// origin of a result can be audited and reproduced with [`Tracked::replay`]
+#[derive(Debug, Clone)]
+pub struct Tracked<P> {
+    value: P,
+    history: Vec<Operation<P>>,
+}
+
+impl<T: MonomialValue> Tracked<Polynomial<T>> {
+    /// Constructs a new tracked polynomial, its history starts with [`Operation::Created`]
+    pub fn new(poly: Polynomial<T>) -> Self {
+        Tracked {
+            history: vec![Operation::Created(poly.clone())],
+            value: poly,
+        }
+    }
+
+    /// Parses the expression, its history starts with [`Operation::Parsed`]
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, Tracked};
+    /// let mut tracked: Tracked<Polynomial<i32>> = Tracked::parse("x^2 - 1").unwrap();
+    /// tracked
+    ///     .mul(&Polynomial::try_from("x + 1").unwrap())
+    ///     .differentiate();
+    ///
+    /// let history: Vec<String> = tracked.history().iter().map(|op| op.to_string()).collect();
+    /// assert_eq!(history, ["parsed from \"x^2 - 1\"", "multiplied by x + 1", "differentiated"]);
+    /// assert_eq!(format!("{}", tracked.polynomial()), "3x^2 + 2x - 1");
+    /// ```
+    pub fn parse(expr: &str) -> Result<Self, &'static str> {
+        Ok(Tracked {
+            value: Polynomial::try_from(expr)?,
+            history: vec![Operation::Parsed(expr.to_string())],
+        })
+    }
+
+    /// Returns the tracked polynomial
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.value
+    }
+
+    /// Consumes the wrapper returning the polynomial
+    pub fn into_inner(self) -> Polynomial<T> {
+        self.value
+    }
+
+    /// Returns every operation in the order they were applied
+    pub fn history(&self) -> &[Operation<Polynomial<T>>] {
+        &self.history
+    }
+
+    /// Adds `rhs`
+    pub fn add(&mut self, rhs: &Polynomial<T>) -> &mut Self {
+        self.apply(Operation::Added(rhs.clone()))
+    }
+
+    /// Multiplies by `rhs`
+    pub fn mul(&mut self, rhs: &Polynomial<T>) -> &mut Self {
+        self.apply(Operation::MultipliedBy(rhs.clone()))
+    }
+
+    /// Keeps the quotient of the division by `rhs`
+    ///
+    /// Returns an error, without changing the polynomial or its history, if `rhs` is zero or
+    /// an exponent of the quotient overflows, like [`checked_div`](Polynomial::checked_div)
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{DivError, Polynomial, Tracked};
+    /// let mut tracked: Tracked<Polynomial<i32>> = Tracked::parse("x^2 - 1").unwrap();
+    ///
+    /// assert_eq!(tracked.div(&Polynomial::zero()).err(), Some(DivError::DivisionByZero));
+    /// assert_eq!(tracked.history().len(), 1);
+    ///
+    /// tracked.div(&Polynomial::try_from("x + 1").unwrap()).unwrap();
+    /// assert_eq!(format!("{}", tracked.polynomial()), "x - 1");
+    /// ```
+    pub fn div(&mut self, rhs: &Polynomial<T>) -> Result<&mut Self, DivError> {
+        self.value = self.value.checked_div(rhs)?.0;
+        self.history.push(Operation::DividedBy(rhs.clone()));
+
+        Ok(self)
+    }
+
+    /// Keeps the remainder of the division by `rhs`
+    ///
+    /// Returns an error, without changing the polynomial or its history, like
+    /// [`div`](Tracked::div)
+    pub fn rem(&mut self, rhs: &Polynomial<T>) -> Result<&mut Self, DivError> {
+        self.value = self.value.checked_div(rhs)?.1;
+        self.history.push(Operation::RemainderBy(rhs.clone()));
+
+        Ok(self)
+    }
+
+    /// Negates the polynomial
+    pub fn neg(&mut self) -> &mut Self {
+        self.apply(Operation::Negated)
+    }
+
+    /// Replaces the polynomial by its derivative
+    pub fn differentiate(&mut self) -> &mut Self {
+        self.apply(Operation::Differentiated)
+    }
+
+    /// Replaces the polynomial by its antiderivative with zero constant
+    ///
+    /// Returns an error, without changing the polynomial or its history, if there is a `x^-1`
+    /// term
+    pub fn integrate(&mut self) -> Result<&mut Self, &'static str> {
+        self.value = self.value.integral().ok_or("There is a x^-1 term")?;
+        self.history.push(Operation::Integrated);
+
+        Ok(self)
+    }
+
+    /// Reconstructs the polynomial applying the operations in order, the first one must be
+    /// [`Operation::Parsed`] or [`Operation::Created`] and it's the only one
+    ///
+    /// Returns an error if the history doesn't have that shape, the expression can't be
+    /// parsed or a division or integration fails
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, Tracked};
+    /// let mut tracked: Tracked<Polynomial<i32>> = Tracked::parse("x^3").unwrap();
+    /// tracked.differentiate().neg();
+    ///
+    /// assert_eq!(Tracked::replay(tracked.history()).as_ref(), Ok(tracked.polynomial()));
+    /// ```
+    pub fn replay(history: &[Operation<Polynomial<T>>]) -> Result<Polynomial<T>, &'static str> {
+        let (origin, operations) = history.split_first().ok_or("The history is empty")?;
+        let mut tracked = match origin {
+            Operation::Parsed(expr) => Tracked::parse(expr)?,
+            Operation::Created(poly) => Tracked::new(poly.clone()),
+            _ => return Err("The history must start with the origin of the polynomial"),
+        };
+
+        for operation in operations {
+            match operation {
+                Operation::Parsed(_) | Operation::Created(_) => {
+                    return Err("The origin of the polynomial must be the first operation")
+                }
+                Operation::Integrated => {
+                    tracked.integrate()?;
+                }
+                Operation::DividedBy(rhs) => {
+                    tracked
+                        .div(rhs)
+                        .map_err(|_| "A division of the history fails")?;
+                }
+                Operation::RemainderBy(rhs) => {
+                    tracked
+                        .rem(rhs)
+                        .map_err(|_| "A division of the history fails")?;
+                }
+                operation => {
+                    tracked.apply(operation.clone());
+                }
+            }
+        }
+
+        Ok(tracked.into_inner())
+    }
+
+    /// Applies and records an operation that can't fail, the ones that can fail have their
+    /// own method and keep the polynomial here
+    fn apply(&mut self, operation: Operation<Polynomial<T>>) -> &mut Self {
+        let poly = self.value.clone();
+        self.value = match &operation {
+            Operation::Added(rhs) => poly + rhs.clone(),
+            Operation::MultipliedBy(rhs) => poly * rhs.clone(),
+            Operation::Negated => poly.neg(),
+            Operation::Differentiated => poly.derivative(),
+            Operation::Parsed(_)
+            | Operation::Created(_)
+            | Operation::DividedBy(_)
+            | Operation::RemainderBy(_)
+            | Operation::Integrated => poly,
+        };
+        self.history.push(operation);
+
+        self
+    }
+}
+
+impl<T: MonomialValue> From<Polynomial<T>> for Tracked<Polynomial<T>> {
+    fn from(value: Polynomial<T>) -> Self {
+        Tracked::new(value)
+    }
+}
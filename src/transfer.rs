@@ -0,0 +1,177 @@
+use crate::{poly::Polynomial, rational::RationalFunction, MonomialValue};
+
+/// [Transfer function](https://en.wikipedia.org/wiki/Transfer_function) `N(s) / D(s)` of a
+/// linear time-invariant system, a [`RationalFunction`] without negative exponents.
+/// Compositions multiply numerators and denominators without cancelling common factors, so
+/// poles and zeros may repeat
+#[derive(Debug, Clone)]
+pub struct TransferFunction<T> {
+    rational: RationalFunction<T>,
+}
+
+impl<T: MonomialValue> TransferFunction<T> {
+    /// Constructs a new `TransferFunction<T>`
+    ///
+    /// Returns an error if the denominator is zero or there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, TransferFunction};
+    /// let num: Polynomial<i32> = Polynomial::try_from("x + 3").unwrap();
+    /// let den: Polynomial<i32> = Polynomial::try_from("x^2 + 3x + 2").unwrap();
+    ///
+    /// assert!(TransferFunction::new(num.clone(), den).is_ok());
+    /// assert!(TransferFunction::new(num, Polynomial::zero()).is_err());
+    /// ```
+    pub fn new(num: Polynomial<T>, den: Polynomial<T>) -> Result<Self, &'static str> {
+        if (&num).into_iter().chain(&den).any(|m| m.get_exp() < 0) {
+            return Err("Transfer functions can't have negative exponents");
+        }
+
+        Ok(TransferFunction {
+            rational: RationalFunction::new(num, den)?,
+        })
+    }
+
+    /// Builds the result of a composition whose denominator is a product of non-zero
+    /// denominators, without checking it again
+    fn from_parts(num: Polynomial<T>, den: Polynomial<T>) -> Self {
+        TransferFunction {
+            rational: RationalFunction { num, den },
+        }
+    }
+
+    /// Returns the numerator `N(s)`, its roots are the zeros
+    pub fn numerator(&self) -> &Polynomial<T> {
+        self.rational.numerator()
+    }
+
+    /// Returns the denominator `D(s)`, its roots are the poles
+    pub fn denominator(&self) -> &Polynomial<T> {
+        self.rational.denominator()
+    }
+
+    /// Returns the quotient `N(s) / D(s)` as a [`RationalFunction`]
+    pub fn as_rational(&self) -> &RationalFunction<T> {
+        &self.rational
+    }
+
+    /// Returns the real zeros, the [`roots`](Polynomial::roots) of the numerator
+    pub fn zeros(&self) -> Option<Vec<T>> {
+        self.numerator().roots()
+    }
+
+    /// Returns the real poles, the [`roots`](Polynomial::roots) of the denominator
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, TransferFunction};
+    /// let tf: TransferFunction<i32> = TransferFunction::new(
+    ///     Polynomial::try_from("x + 3").unwrap(),
+    ///     Polynomial::try_from("x^2 + 3x + 2").unwrap(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(tf.zeros(), Some(vec![-3]));
+    /// assert_eq!(tf.poles(), Some(vec![-2, -1]));
+    /// ```
+    pub fn poles(&self) -> Option<Vec<T>> {
+        self.denominator().roots()
+    }
+
+    /// Evaluates `N(s) / D(s)`, returns [`None`] at a pole. Integer values are truncated by
+    /// the division
+    pub fn eval(&self, s: T) -> Option<T> {
+        self.rational.eval(s)
+    }
+
+    /// Returns the steady state gain of a step input, the value at `s = 0`, or [`None`] if
+    /// there is a pole at the origin
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, TransferFunction};
+    /// let tf: TransferFunction<f64> = TransferFunction::new(
+    ///     Polynomial::try_from("x + 3").unwrap(),
+    ///     Polynomial::try_from("x^2 + 3x + 2").unwrap(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(tf.dc_gain(), Some(1.5));
+    /// ```
+    pub fn dc_gain(&self) -> Option<T> {
+        self.eval(T::zero())
+    }
+
+    /// Returns `true` if every pole, real or complex, has negative real part, checked with
+    /// the Routh–Hurwitz criterion on the denominator without finding the poles
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, TransferFunction};
+    /// let num: Polynomial<i32> = Polynomial::try_from("1").unwrap();
+    /// let stable = TransferFunction::new(num.clone(), Polynomial::try_from("x^2 + x + 1").unwrap());
+    /// let unstable = TransferFunction::new(num, Polynomial::try_from("x^2 - x + 1").unwrap());
+    ///
+    /// assert!(stable.unwrap().is_stable());
+    /// assert!(!unstable.unwrap().is_stable());
+    /// ```
+    pub fn is_stable(&self) -> bool {
+        self.denominator().is_hurwitz_stable()
+    }
+
+    /// Returns the systems connected one after the other, `G1 G2`
+    pub fn series(&self, other: &Self) -> Self {
+        let (num, den) = (self.numerator().clone(), self.denominator().clone());
+        TransferFunction::from_parts(
+            num * other.numerator().clone(),
+            den * other.denominator().clone(),
+        )
+    }
+
+    /// Returns the sum of the outputs of both systems, `G1 + G2`
+    pub fn parallel(&self, other: &Self) -> Self {
+        let (num, den) = (self.numerator().clone(), self.denominator().clone());
+        TransferFunction::from_parts(
+            num * other.denominator().clone() + other.numerator().clone() * den.clone(),
+            den * other.denominator().clone(),
+        )
+    }
+
+    /// Returns the closed loop with `other` in the negative feedback path, `G / (1 + G H)`
+    ///
+    /// Returns an error if the loop is degenerate, `G H = -1` makes the denominator zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::{Polynomial, TransferFunction};
+    /// // Unstable plant `1 / (s - 1)` stabilized with a gain of 3
+    /// let plant: TransferFunction<i32> = TransferFunction::new(
+    ///     Polynomial::try_from("1").unwrap(),
+    ///     Polynomial::try_from("x - 1").unwrap(),
+    /// )
+    /// .unwrap();
+    /// let gain = TransferFunction::new(Polynomial::try_from("3").unwrap(), Polynomial::try_from("1").unwrap());
+    /// let closed = plant.feedback(&gain.unwrap()).unwrap();
+    ///
+    /// assert!(!plant.is_stable());
+    /// assert!(closed.is_stable());
+    /// assert_eq!(closed.poles(), Some(vec![-2]));
+    /// ```
+    pub fn feedback(&self, other: &Self) -> Result<Self, &'static str> {
+        let (num, den) = (self.numerator().clone(), self.denominator().clone());
+        Ok(TransferFunction {
+            rational: RationalFunction::new(
+                num.clone() * other.denominator().clone(),
+                den * other.denominator().clone() + num * other.numerator().clone(),
+            )?,
+        })
+    }
+}
+
+impl<T: MonomialValue> PartialEq for TransferFunction<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rational == other.rational
+    }
+}
+
+impl<T: MonomialValue> From<TransferFunction<T>> for RationalFunction<T> {
+    fn from(tf: TransferFunction<T>) -> Self {
+        tf.rational
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Changes of variable, the input of the closed form solvers and graphing helpers
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns `p(x + h)`, the polynomial translated `h` units to the left
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 4x + 1").unwrap();
+    ///
+    /// assert_eq!(format!("{}", poly.shift(2).unwrap()), "x^2 - 3");
+    /// ```
+    pub fn shift(&self, h: T) -> Result<Self, &'static str> {
+        let coeffs = self.to_coeffs()?;
+        let binomial = Polynomial::new(vec![Monomial::new(T::one(), 1), Monomial::new(h, 0)]);
+
+        let result = coeffs.iter().rev().fold(Polynomial::zero(), |acc, coeff| {
+            acc * binomial.clone() + Polynomial::from(Monomial::new(*coeff, 0))
+        });
+
+        Ok(result.with_var(self.get_var()))
+    }
+
+    /// Returns the depressed polynomial `q` without the second-highest term
+    /// ([Tschirnhaus transformation](https://en.wikipedia.org/wiki/Tschirnhaus_transformation))
+    /// and the shift `s` such that `q(x) = p(x + s)`, so every root of `q` plus `s` is a root
+    /// of `p`
+    ///
+    /// Integer coefficients are truncated by the division. Returns an error if there is any
+    /// negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    /// let (depressed, s) = poly.shift_to_depressed().unwrap();
+    ///
+    /// assert_eq!(s, 2.0);
+    /// assert_eq!(format!("{depressed}"), "x^3 - x");
+    /// ```
+    pub fn shift_to_depressed(&self) -> Result<(Self, T), &'static str> {
+        // Constants are already depressed, the shift by zero only rejects negative exponents
+        let lead = self.max_exp();
+        if lead.get_exp() < 1 {
+            return Ok((self.shift(T::zero())?, T::zero()));
+        }
+
+        let n = T::from(lead.get_exp()).ok_or("Not valid exponent")?;
+        let s = -self.find_by_exp(lead.get_exp() - 1).get_value() / (n * lead.get_value());
+
+        Ok((self.shift(s)?, s))
+    }
+
+    /// Returns the vertex form `a(x - h)^2 + k` of a quadratic as `(a, h, k)`, where `(h, k)`
+    /// is the vertex of the parabola
+    ///
+    /// Integer coefficients are truncated by the division. Returns [`None`] if the polynomial
+    /// isn't `ax^2 + bx + c`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<f64> = Polynomial::try_from("2x^2 - 4x + 5").unwrap();
+    ///
+    /// assert_eq!(poly.to_vertex_form(), Some((2.0, 1.0, 3.0)));
+    /// ```
+    pub fn to_vertex_form(&self) -> Option<(T, T, T)> {
+        let coeffs = self.to_coeffs().ok()?;
+        let [c, b, a] = <[T; 3]>::try_from(coeffs).ok()?;
+
+        let two = T::one() + T::one();
+        let h = -b / (two * a);
+        let k = c - b * b / (two * two * a);
+
+        Some((a, h, k))
+    }
+}
@@ -0,0 +1,68 @@
+//! Crossover thresholds between the algorithms used by the polynomial operations
+//!
+//! Every threshold can be changed at runtime and is shared by the whole process. The defaults
+//! are rough starting points, the `thresholds` bench (`cargo bench --bench thresholds`)
+//! compares the strategies around them, run it on the target machine and coefficient type to
+//! find the sweet spots.
+//! ```
+//! use rust_polynomial::tuning;
+//!
+//! tuning::set_toom3_threshold(32);
+//! assert_eq!(tuning::toom3_threshold(), 32);
+//!
+//! tuning::reset();
+//! assert_eq!(tuning::toom3_threshold(), tuning::DEFAULT_TOOM3_THRESHOLD);
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default minimum number of monomials in both operands to multiply by Toom-3
+pub const DEFAULT_TOOM3_THRESHOLD: usize = 24;
+
+/// Default minimum number of monomials in both operands to multiply by Kronecker substitution
+pub const DEFAULT_KRONECKER_THRESHOLD: usize = 64;
+
+/// Default minimum quotient degree to divide through Newton inversion
+pub const DEFAULT_NEWTON_DIVISION_THRESHOLD: usize = 64;
+
+static TOOM3_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_TOOM3_THRESHOLD);
+static KRONECKER_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_KRONECKER_THRESHOLD);
+static NEWTON_DIVISION_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_NEWTON_DIVISION_THRESHOLD);
+
+/// Minimum number of monomials in both operands to multiply by Toom-3 instead of the naive
+/// loop, also the size where Toom-3 stops recursing
+pub fn toom3_threshold() -> usize {
+    TOOM3_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Set the [`toom3_threshold`], `usize::MAX` disables Toom-3
+pub fn set_toom3_threshold(value: usize) {
+    TOOM3_THRESHOLD.store(value, Ordering::Relaxed);
+}
+
+/// Minimum number of monomials in both operands to multiply by Kronecker substitution
+pub fn kronecker_threshold() -> usize {
+    KRONECKER_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Set the [`kronecker_threshold`], `usize::MAX` disables Kronecker substitution
+pub fn set_kronecker_threshold(value: usize) {
+    KRONECKER_THRESHOLD.store(value, Ordering::Relaxed);
+}
+
+/// Minimum quotient degree to divide through Newton inversion instead of long division
+pub fn newton_division_threshold() -> usize {
+    NEWTON_DIVISION_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Set the [`newton_division_threshold`], `usize::MAX` disables Newton division
+pub fn set_newton_division_threshold(value: usize) {
+    NEWTON_DIVISION_THRESHOLD.store(value, Ordering::Relaxed);
+}
+
+/// Restore every threshold to its default
+pub fn reset() {
+    set_toom3_threshold(DEFAULT_TOOM3_THRESHOLD);
+    set_kronecker_threshold(DEFAULT_KRONECKER_THRESHOLD);
+    set_newton_division_threshold(DEFAULT_NEWTON_DIVISION_THRESHOLD);
+}
@@ -0,0 +1,208 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use num::{Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+use crate::DoubleDouble;
+
+/// Extended precision float, a wrapper of [`twofloat::TwoFloat`] with the traits of a
+/// [`MonomialValue`](crate::MonomialValue). Like [`DoubleDouble`] it's the unevaluated sum of
+/// two [`f64`] with about 32 decimal digits, for projects already using the `twofloat` crate
+///
+/// Parsing, `Display` and the division go through [`DoubleDouble`], so every digit is read
+/// and written
+/// # Examples
+/// ```
+/// # use rust_polynomial::{Polynomial, TwoFloat};
+/// // (x - 1)^7 close to the root, where f64 only returns rounding noise
+/// let expanded = "x^7 - 7x^6 + 21x^5 - 35x^4 + 35x^3 - 21x^2 + 7x - 1";
+/// let poly: Polynomial<TwoFloat> = Polynomial::try_from(expanded).unwrap();
+/// let x: TwoFloat = "1.001".parse().unwrap();
+/// let exact = 1e-21;
+///
+/// assert!((poly.eval(x).0.hi() - exact).abs() < 1e-6 * exact);
+///
+/// let root = Polynomial::<TwoFloat>::try_from("x^2 - 2").unwrap().newton_refine(2f64.sqrt().into(), 5);
+/// assert_eq!(format!("{root}"), "1.4142135623730950488016887242097");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TwoFloat(pub twofloat::TwoFloat);
+
+impl TwoFloat {
+    /// The same value as a [`DoubleDouble`], exactly
+    fn double(self) -> DoubleDouble {
+        DoubleDouble::new(self.0.hi(), self.0.lo())
+    }
+}
+
+impl From<twofloat::TwoFloat> for TwoFloat {
+    fn from(value: twofloat::TwoFloat) -> Self {
+        TwoFloat(value)
+    }
+}
+
+impl From<TwoFloat> for twofloat::TwoFloat {
+    fn from(value: TwoFloat) -> Self {
+        value.0
+    }
+}
+
+impl From<f64> for TwoFloat {
+    fn from(value: f64) -> Self {
+        TwoFloat(value.into())
+    }
+}
+
+impl From<DoubleDouble> for TwoFloat {
+    fn from(value: DoubleDouble) -> Self {
+        TwoFloat(twofloat::TwoFloat::new_add(value.hi(), value.lo()))
+    }
+}
+
+impl From<TwoFloat> for DoubleDouble {
+    fn from(value: TwoFloat) -> Self {
+        value.double()
+    }
+}
+
+impl Display for TwoFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.double(), f)
+    }
+}
+
+/// Decimal strings with optional sign, fraction and exponent, `-1.25e-3`
+impl FromStr for TwoFloat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<DoubleDouble>().map(Into::into)
+    }
+}
+
+macro_rules! impl_two_float_op {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait for TwoFloat {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    TwoFloat(self.0.$method(rhs.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_two_float_op!(Add add, Sub sub, Mul mul);
+
+/// Computed as a [`DoubleDouble`], the division of `twofloat` 0.8 drops the low part of
+/// quotients like `1 / 3`
+impl Div for TwoFloat {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        (self.double() / rhs.double()).into()
+    }
+}
+
+/// Same as [`Div`], computed as a [`DoubleDouble`]
+impl Rem for TwoFloat {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        (self.double() % rhs.double()).into()
+    }
+}
+
+impl Neg for TwoFloat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        TwoFloat(-self.0)
+    }
+}
+
+impl Zero for TwoFloat {
+    fn zero() -> Self {
+        TwoFloat(twofloat::TwoFloat::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.hi() == 0f64
+    }
+}
+
+impl One for TwoFloat {
+    fn one() -> Self {
+        TwoFloat(twofloat::TwoFloat::one())
+    }
+}
+
+impl Num for TwoFloat {
+    type FromStrRadixErr = &'static str;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        match radix {
+            10 => str.parse(),
+            _ => Err("Only radix 10 is supported"),
+        }
+    }
+}
+
+/// Zero is neither positive nor negative, unlike the signs of the wrapped type
+impl Signed for TwoFloat {
+    fn abs(&self) -> Self {
+        TwoFloat(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        match self > other {
+            true => *self - *other,
+            false => TwoFloat::zero(),
+        }
+    }
+
+    fn signum(&self) -> Self {
+        match self.is_zero() {
+            true => TwoFloat::zero(),
+            false => TwoFloat(self.0.signum()),
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.hi() > 0f64
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.hi() < 0f64
+    }
+}
+
+impl ToPrimitive for TwoFloat {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0.hi())
+    }
+}
+
+/// Integers are converted exactly up to 106 bits, other values through [`f64`]
+impl NumCast for TwoFloat {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        <DoubleDouble as NumCast>::from(n).map(Into::into)
+    }
+}
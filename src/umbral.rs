@@ -0,0 +1,92 @@
+use crate::{poly::Polynomial, MonomialValue};
+
+/// Transforms of the coefficient sequence `a_0, a_1, ..., a_n`, for combinatorics
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the [binomial transform](https://en.wikipedia.org/wiki/Binomial_transform) of the
+    /// coefficients, `b_n = sum C(n, k) a_k`
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// // 1, 1, 1, 1 becomes the powers of two
+    /// let poly: Polynomial<i32> = Polynomial::from_coeffs(&[1, 1, 1, 1]);
+    ///
+    /// assert_eq!(poly.binomial_transform().unwrap().to_coeffs(), Ok(vec![1, 2, 4, 8]));
+    /// ```
+    pub fn binomial_transform(&self) -> Result<Self, &'static str> {
+        self.binomial_with(T::one())
+    }
+
+    /// Returns the inverse of [`binomial_transform`](Polynomial::binomial_transform),
+    /// `a_n = sum (-1)^(n - k) C(n, k) b_k`
+    ///
+    /// Returns an error if there is any negative exponent
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i32> = Polynomial::try_from("8x^3 + 4x^2 + 2x + 1").unwrap();
+    ///
+    /// assert_eq!(poly.inverse_binomial_transform().unwrap().to_coeffs(), Ok(vec![1, 1, 1, 1]));
+    /// ```
+    pub fn inverse_binomial_transform(&self) -> Result<Self, &'static str> {
+        self.binomial_with(-T::one())
+    }
+
+    /// Returns `p(x + k)`, the `k`-th power of the shift operator `E p(x) = p(x + 1)`. It's
+    /// the same as [`shift`](Polynomial::shift), computed in place on the coefficients with
+    /// the Taylor shift, `O(n^2)` multiplications by `k` and additions without building any
+    /// intermediate polynomial
+    ///
+    /// Returns an error if there is any negative exponent or `k` can't be represented as `T`
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let poly: Polynomial<i64> = Polynomial::try_from("x^3 - 2x + 1").unwrap();
+    ///
+    /// assert_eq!(poly.shift_operator_power(-3), poly.shift(-3));
+    /// assert_eq!(format!("{}", poly.shift_operator_power(1).unwrap()), "x^3 + 3x^2 + x");
+    /// ```
+    pub fn shift_operator_power(&self, k: i64) -> Result<Self, &'static str> {
+        let mut coeffs = self.to_coeffs()?;
+        let k = T::from(k).ok_or("Not valid shift")?;
+
+        // Horner's scheme repeated, each pass divides by `x - k` and leaves a Taylor
+        // coefficient at `k` behind
+        let n = coeffs.len();
+        for i in 0..n {
+            for j in (i..n.saturating_sub(1)).rev() {
+                coeffs[j] = coeffs[j] + k * coeffs[j + 1];
+            }
+        }
+
+        Ok(Polynomial::from_coeffs(&coeffs).with_var(self.get_var()))
+    }
+
+    /// Binomial transform `b_n = sum C(n, k) sign^(n - k) a_k`, building the rows of Pascal's
+    /// triangle with the signs
+    fn binomial_with(&self, sign: T) -> Result<Self, &'static str> {
+        let coeffs = self.to_coeffs()?;
+
+        let mut row: Vec<T> = Vec::with_capacity(coeffs.len());
+        let mut transformed = Vec::with_capacity(coeffs.len());
+        for _ in 0..coeffs.len() {
+            // Next row from the previous one, `C(n, k) = C(n - 1, k) + C(n - 1, k - 1)`
+            row.push(T::one());
+            for k in (1..row.len() - 1).rev() {
+                row[k] = sign * row[k] + row[k - 1];
+            }
+            if row.len() > 1 {
+                row[0] = sign * row[0];
+            }
+
+            let value = row
+                .iter()
+                .zip(&coeffs)
+                .fold(T::zero(), |acc, (c, a)| acc + *c * *a);
+            transformed.push(value);
+        }
+
+        Ok(Polynomial::from_coeffs(&transformed).with_var(self.get_var()))
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::Neg;
+
+use crate::{mono::Monomial, poly::Polynomial, MonomialValue};
+
+/// Row of the worksheet, its left margin and its terms
+type Row<T> = (String, Vec<Monomial<T>>);
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Renders the long division by `divisor` as the classic worksheet: the quotient over a
+    /// bar, `divisor ) dividend` and, for every term of the quotient, the product that is
+    /// subtracted, marked with `-` in the margin, a rule and the remainder. Terms with the same
+    /// exponent are aligned in columns
+    ///
+    /// The division stops like `/` when the remainder has lower degree than the divisor, or
+    /// when the next term of the quotient is zero, like dividing `x` by `2x` with integer
+    /// coefficients
+    ///
+    /// Returns an error if the divisor is zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let dividend: Polynomial<i32> = Polynomial::try_from("x^3 - 2x^2 - 4").unwrap();
+    /// let divisor: Polynomial<i32> = Polynomial::try_from("x - 3").unwrap();
+    ///
+    /// let expected = [
+    ///     "               x^2  + x + 3",
+    ///     "        -------------------",
+    ///     "x - 3 ) x^3 - 2x^2      - 4",
+    ///     "      - x^3 - 3x^2",
+    ///     "        ----------",
+    ///     "               x^2      - 4",
+    ///     "      -        x^2 - 3x",
+    ///     "               --------",
+    ///     "                     3x - 4",
+    ///     "      -              3x - 9",
+    ///     "                     ------",
+    ///     "                          5",
+    /// ];
+    /// assert_eq!(dividend.long_division_worksheet(&divisor), Ok(expected.join("\n")));
+    /// ```
+    pub fn long_division_worksheet(&self, divisor: &Self) -> Result<String, &'static str> {
+        if divisor.is_zero() {
+            return Err("The divisor must not be zero");
+        }
+
+        let var = self.get_var();
+        let divisor_text = divisor.clone().with_var(var).to_string();
+        let margin = " ".repeat(divisor_text.len() + 3);
+        let subtract = format!("{}- ", " ".repeat(divisor_text.len() + 1));
+        let lead = divisor.max_exp();
+
+        let mut quotient = Vec::new();
+        let mut rows: Vec<Row<T>> = vec![(format!("{divisor_text} ) "), terms(self))];
+        let mut rem = self.clone();
+        while !rem.is_zero() && rem.max_exp().get_exp() >= lead.get_exp() {
+            let term = rem.max_exp() / lead;
+            if term.get_value().is_zero() {
+                break;
+            }
+
+            let product = divisor.clone().mul_mono(term);
+            rem = rem + product.clone().neg();
+            quotient.push(term);
+            rows.push((subtract.clone(), terms(&product)));
+            rows.push((margin.clone(), terms(&rem)));
+        }
+
+        let mut exps: Vec<i32> = rows
+            .iter()
+            .flat_map(|(_, terms)| terms.iter().map(|m| m.get_exp()))
+            .chain(quotient.iter().map(|m| m.get_exp()))
+            .collect();
+        exps.sort_unstable_by(|a, b| b.cmp(a));
+        exps.dedup();
+
+        let quotient_cells = cells(&quotient, &exps, var);
+        let row_cells: Vec<Vec<Option<String>>> = rows
+            .iter()
+            .map(|(_, terms)| cells(terms, &exps, var))
+            .collect();
+        let widths: Vec<usize> = (0..exps.len())
+            .map(|col| {
+                row_cells
+                    .iter()
+                    .chain([&quotient_cells])
+                    .filter_map(|row| row[col].as_ref().map(String::len))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let render = |margin: &str, cells: &[Option<String>]| {
+            let mut line = margin.to_string();
+            for (cell, width) in cells.iter().zip(&widths) {
+                line += &format!("{:>width$} ", cell.as_deref().unwrap_or(""));
+            }
+            line.trim_end().to_string()
+        };
+
+        // Dashes under the terms of the row
+        let rule = |cells: &[Option<String>]| {
+            let first = cells.iter().position(Option::is_some).unwrap_or(0);
+            let padding = widths[first] - cells[first].as_ref().map_or(0, String::len);
+            let start = padding + (0..first).map(|col| widths[col] + 1).sum::<usize>();
+            let end: usize = (0..exps.len())
+                .take_while(|col| cells[*col..].iter().any(Option::is_some))
+                .map(|col| widths[col] + 1)
+                .sum();
+            format!(
+                "{margin}{}{}",
+                " ".repeat(start),
+                "-".repeat(end.saturating_sub(start + 1))
+            )
+        };
+
+        let total = widths.iter().map(|w| w + 1).sum::<usize>().max(2) - 1;
+        let zero = |margin: &str| format!("{margin}{:>total$}", "0");
+
+        let mut lines = vec![
+            match quotient.is_empty() {
+                true => zero(&margin),
+                false => render(&margin, &quotient_cells),
+            },
+            format!("{margin}{}", "-".repeat(total)),
+        ];
+
+        for (i, ((margin, terms), cells)) in rows.iter().zip(&row_cells).enumerate() {
+            lines.push(match terms.is_empty() {
+                true => zero(margin),
+                false => render(margin, cells),
+            });
+
+            if i % 2 == 1 {
+                lines.push(rule(cells));
+            }
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Nonzero terms of the polynomial
+fn terms<T: MonomialValue>(poly: &Polynomial<T>) -> Vec<Monomial<T>> {
+    poly.into_iter()
+        .filter(|m| !m.get_value().is_zero())
+        .copied()
+        .collect()
+}
+
+/// Text of every column of a row, signed like the terms of [`Display`](std::fmt::Display)
+fn cells<T: MonomialValue>(terms: &[Monomial<T>], exps: &[i32], var: char) -> Vec<Option<String>> {
+    exps.iter()
+        .map(|exp| {
+            let index = terms.iter().position(|m| m.get_exp() == *exp)?;
+            let mono = terms[index];
+            let abs = Polynomial::from(Monomial::new(mono.get_value().abs(), mono.get_exp()))
+                .with_var(var)
+                .to_string();
+
+            Some(match (index, mono.get_value() < T::zero()) {
+                (0, true) => format!("-{abs}"),
+                (0, false) => abs,
+                (_, true) => format!("- {abs}"),
+                (_, false) => format!("+ {abs}"),
+            })
+        })
+        .collect()
+}
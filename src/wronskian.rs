@@ -0,0 +1,85 @@
+use std::collections::BTreeSet;
+
+use num::BigInt;
+
+use crate::{
+    checked::integer, matrix::PolyMatrix, poly::Polynomial, resultant::bareiss, MonomialValue,
+};
+
+impl<T: MonomialValue> Polynomial<T> {
+    /// Returns the [Wronskian](https://en.wikipedia.org/wiki/Wronskian), the determinant of
+    /// the matrix whose row `i` has the `i`-th derivatives of the polynomials. It's not the
+    /// zero polynomial only if they are linearly independent. The determinant is expanded by
+    /// cofactors, so it's meant for small sets like the bases of ODE solutions
+    ///
+    /// An empty set returns one, the determinant of the empty matrix
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let basis: Vec<Polynomial<i32>> = ["1", "x", "x^2"]
+    ///     .iter()
+    ///     .map(|p| Polynomial::try_from(*p).unwrap())
+    ///     .collect();
+    ///
+    /// assert_eq!(format!("{}", Polynomial::wronskian(&basis)), "2");
+    /// ```
+    pub fn wronskian(polys: &[Self]) -> Self {
+        let var = polys.first().map_or('x', |p| p.get_var());
+        let rows = (0..polys.len())
+            .scan(polys.to_vec(), |row, _| {
+                let current = row.clone();
+                *row = row.iter().map(Polynomial::derivative).collect();
+                Some(current)
+            })
+            .collect();
+
+        match PolyMatrix::new(rows) {
+            Ok(matrix) => matrix.determinant().unwrap_or_else(|_| Polynomial::zero()),
+            Err(_) => Polynomial::from_coeffs(&[T::one()]),
+        }
+        .with_var(var)
+    }
+
+    /// Returns `true` if no nontrivial combination of the polynomials is zero, the rank of
+    /// their coefficient matrix equals their number. The rank is found by fraction-free
+    /// Bareiss elimination with big integers, so it's exact for integer coefficients, while
+    /// floating point coefficients are eliminated as they are and compared exactly with zero
+    /// # Examples
+    /// ```
+    /// # use rust_polynomial::Polynomial;
+    /// let set: Vec<Polynomial<i32>> = ["x^2 + 1", "x - 1", "x^2 + x"]
+    ///     .iter()
+    ///     .map(|p| Polynomial::try_from(*p).unwrap())
+    ///     .collect();
+    ///
+    /// assert!(!Polynomial::are_linearly_independent(&set));
+    /// assert!(Polynomial::are_linearly_independent(&set[..2]));
+    /// ```
+    pub fn are_linearly_independent(polys: &[Self]) -> bool {
+        let exps: BTreeSet<i32> = polys.iter().flatten().map(|m| m.get_exp()).collect();
+        let mut rows: Vec<Vec<T>> = polys
+            .iter()
+            .map(|p| {
+                exps.iter()
+                    .map(|exp| {
+                        p.into_iter()
+                            .find(|m| m.get_exp() == *exp)
+                            .map_or(T::zero(), |m| m.get_value())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // The minors of the elimination outgrow `T`, integers are eliminated as big integers
+        let integers: Option<Vec<Vec<BigInt>>> = rows
+            .iter()
+            .map(|row| row.iter().map(|c| integer(*c).map(BigInt::from)).collect())
+            .collect();
+        let rank = match integers {
+            Some(mut integers) => bareiss(&mut integers).0,
+            None => bareiss(&mut rows).0,
+        };
+
+        rank == polys.len()
+    }
+}
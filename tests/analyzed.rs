@@ -0,0 +1,34 @@
+use rust_polynomial::{AnalyzedPolynomial, Monomial, Polynomial};
+
+#[test]
+fn cached_results_match_polynomial() {
+    let poly = Polynomial::<i32>::try_from("x^3 - 5x^2 - x + 5").unwrap();
+    let analyzed = AnalyzedPolynomial::new(poly.clone());
+
+    assert_eq!(analyzed.derivative(), &poly.derivative());
+    assert_eq!(analyzed.roots(), poly.roots().as_ref());
+    assert_eq!(analyzed.extrema(), poly.extrema().as_ref());
+    assert_eq!(analyzed.factors(), poly.factors().as_slice());
+}
+
+#[test]
+fn mutation_invalidates_cache() {
+    let poly = Polynomial::<i32>::try_from("x^2 - 4x + 3").unwrap();
+    let mut analyzed = AnalyzedPolynomial::new(poly);
+
+    assert_eq!(analyzed.roots(), Some(&vec![1, 3]));
+    assert_eq!(format!("{}", analyzed.derivative()), "2x - 4");
+
+    analyzed.push(Monomial::new(1, 0));
+
+    assert_eq!(analyzed.roots(), Some(&vec![2]));
+    assert_eq!(analyzed.extrema(), Some(&vec![(2, 0)]));
+
+    analyzed.update(|p| *p = Polynomial::try_from("x - 1").unwrap());
+
+    assert_eq!(format!("{}", analyzed.derivative()), "1");
+    assert_eq!(
+        analyzed.into_inner(),
+        Polynomial::try_from("x - 1").unwrap()
+    );
+}
@@ -0,0 +1,62 @@
+use rust_polynomial::{DivError, PolyArena, Polynomial};
+
+#[test]
+fn division_in_arena_matches_checked_div() {
+    let mut arena = PolyArena::new();
+    let cases = [
+        ("x^7 - 3x^5 + 2x^2 - x + 9", "x^3 + 2x - 1"),
+        ("x^2 + 3x^-1", "x^-1"),
+        ("4x^3 + 8x^2 - 4x - 8", "2x + 2"),
+        ("x", "2x"),
+        ("x^2 - 1", "x^5"),
+    ];
+    for (p, q) in cases {
+        let p: Polynomial<i64> = Polynomial::try_from(p).unwrap();
+        let q: Polynomial<i64> = Polynomial::try_from(q).unwrap();
+        assert_eq!(p.div_in(&q, &mut arena), p.checked_div(&q), "{p} / {q}");
+        assert_eq!(p.euclidean_chain_in(&q, &mut arena), p.euclidean_chain(&q));
+    }
+
+    let p: Polynomial<f64> = Polynomial::try_from("0.3x^4 - 0.1x^3 + 0.7").unwrap();
+    let q: Polynomial<f64> = Polynomial::try_from("0.1x^2 + 0.2").unwrap();
+    let (quotient, remainder) = p.div_in(&q, &mut PolyArena::new()).unwrap();
+    let back = quotient * q + remainder;
+    let error = (back + -p)
+        .into_iter()
+        .fold(0f64, |acc, m| acc.max(m.get_value().abs()));
+    assert!(error < 1e-12);
+
+    let p: Polynomial<i64> = Polynomial::try_from("x").unwrap();
+    assert_eq!(
+        p.div_in(&Polynomial::zero(), &mut arena),
+        Err(DivError::DivisionByZero)
+    );
+    let low: Polynomial<i64> = Polynomial::try_from("x^-2147483647").unwrap();
+    let high: Polynomial<i64> = Polynomial::try_from("x^2147483647 + 1").unwrap();
+    assert_eq!(
+        low.div_in(&high, &mut arena),
+        Err(DivError::ExponentOverflow)
+    );
+}
+
+#[test]
+fn buffers_are_reused() {
+    let mut arena = PolyArena::new();
+    assert_eq!(arena.available(), 0);
+    arena.recycle(Polynomial::<i32>::zero());
+    assert_eq!(arena.available(), 0);
+
+    let p: Polynomial<i32> = Polynomial::try_from("x^9 + x^4 - 1").unwrap();
+    let q: Polynomial<i32> = Polynomial::try_from("x^2 - x").unwrap();
+    let chain = p.euclidean_chain_in(&q, &mut arena);
+    assert_eq!(chain, p.euclidean_chain(&q));
+
+    // Later divisions take the buffers instead of allocating
+    let available = arena.available();
+    assert!(available > 0);
+    let (quotient, remainder) = p.div_in(&q, &mut arena).unwrap();
+    assert!(arena.available() < available + 2);
+    arena.recycle(quotient);
+    arena.recycle(remainder);
+    assert!(arena.available() >= available);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::{array, Array1};
+use rust_polynomial::Polynomial;
+
+#[test]
+fn array1_conversion() {
+    let poly = Polynomial::<i64>::try_from("-6x^4 + 20x - 8").unwrap();
+
+    assert_eq!(poly.to_array1(), Ok(array![-8, 20, 0, 0, -6]));
+    assert_eq!(
+        Polynomial::from_array1(array![-8, 20, 0, 0, -6].view()),
+        poly
+    );
+
+    let strided = array![1.0, 9.0, 2.0, 9.0, 3.0];
+    let strided = strided.slice(ndarray::s![..;2]);
+    assert_eq!(
+        Polynomial::from_array1(strided),
+        Polynomial::try_from("3x^2 + 2x + 1").unwrap()
+    );
+}
+
+#[test]
+fn array1_eval() {
+    let poly = Polynomial::<f64>::try_from("0.5x^3 - x + 2").unwrap();
+    let xs = Array1::linspace(-2.0, 2.0, 9);
+    let ys = poly.eval_array1(xs.view());
+
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        assert_eq!(*y, poly.eval(*x));
+    }
+}
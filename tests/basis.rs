@@ -0,0 +1,134 @@
+use rust_polynomial::{Bezier, PolyBasis, Polynomial};
+
+fn poly(expr: &str) -> Polynomial<f64> {
+    Polynomial::try_from(expr).unwrap()
+}
+
+#[test]
+fn coordinates_round_trip() {
+    let custom = PolyBasis::new(vec![
+        poly("x^3 - x"),
+        poly("2x^2 + 1"),
+        poly("x + 3"),
+        poly("-2"),
+    ])
+    .unwrap();
+    let bases = [
+        PolyBasis::monomial(3),
+        PolyBasis::chebyshev(3),
+        PolyBasis::bernstein(3),
+        custom,
+    ];
+
+    for expr in ["x^3 - 2x^2 + 0.5x - 7", "3x^2 + 1", "-x"] {
+        let p = poly(expr);
+        for basis in &bases {
+            let coordinates = p.coordinates_in(basis).unwrap();
+            assert_eq!(coordinates.len(), basis.dimension());
+
+            let back = basis.from_coordinates(&coordinates).unwrap();
+            let error = (back + -p.clone())
+                .into_iter()
+                .fold(0f64, |acc, m| acc.max(m.get_value().abs()));
+            assert!(error < 1e-12, "{expr}");
+        }
+    }
+
+    let p = poly("x^3 - 2x^2 + 0.5x - 7");
+    assert_eq!(
+        p.coordinates_in(&PolyBasis::monomial(4)),
+        Ok(vec![-7.0, 0.5, -2.0, 1.0, 0.0])
+    );
+    let control = Bezier::from_polynomial(&p, 3).unwrap();
+    for (a, b) in p
+        .coordinates_in(&PolyBasis::bernstein(3))
+        .unwrap()
+        .iter()
+        .zip(control.control_points())
+    {
+        assert!((a - b).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn invalid_bases_and_degrees() {
+    assert!(PolyBasis::<f64>::new(Vec::new()).is_err());
+    assert!(PolyBasis::new(vec![poly("x^2"), poly("1")]).is_err());
+    assert!(PolyBasis::new(vec![poly("x + 1"), poly("2x + 2")]).is_err());
+    assert!(PolyBasis::new(vec![poly("x^-1"), poly("1")]).is_err());
+
+    let basis = PolyBasis::chebyshev(2);
+    assert_eq!(basis.basis().len(), 3);
+    assert!(poly("x^3").coordinates_in(&basis).is_err());
+    assert!(poly("x^-1").coordinates_in(&basis).is_err());
+    assert!(basis.from_coordinates(&[1.0, 2.0]).is_err());
+
+    // Integer coordinates are exact for bases with unit leading coefficients
+    let shifted: PolyBasis<i32> = PolyBasis::new(vec![
+        Polynomial::try_from("1").unwrap(),
+        Polynomial::try_from("x + 1").unwrap(),
+        Polynomial::try_from("x^2 + 2x + 1").unwrap(),
+    ])
+    .unwrap();
+    let p: Polynomial<i32> = Polynomial::try_from("x^2 + 3x + 5").unwrap();
+    assert_eq!(p.coordinates_in(&shifted), Ok(vec![3, 1, 1]));
+
+    // Rows below the pivot are eliminated without truncating, `4x + 5 = 2(x + 2) + (2x + 1)`
+    let basis: PolyBasis<i32> = PolyBasis::new(vec![
+        Polynomial::try_from("x + 2").unwrap(),
+        Polynomial::try_from("2x + 1").unwrap(),
+    ])
+    .unwrap();
+    let p: Polynomial<i32> = Polynomial::try_from("4x + 5").unwrap();
+    assert_eq!(p.coordinates_in(&basis), Ok(vec![2, 1]));
+    // `x = (2(2x + 1) - (x + 2)) / 3`
+    let x: Polynomial<i32> = Polynomial::try_from("x").unwrap();
+    assert!(x.coordinates_in(&basis).is_err());
+    let coordinates = poly("x")
+        .coordinates_in(&PolyBasis::new(vec![poly("x + 2"), poly("2x + 1")]).unwrap())
+        .unwrap();
+    assert!((coordinates[0] + 1.0 / 3.0).abs() < 1e-12);
+    assert!((coordinates[1] - 2.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn falling_factorial_coordinates() {
+    // Stirling numbers of the second kind
+    let poly: Polynomial<i64> = Polynomial::try_from("x^4").unwrap();
+    let basis = PolyBasis::falling_factorial(4);
+    assert_eq!(poly.coordinates_in(&basis), Ok(vec![0, 1, 7, 6, 1]));
+    assert_eq!(basis.from_coordinates(&[0, 1, 7, 6, 1]), Ok(poly));
+    assert_eq!(
+        format!("{}", PolyBasis::<i64>::falling_factorial(3).basis()[3]),
+        "x^3 - 3x^2 + 2x"
+    );
+}
+
+#[test]
+fn integer_valued_polynomials() {
+    let valued = [
+        "0.5x^2 - 0.5x",
+        "0.5x^3 - 0.5x",
+        "0.125x^4 - 0.75x^3 + 1.375x^2 - 0.75x",
+        "3x^2 + 1",
+        "0",
+    ];
+    for p in valued {
+        assert!(
+            Polynomial::<f64>::try_from(p).unwrap().is_integer_valued(),
+            "{p}"
+        );
+    }
+
+    let not_valued = ["0.5x^2", "0.5x^3 - 0.25x", "x + 0.5", "x^-1", "x + 1e-9"];
+    for p in not_valued {
+        assert!(
+            !Polynomial::<f64>::try_from(p).unwrap().is_integer_valued(),
+            "{p}"
+        );
+    }
+
+    assert!(Polynomial::<i32>::try_from("7x^5 - x")
+        .unwrap()
+        .is_integer_valued());
+}
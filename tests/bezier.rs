@@ -0,0 +1,56 @@
+use rust_polynomial::{Bezier, Polynomial};
+
+#[test]
+fn eval_matches_polynomial() {
+    let curve: Bezier<f64> = Bezier::new(vec![1.0, -2.0, 3.5, 0.0, 4.0]);
+    let poly = curve.to_polynomial();
+
+    assert_eq!(curve.degree(), 4);
+    for t in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+        assert!((curve.eval(t) - poly.eval(t)).abs() < 1e-12);
+    }
+
+    assert_eq!(curve.eval(0.0), 1.0);
+    assert_eq!(curve.eval(1.0), 4.0);
+}
+
+#[test]
+fn roundtrip() {
+    let poly = Polynomial::<f64>::try_from("2x^3 - 3x^2 + 0.5x - 1").unwrap();
+
+    for degree in 3..6 {
+        let curve = Bezier::from_polynomial(&poly, degree).unwrap();
+        assert_eq!(curve.degree(), degree);
+
+        let back = curve.to_polynomial();
+        for (a, b) in back
+            .to_coeffs()
+            .unwrap()
+            .iter()
+            .zip(poly.to_coeffs().unwrap())
+        {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    assert!(Bezier::from_polynomial(&poly, 2).is_err());
+}
+
+#[test]
+fn integer_control_points() {
+    let curve: Bezier<i64> = Bezier::new(vec![0, 3, -3, 6]);
+    let poly = curve.to_polynomial();
+
+    assert_eq!(poly, Polynomial::try_from("24x^3 - 27x^2 + 9x").unwrap());
+    assert_eq!(curve.eval(1), 6);
+    assert_eq!(Bezier::from_polynomial(&poly, 3), Ok(curve));
+}
+
+#[test]
+fn degenerate() {
+    assert_eq!(Bezier::<i32>::new(vec![]).eval(1), 0);
+    assert_eq!(
+        Bezier::new(vec![7]).to_polynomial(),
+        Polynomial::try_from("7").unwrap()
+    );
+}
@@ -0,0 +1,61 @@
+use rust_polynomial::{Bivariate, Polynomial};
+
+fn poly(expr: &str) -> Polynomial<i64> {
+    Polynomial::try_from(expr).unwrap()
+}
+
+#[test]
+fn arithmetic_matches_evaluation() {
+    // x^2 + xy - 1 and 2x - y^2
+    let p = Bivariate::new(vec![poly("-1"), poly("x"), poly("1")]);
+    let q = Bivariate::new(vec![-poly("x^2"), poly("2")]);
+
+    for x in -3..=3 {
+        for y in -3..=3 {
+            assert_eq!(
+                (p.clone() + q.clone()).eval(x, y),
+                p.eval(x, y) + q.eval(x, y)
+            );
+            assert_eq!(
+                (p.clone() * q.clone()).eval(x, y),
+                p.eval(x, y) * q.eval(x, y)
+            );
+            assert_eq!((-p.clone()).eval(x, y), -p.eval(x, y));
+            assert_eq!(p.eval_x(x).eval(y), p.eval(x, y));
+            assert_eq!(p.eval_y(y).eval(x), p.eval(x, y));
+        }
+    }
+
+    assert_eq!(p.clone() + -p.clone(), Bivariate::new(Vec::new()));
+    assert_eq!((p.clone() + -p).degree_x(), None);
+    assert_eq!((q.clone() * q).degree_x(), Some(2));
+}
+
+#[test]
+fn resultant_eliminates_x() {
+    // x^2 + y^2 - 1 and y - x
+    let circle = Bivariate::new(vec![poly("x^2 - 1"), Polynomial::zero(), poly("1")]);
+    let line = Bivariate::new(vec![poly("x"), poly("-1")]);
+    let resultant = circle.resultant_x(&line).unwrap();
+
+    assert_eq!(resultant, poly("2x^2 - 1"));
+    assert_eq!(format!("{resultant}"), "2y^2 - 1");
+
+    // Constant coefficients agree with the univariate resultant
+    let p = poly("x^3 - 2x + 5");
+    let q = poly("3x^2 + x - 4");
+    let [bp, bq] = [&p, &q].map(|p| {
+        let coeffs = p.to_coeffs().unwrap();
+        Bivariate::new(coeffs.into_iter().map(Polynomial::constant).collect())
+    });
+    assert_eq!(bp.eval_y(0), p);
+
+    assert_eq!(
+        bp.resultant_x(&bq).unwrap(),
+        Polynomial::constant(p.resultant(&q).unwrap())
+    );
+    assert!(bp
+        .resultant_x(&Bivariate::new(Vec::new()))
+        .unwrap()
+        .is_zero());
+}
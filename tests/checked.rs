@@ -0,0 +1,60 @@
+use std::cmp::Ordering;
+
+use rust_polynomial::Polynomial;
+
+#[test]
+fn checked_evaluation_detects_overflow() {
+    let poly: Polynomial<i64> = Polynomial::try_from("3x^4 - x^2 + 5").unwrap();
+    for x in [-7, 0, 2, 1000] {
+        assert_eq!(poly.eval_checked(x), Some(poly.eval(x)));
+        assert_eq!(poly.eval_i128(x as i128), Some(poly.eval(x) as i128));
+    }
+    assert_eq!(poly.eval_checked(1 << 20), None);
+    assert_eq!(poly.eval_i128(1 << 20), Some(3 * (1 << 80) - (1 << 40) + 5));
+    assert_eq!(poly.eval_i128(1 << 40), None);
+
+    let float: Polynomial<f64> = Polynomial::try_from("0.5x + 1").unwrap();
+    assert_eq!(float.eval_i128(2), None);
+    let laurent: Polynomial<i32> = Polynomial::try_from("x + x^-1").unwrap();
+    assert_eq!(laurent.eval_i128(1), None);
+    assert_eq!(laurent.eval_checked(1), None);
+    assert_eq!(Polynomial::<i32>::zero().eval_checked(9), Some(0));
+}
+
+#[test]
+fn sign_at_is_exact() {
+    // x^4 (x - 2^62) - 1, the values around the root overflow `i128`
+    let poly: Polynomial<i64> = Polynomial::try_from("x^5 - 4611686018427387904x^4 - 1").unwrap();
+    let x = i64::MAX;
+    assert_eq!(poly.eval_checked(x), None);
+    assert_eq!(poly.eval_i128(x as i128), None);
+    assert_eq!(poly.sign_at(x), Some(Ordering::Greater));
+    assert_eq!(poly.sign_at((1 << 62) - 1), Some(Ordering::Less));
+    assert_eq!(poly.sign_at(1 << 62), Some(Ordering::Less));
+    assert_eq!(poly.sign_at(0), Some(Ordering::Less));
+    assert_eq!(poly.sign_at(-x), Some(Ordering::Less));
+
+    let laurent: Polynomial<i32> = Polynomial::try_from("x^-1 - x").unwrap();
+    assert_eq!(laurent.sign_at(-2), Some(Ordering::Greater));
+    assert_eq!(laurent.sign_at(-1), Some(Ordering::Equal));
+    assert_eq!(laurent.sign_at(2), Some(Ordering::Less));
+    assert_eq!(laurent.sign_at(0), None);
+
+    let float: Polynomial<f64> = Polynomial::try_from("x^2 - 2").unwrap();
+    assert_eq!(float.sign_at(1.5), Some(Ordering::Greater));
+    assert_eq!(float.sign_at(1.0), Some(Ordering::Less));
+    assert_eq!(float.sign_at(f64::NAN), None);
+}
+
+#[test]
+fn rational_roots_skip_overflowing_divisors() {
+    let poly: Polynomial<i64> = Polynomial::try_from("4611686018427387904x^4 + x - 2").unwrap();
+    assert_eq!(poly.roots(), None);
+
+    let poly: Polynomial<i64> =
+        Polynomial::try_from("x^4 - 3074457345618258602x^2 + x + 2").unwrap();
+    let roots = poly.roots().unwrap_or_default();
+    assert!(roots
+        .iter()
+        .all(|r| poly.sign_at(*r) == Some(Ordering::Equal)));
+}
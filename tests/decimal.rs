@@ -0,0 +1,49 @@
+#![cfg(feature = "decimal")]
+
+use rust_polynomial::{Decimal, Polynomial};
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn decimal_strings_are_exact() {
+    let p: Polynomial<Decimal> = Polynomial::try_from("0.1x^2 + 0.2x - 0.3").unwrap();
+    let q: Polynomial<Decimal> = Polynomial::try_from("0.3x - 0.1").unwrap();
+
+    assert_eq!(format!("{}", p.clone() + q.clone()), "0.1x^2 + 0.5x - 0.4");
+    assert_eq!(
+        format!("{}", p.clone() * q),
+        "0.03x^3 + 0.05x^2 - 0.11x + 0.03"
+    );
+    assert_eq!(p.eval(dec("0.1")), dec("-0.279"));
+    assert_eq!(p.eval(dec("1")), Decimal::default());
+    assert_eq!(format!("{}", p.derivative()), "0.2x + 0.2");
+
+    // The same computation with floats isn't exact
+    let float: Polynomial<f64> = Polynomial::try_from("0.1x^2 + 0.2x - 0.3").unwrap();
+    assert_ne!(float.eval(1.0), 0.0);
+}
+
+#[test]
+fn parsing_and_conversions() {
+    assert_eq!(dec("1.5e-3"), dec("0.0015"));
+    assert_eq!(
+        format!(
+            "{}",
+            Polynomial::<Decimal>::try_from("2.50x - 1e2").unwrap()
+        ),
+        "2.50x - 100"
+    );
+    assert!(Polynomial::<Decimal>::try_from("0.1.2x").is_err());
+
+    assert_eq!(<Decimal as num::NumCast>::from(2.5f64), Some(dec("2.5")));
+    assert_eq!(
+        <Decimal as num::NumCast>::from(-7i32),
+        Some(Decimal::from(-7))
+    );
+    assert_eq!(<Decimal as num::NumCast>::from(f64::NAN), None);
+
+    let raw = rust_decimal::Decimal::new(314, 2);
+    assert_eq!(rust_decimal::Decimal::from(Decimal::from(raw)), raw);
+}
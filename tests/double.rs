@@ -0,0 +1,76 @@
+use num::{NumCast, Signed, ToPrimitive, Zero};
+use rust_polynomial::{DoubleDouble, Polynomial};
+
+fn dd(s: &str) -> DoubleDouble {
+    s.parse().unwrap()
+}
+
+fn f(value: f64) -> DoubleDouble {
+    value.into()
+}
+
+#[test]
+fn arithmetic_keeps_twice_the_precision() {
+    let third = f(1.0) / f(3.0);
+    assert_eq!(format!("{third}"), "0.33333333333333333333333333333333");
+    assert!((third * f(3.0) - f(1.0)).abs().hi() < 1e-31);
+
+    // 1 + 2^-80 is lost in f64
+    let tiny = f(2f64.powi(-80));
+    let sum = f(1.0) + tiny;
+    assert_eq!(sum - f(1.0), tiny);
+    assert!(sum > f(1.0));
+
+    assert_eq!(dd("7.5") % dd("2"), dd("1.5"));
+    assert_eq!(dd("-7.5") % dd("2"), dd("-1.5"));
+    assert_eq!(dd("-2.5").abs(), dd("2.5"));
+    assert_eq!(dd("-2.5").trunc(), dd("-2"));
+    assert_eq!(dd("-2.5").floor(), dd("-3"));
+    assert!((f(1.0) / DoubleDouble::zero()).hi().is_infinite());
+}
+
+#[test]
+fn parsing_display_and_casts() {
+    for text in [
+        "0.1",
+        "-2.5",
+        "123456789.123456789123456789",
+        "1e-21",
+        "0.00001234",
+    ] {
+        assert_eq!(format!("{}", dd(text)), text);
+    }
+    assert_eq!(format!("{:.3}", dd("2.71828")), "2.718");
+    assert_eq!(format!("{:.3}", dd("0.00001")), "0.000");
+    assert_eq!(format!("{}", dd("3e40")), "3e40");
+    assert!("1.2.3".parse::<DoubleDouble>().is_err());
+    assert!("".parse::<DoubleDouble>().is_err());
+
+    assert_eq!(dd("-12.9").to_i64(), Some(-12));
+    assert_eq!(
+        <DoubleDouble as NumCast>::from(i64::MAX).unwrap().to_i64(),
+        Some(i64::MAX)
+    );
+    assert_eq!(<DoubleDouble as NumCast>::from(0.5f32), Some(dd("0.5")));
+}
+
+#[test]
+fn polynomials_over_double_double() {
+    let poly: Polynomial<DoubleDouble> = Polynomial::try_from("0.1x^2 - 3x + 1.5").unwrap();
+    assert_eq!(
+        format!("{}", poly.clone() * poly.clone()),
+        "0.01x^4 - 0.6x^3 + 9.3x^2 - 9x + 2.25"
+    );
+    assert_eq!(format!("{}", poly.derivative()), "0.2x - 3");
+
+    // Roots found in f64 refined to double-double precision
+    let float: Polynomial<f64> = Polynomial::try_from("x^3 - 2x - 5").unwrap();
+    let root = float.roots_in_interval(2.0, 3.0).unwrap()[0];
+    let poly: Polynomial<DoubleDouble> = Polynomial::try_from("x^3 - 2x - 5").unwrap();
+    let refined = poly.newton_refine(f(root), 10);
+    assert_eq!(
+        format!("{:.30}", refined),
+        "2.094551481542326591482386540579"
+    );
+    assert!(poly.eval(refined).abs().hi() < 1e-30);
+}
@@ -0,0 +1,33 @@
+use rust_polynomial::{Dual, Polynomial};
+
+#[test]
+fn matches_symbolic_derivative() {
+    let poly = Polynomial::<i64>::try_from("3x^5 - x^4 + 7x^2 - 2x + 9").unwrap();
+    let derivative = poly.derivative();
+
+    for x in -4..=4 {
+        let result = poly.eval_as(Dual::variable(x)).unwrap();
+
+        assert_eq!(result.value(), poly.eval(x));
+        assert_eq!(result.derivative(), derivative.eval(x));
+    }
+}
+
+#[test]
+fn arithmetic_rules() {
+    let x = Dual::variable(3.0);
+    let c = Dual::constant(2.0);
+
+    assert_eq!(x + c, Dual::new(5.0, 1.0));
+    assert_eq!(x - c, Dual::new(1.0, 1.0));
+    assert_eq!(x * x, Dual::new(9.0, 6.0));
+    assert_eq!(-(x * c), Dual::new(-6.0, -2.0));
+    assert_eq!(Dual::from(4.0), Dual::new(4.0, 0.0));
+
+    // Chain through composition, d/dx p(q(x)) = p'(q(x)) q'(x)
+    let p = Polynomial::<f64>::try_from("x^2 + 1").unwrap();
+    let q = Polynomial::<f64>::try_from("2x - 1").unwrap();
+    let inner = q.eval_as(Dual::variable(3.0)).unwrap();
+    let outer = p.eval_as(inner).unwrap();
+    assert_eq!((outer.value(), outer.derivative()), (26.0, 20.0));
+}
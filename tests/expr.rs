@@ -0,0 +1,53 @@
+use rust_polynomial::{PolyExpr, Polynomial};
+
+fn poly(expr: &str) -> Polynomial<i64> {
+    Polynomial::try_from(expr).unwrap()
+}
+
+#[test]
+fn lazy_matches_expanded() {
+    let p = poly("x^4 - 3x^2 + x - 7");
+    let q = poly("2x^3 + x");
+    let r = poly("-x^5 + 4");
+    let exprs = [
+        &p * &q + &r,
+        -(&p * &q) + &p * &r,
+        (&p + &q) * (&q + -&r) * &r,
+        PolyExpr::from(&p),
+    ];
+
+    for expr in &exprs {
+        let expanded = expr.expand();
+        assert_eq!(expr.degree(), Some(expanded.max_exp().get_exp()));
+        assert!(expr.probably_equal(&expanded));
+        assert!(!expr.probably_equal(&(expanded.clone() + poly("1"))));
+        for x in -3..=3 {
+            assert_eq!(expr.eval(x), expanded.eval(x));
+        }
+    }
+}
+
+#[test]
+fn degree_with_cancellation() {
+    let p = poly("x^3 + x");
+    let q = poly("x^3 - 5");
+    let r = poly("x - 1");
+
+    // The leading terms cancel, the sum is expanded to find the degree
+    assert_eq!((&p + -&q).degree(), Some(1));
+    assert_eq!((&p * &r + -(&q * &r)).degree(), Some(2));
+    assert_eq!((&p + -&p).degree(), None);
+    assert!((&p + -&p).expand().is_zero());
+
+    let zero = Polynomial::zero();
+    assert_eq!((&p * &zero).degree(), None);
+    assert_eq!((&zero + &r).degree(), Some(1));
+
+    // Negative exponents
+    let laurent = poly("x^-2 + 3");
+    assert_eq!((&laurent * &laurent).degree(), Some(0));
+    assert_eq!(
+        format!("{}", (&laurent * &r).expand()),
+        "3x - 3 + x^-1 - x^-2"
+    );
+}
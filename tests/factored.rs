@@ -0,0 +1,79 @@
+use rust_polynomial::{Polynomial, SyntaxErrorKind};
+
+#[test]
+fn factored_string_roundtrip() {
+    let cases = [
+        ("x^2 - 4x + 4", "(x-2)^2"),
+        ("-x^3 + 3x^2 - 4", "-(x+1)(x-2)^2"),
+        ("3x^3 + 3x", "(3x^2+3)(x)"),
+        ("x^2 + 1", "(x^2+1)"),
+        ("x - 7", "(x-7)"),
+        ("-5", "-5"),
+        ("1", "1"),
+        ("0", "0"),
+    ];
+    for (expanded, factored) in cases {
+        let poly: Polynomial<i64> = Polynomial::try_from(expanded).unwrap();
+        assert_eq!(poly.to_factored_string(), factored, "{poly}");
+        assert_eq!(Polynomial::parse_factored(factored), Ok(poly));
+    }
+
+    let poly: Polynomial<f64> = Polynomial::try_from("2x^2 - 3x + 1").unwrap();
+    let key = poly.to_factored_string();
+    assert_eq!(Polynomial::parse_factored(&key), Ok(poly));
+
+    let poly: Polynomial<i32> = Polynomial::try_from("x^2 - 1").unwrap().with_var('t');
+    assert_eq!(poly.to_factored_string(), "(x+1)(x-1)");
+}
+
+#[test]
+fn parse_factored_forms() {
+    let parse = |expr: &str| Polynomial::<i32>::parse_factored(expr).map(|p| format!("{p}"));
+
+    assert_eq!(
+        parse(" 2 ( x + 1 ) ^ 2 ( x ) "),
+        Ok("2x^3 + 4x^2 + 2x".to_string())
+    );
+    assert_eq!(parse("+(x - 1)^0"), Ok("1".to_string()));
+    assert_eq!(parse("x^2 - 1"), Ok("x^2 - 1".to_string()));
+    assert_eq!(parse("3x(x + 1)"), Ok("3x^2 + 3x".to_string()));
+
+    let error = |expr: &str| Polynomial::<i32>::parse_factored(expr).unwrap_err();
+    let e = error("2(x + 1");
+    assert_eq!((e.column, e.message), (8, "Missing )"));
+    let e = error("(x + 1) + 2");
+    assert_eq!(
+        (e.column, e.token.as_str(), e.message),
+        (9, "+", "Expected (")
+    );
+    let e = error("((x))");
+    assert_eq!((e.column, e.message), (2, "Unexpected symbol"));
+    let e = error("(x + 1)^");
+    assert_eq!((e.column, e.message), (8, "Missing exponent"));
+    let e = error("(x)()");
+    assert_eq!((e.column, e.message), (5, "Empty expression"));
+    let e = error("(x + é)");
+    assert_eq!((e.column, e.offset, e.token.as_str()), (6, 5, "é"));
+    let e = error("2y(x)");
+    assert_eq!((e.column, e.token.as_str()), (1, "2y"));
+
+    // Powers whose product leaves `i32` or expands to too many terms
+    assert_eq!(parse("(x^2)^1073741823"), Ok("x^2147483646".to_string()));
+    let e = error("(x^2)^1073741824");
+    assert_eq!(
+        (e.column, e.token.as_str(), e.message),
+        (7, "1", "Power too large")
+    );
+    let e = error("x^2147483647 (x)^1");
+    assert_eq!((e.column, e.message), (18, "Power too large"));
+    let e = error("x^2147483647 (x)");
+    assert_eq!((e.column, e.message), (14, "Power too large"));
+    let e = error("(x^-1)^4294967296");
+    assert_eq!(e.message, "Not valid exponent");
+    let e = error("(x^-2)^1073741825");
+    assert_eq!(e.message, "Power too large");
+    let e = error("(x + 1)^4097");
+    assert_eq!((e.column, e.message), (9, "Power too large"));
+    assert_eq!(e.kind, SyntaxErrorKind::PowerTooLarge);
+    assert_eq!(parse("(-x)^5001"), Ok("-x^5001".to_string()));
+}
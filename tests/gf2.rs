@@ -0,0 +1,98 @@
+use rust_polynomial::{
+    gf2::{self, Gf2Poly},
+    Polynomial,
+};
+
+#[test]
+fn arithmetic() {
+    let a = Gf2Poly::from_u64(0b1011);
+    let b = Gf2Poly::from_u64(0b110);
+
+    assert_eq!(a.degree(), Some(3));
+    assert_eq!(Gf2Poly::zero().degree(), None);
+    assert_eq!(a.to_coeffs(), vec![true, true, false, true]);
+    assert_eq!(Gf2Poly::from_coeffs(&[true, true, false, true, false]), a);
+
+    assert_eq!(a.clone() + a.clone(), Gf2Poly::zero());
+    assert_eq!(a.clone() + b.clone(), Gf2Poly::from_u64(0b1101));
+    // (x^3 + x + 1)(x^2 + x) = x^5 + x^4 + x^3 + x
+    assert_eq!(a.clone() * b.clone(), Gf2Poly::from_u64(0b111010));
+    assert_eq!((a.clone() * b.clone()).rem(&a), Ok(Gf2Poly::zero()));
+    assert!(a.rem(&Gf2Poly::zero()).is_err());
+
+    // Carries across words
+    let high = Gf2Poly::from_coeffs(&[true; 100]);
+    let square = high.clone() * high.clone();
+    assert_eq!(square.degree(), Some(198));
+    assert_eq!(square.to_u64(), None);
+    assert!((0..=198).all(|i| square.coeff(i) == (i % 2 == 0)));
+    assert_eq!(square.rem(&high), Ok(Gf2Poly::zero()));
+
+    assert_eq!(format!("{}", Gf2Poly::zero()), "0");
+    assert_eq!(format!("{}", Gf2Poly::from_u64(0x107)), "x^8 + x^2 + x + 1");
+}
+
+#[test]
+fn polynomial_conversion() {
+    let poly = Polynomial::<i32>::try_from("3x^4 - 2x^2 - x + 5").unwrap();
+    let reduced = Gf2Poly::try_from(&poly).unwrap();
+
+    assert_eq!(reduced, Gf2Poly::from_u64(0b10011));
+    assert_eq!(
+        reduced.to_polynomial::<i32>(),
+        Polynomial::try_from("x^4 + x + 1").unwrap()
+    );
+    assert!(Gf2Poly::try_from(&Polynomial::<i32>::try_from("x^-1").unwrap()).is_err());
+}
+
+#[test]
+fn crc() {
+    let check = b"123456789";
+
+    // CRC-8/SMBUS, CRC-16/XMODEM and CRC-32/CKSUM without its final xor
+    let cases: [(u64, u64); 3] = [
+        (0x107, 0xF4),
+        (0x1_1021, 0x31C3),
+        (0x1_04C1_1DB7, 0x765E_7680 ^ 0xFFFF_FFFF),
+    ];
+    for (generator, expected) in cases {
+        let crc = gf2::crc(check, &Gf2Poly::from_u64(generator)).unwrap();
+        assert_eq!(crc.to_u64(), Some(expected));
+    }
+
+    // Appending the CRC leaves a multiple of the generator
+    let generator = Gf2Poly::from_u64(0x1_1021);
+    let crc = gf2::crc(b"hello", &generator).unwrap().to_u64().unwrap() as u16;
+    let mut message = b"hello".to_vec();
+    message.extend(crc.to_be_bytes());
+    assert_eq!(
+        Gf2Poly::from_bytes(&message).rem(&generator),
+        Ok(Gf2Poly::zero())
+    );
+    assert_eq!(gf2::crc(&message, &generator), Ok(Gf2Poly::zero()));
+
+    assert!(gf2::crc(check, &Gf2Poly::from_u64(1)).is_err());
+}
+
+#[test]
+fn lfsr() {
+    // Primitive `x^4 + x + 1` has maximal period 15
+    let feedback = Gf2Poly::from_u64(0b10011);
+    let bits = gf2::lfsr(&feedback, &[true, false, false, false], 45).unwrap();
+
+    assert_eq!(bits.len(), 45);
+    assert_eq!(bits[..15], bits[15..30]);
+    assert!((1..15).all(|p| bits[..15] != bits[p..p + 15]));
+    assert_eq!(bits[..15].iter().filter(|b| **b).count(), 8);
+
+    // A zero seed stays at zero
+    let zeros = gf2::lfsr(&feedback, &[false; 4], 10).unwrap();
+    assert!(zeros.iter().all(|b| !b));
+
+    assert_eq!(
+        gf2::lfsr(&feedback, &[true, true, false, true], 2).unwrap(),
+        vec![true, true]
+    );
+    assert!(gf2::lfsr(&feedback, &[true], 10).is_err());
+    assert!(gf2::lfsr(&Gf2Poly::from_u64(1), &[], 10).is_err());
+}
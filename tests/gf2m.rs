@@ -0,0 +1,143 @@
+use rust_polynomial::{gf2::Gf2Poly, gf2m::Gf2m};
+
+fn qr_field() -> Gf2m {
+    Gf2m::new(&Gf2Poly::from_u64(0x11D)).unwrap()
+}
+
+#[test]
+fn field_axioms() {
+    let field = Gf2m::new(&Gf2Poly::from_u64(0b1_0011)).unwrap();
+    assert_eq!((field.degree(), field.order()), (4, 16));
+
+    for a in 0..16u16 {
+        assert_eq!(field.mul(a, 1), a);
+        assert_eq!(field.add(a, a), 0);
+        assert_eq!(field.pow(a, 15), u16::from(a != 0));
+        if a != 0 {
+            assert_eq!(field.mul(a, field.inv(a).unwrap()), 1);
+        }
+
+        for b in 0..16u16 {
+            assert_eq!(field.mul(a, b), field.mul(b, a));
+            assert!(field.mul(a, b) < 16);
+            for c in 0..16u16 {
+                let lhs = field.mul(a, field.add(b, c));
+                assert_eq!(lhs, field.add(field.mul(a, b), field.mul(a, c)));
+            }
+        }
+    }
+
+    // Multiplication of the field agrees with the reduction of the Gf2Poly product
+    let modulus = Gf2Poly::from_u64(0x11D);
+    let field = qr_field();
+    for (a, b) in [(3u16, 7u16), (200, 17), (255, 255), (128, 2)] {
+        let product = (Gf2Poly::from_u64(a as u64) * Gf2Poly::from_u64(b as u64)).rem(&modulus);
+        assert_eq!(product.unwrap().to_u64(), Some(field.mul(a, b) as u64));
+    }
+    assert_eq!(field.alpha_pow(8), 0x1D);
+    assert_eq!(field.alpha_pow(255), 1);
+    assert_eq!(field.div(5, 0), None);
+    assert_eq!(field.pow(0, 0), 1);
+
+    assert!(Gf2m::new(&Gf2Poly::from_u64(1)).is_err());
+    assert!(Gf2m::new(&Gf2Poly::from_u64(0b1_0101)).is_err());
+    assert!(Gf2m::new(&Gf2Poly::from_coeffs(&[true; 18])).is_err());
+}
+
+#[test]
+fn polynomials() {
+    let field = qr_field();
+    let a = [1u16, 2, 3];
+    let b = [4u16, 5];
+
+    let product = field.poly_mul(&a, &b);
+    assert_eq!(product.len(), 4);
+    for x in 0..50u16 {
+        let expected = field.mul(field.eval(&a, x), field.eval(&b, x));
+        assert_eq!(field.eval(&product, x), expected);
+    }
+    assert_eq!(field.poly_rem(&product, &b), Ok(vec![0]));
+    assert_eq!(field.poly_rem(&[7], &a), Ok(vec![7, 0]));
+    assert!(field.poly_rem(&a, &[1, 0]).is_err());
+
+    let points: Vec<(u16, u16)> = (10..14u16).map(|x| (x, field.eval(&product, x))).collect();
+    assert_eq!(field.interpolate(&points), Ok(product));
+    assert!(field.interpolate(&[(1, 2), (1, 3)]).is_err());
+    assert!(field.interpolate(&[(1, 2), (256, 3)]).is_err());
+    assert!(field.interpolate(&[(1, 60000)]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "16 isn't an element of GF(2^4)")]
+fn elements_out_of_field() {
+    let field = Gf2m::new(&Gf2Poly::from_u64(0b1_0011)).unwrap();
+    field.mul(3, 16);
+}
+
+#[test]
+fn reed_solomon() {
+    let field = qr_field();
+    let generator = field.rs_generator(10);
+
+    assert_eq!(generator.len(), 11);
+    for i in 0..10 {
+        assert_eq!(field.eval(&generator, field.alpha_pow(i)), 0);
+    }
+
+    let message: Vec<u16> = b"hello world".iter().map(|b| *b as u16).collect();
+    let codeword = field.rs_encode(&message, 10).unwrap();
+    assert_eq!(codeword.len(), 21);
+    assert_eq!(codeword[10..], message[..]);
+    assert_eq!(field.syndromes(&codeword, 10), vec![0; 10]);
+    assert_eq!(field.poly_rem(&codeword, &generator), Ok(vec![0; 10]));
+
+    let mut corrupted = codeword.clone();
+    corrupted[15] ^= 0x42;
+    assert!(field.syndromes(&corrupted, 10).iter().any(|s| *s != 0));
+
+    assert!(field.rs_encode(&[0; 250], 10).is_err());
+    assert!(field.rs_encode(&[256], 4).is_err());
+}
+
+#[test]
+fn shamir() {
+    let field = qr_field();
+    let mut state = 0x2545_F491u32;
+    let mut rng = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u16
+    };
+
+    for secret in [0u16, 1, 42, 255] {
+        let shares = field.share_secret(secret, 3, 6, &mut rng).unwrap();
+        assert_eq!(shares.len(), 6);
+        assert!(shares
+            .iter()
+            .enumerate()
+            .all(|(i, s)| s.0 as usize == i + 1));
+
+        for subset in [&shares[..3], &shares[3..], &shares[1..5], &shares[..]] {
+            assert_eq!(field.reconstruct(subset), Ok(secret));
+        }
+        let scattered = [shares[5], shares[0], shares[3]];
+        assert_eq!(field.reconstruct(&scattered), Ok(secret));
+    }
+
+    // Threshold one gives the secret in every share
+    let shares = field.share_secret(9, 1, 3, &mut rng).unwrap();
+    assert!(shares.iter().all(|s| s.1 == 9));
+
+    assert!(field.share_secret(1, 0, 3, &mut rng).is_err());
+    assert!(field.share_secret(1, 4, 3, &mut rng).is_err());
+    assert!(field.share_secret(1, 2, 256, &mut rng).is_err());
+    assert!(field.share_secret(256, 2, 3, &mut rng).is_err());
+    assert!(field.reconstruct(&[]).is_err());
+    assert!(field.reconstruct(&[(1, 2), (1, 5)]).is_err());
+    assert!(field.reconstruct(&[(1, 2), (256, 5)]).is_err());
+    assert!(field.reconstruct(&[(1, 2), (2, 60000)]).is_err());
+    // A share at zero would hand back its own `y` as the secret
+    assert!(field.reconstruct(&[(0, 7)]).is_err());
+    assert!(field.reconstruct(&[(0, 7), (1, 2)]).is_err());
+}
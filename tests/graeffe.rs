@@ -0,0 +1,53 @@
+use rust_polynomial::Polynomial;
+
+#[test]
+fn graeffe_step_squares_the_roots() {
+    let poly: Polynomial<i64> = Polynomial::try_from("x^3 - 2x^2 - 5x + 6").unwrap();
+    let step = poly.graeffe_step().unwrap();
+    assert_eq!(step.roots(), Some(vec![1, 4, 9]));
+
+    // Complex roots `±2i` become the double root `-4`
+    let poly: Polynomial<i64> = Polynomial::try_from("x^2 + 4").unwrap();
+    assert_eq!(format!("{}", poly.graeffe_step().unwrap()), "x^2 + 8x + 16");
+
+    let poly: Polynomial<i64> = Polynomial::try_from("x^2 - 3x").unwrap();
+    assert_eq!(format!("{}", poly.graeffe_step().unwrap()), "x^2 - 9x");
+    assert_eq!(
+        Polynomial::<i64>::zero().graeffe_step(),
+        Ok(Polynomial::zero())
+    );
+    assert!(Polynomial::<i64>::try_from("x^-1")
+        .unwrap()
+        .graeffe_step()
+        .is_err());
+}
+
+#[test]
+fn graeffe_magnitudes_match_roots() {
+    let poly: Polynomial<f64> = Polynomial::try_from("x^4 + 1.5x^3 - 11.5x^2 - 6x").unwrap();
+    let magnitudes = poly.graeffe_root_magnitudes(8).unwrap();
+    let expected = [0.0, 0.5, 3.0, 4.0];
+    assert_eq!(magnitudes.len(), expected.len());
+    for (estimate, root) in magnitudes.iter().zip(expected) {
+        assert!((estimate - root).abs() < 1e-9, "{estimate} != {root}");
+    }
+
+    // A conjugate pair of magnitude 2 and a real root 5, the pair only gets the product right
+    let poly: Polynomial<f64> = Polynomial::try_from("x^3 - 5x^2 + 4x - 20").unwrap();
+    let magnitudes = poly.graeffe_root_magnitudes(5).unwrap();
+    assert!((magnitudes[2] - 5.0).abs() < 1e-6);
+    assert!((magnitudes[0] * magnitudes[1] - 4.0).abs() < 1e-6);
+
+    assert_eq!(
+        Polynomial::<i32>::try_from("7")
+            .unwrap()
+            .graeffe_root_magnitudes(3),
+        None
+    );
+    assert_eq!(
+        Polynomial::<i32>::try_from("x - 1")
+            .unwrap()
+            .graeffe_root_magnitudes(0),
+        Some(vec![1.0])
+    );
+}
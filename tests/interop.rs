@@ -0,0 +1,23 @@
+#![cfg(feature = "polynomial")]
+
+use rust_polynomial::Polynomial;
+
+#[test]
+fn polynomial_crate_conversion() {
+    let poly = Polynomial::<i64>::try_from("-6x^4 + 20x - 8").unwrap();
+    let other = polynomial::Polynomial::try_from(&poly).unwrap();
+
+    assert_eq!(other.data(), &[-8, 20, 0, 0, -6]);
+    assert_eq!(other.eval(3), poly.eval(3));
+    assert_eq!(Polynomial::from(other.clone()), poly);
+    assert_eq!(polynomial::Polynomial::try_from(poly), Ok(other));
+
+    // Trailing zeros are dropped by both crates
+    let zero = polynomial::Polynomial::new(vec![0.0, 0.0]);
+    assert!(Polynomial::from(zero).is_zero());
+    let zero = polynomial::Polynomial::try_from(Polynomial::<f64>::zero()).unwrap();
+    assert!(zero.data().is_empty());
+
+    let laurent = Polynomial::<f64>::try_from("x + x^-2").unwrap();
+    assert!(polynomial::Polynomial::try_from(&laurent).is_err());
+}
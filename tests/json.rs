@@ -0,0 +1,56 @@
+#![cfg(feature = "json")]
+
+use rust_polynomial::Polynomial;
+
+#[test]
+fn json_roundtrip() {
+    let poly: Polynomial<i64> = Polynomial::builder()
+        .term(i64::MAX, 40)
+        .term(-7, 3)
+        .term(1, -2)
+        .var('s')
+        .build();
+    let json = poly.to_json().unwrap();
+    let back: Polynomial<i64> = Polynomial::from_json(&json).unwrap();
+
+    assert_eq!(back, poly);
+    assert_eq!(back.get_var(), 's');
+
+    let poly = Polynomial::<f64>::try_from("0.1x^2 - 3").unwrap();
+    assert_eq!(Polynomial::from_json(&poly.to_json().unwrap()), Ok(poly));
+
+    let poly = Polynomial::<f32>::try_from("0.1x^2 - 3").unwrap();
+    assert_eq!(Polynomial::from_json(&poly.to_json().unwrap()), Ok(poly));
+}
+
+#[test]
+fn json_independent_of_type() {
+    let json = Polynomial::<i32>::try_from("4x^2 - 2")
+        .unwrap()
+        .to_json()
+        .unwrap();
+
+    assert_eq!(
+        Polynomial::<f32>::from_json(&json),
+        Ok(Polynomial::try_from("4x^2 - 2").unwrap())
+    );
+    assert!(Polynomial::<i32>::from_json(
+        r#"{"version":1,"polynomial":{"variable":"x","terms":[{"coeff":0.5,"exp":1}]}}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn json_invalid() {
+    #[rustfmt::skip]
+    let invalid = [
+        "{}",
+        r#"{"version":2,"polynomial":{"variable":"x","terms":[]}}"#,
+        r#"{"version":1,"polynomial":{"variable":"xy","terms":[]}}"#,
+        r#"{"version":1,"polynomial":{"variable":"x","terms":[{"coeff":"1","exp":1}]}}"#,
+    ];
+
+    for json in invalid {
+        assert!(Polynomial::<i32>::from_json(json).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "laws")]
+
+use rust_polynomial::{laws, Polynomial};
+
+#[test]
+fn builtin_types_satisfy_laws() {
+    assert_eq!(laws::check_samples(&laws::samples::<i32>()), Ok(()));
+    assert_eq!(laws::check_samples(&laws::samples::<i64>()), Ok(()));
+    assert_eq!(laws::check_samples(&laws::samples::<f64>()), Ok(()));
+}
+
+#[test]
+fn samples_cover_edge_cases() {
+    let samples = laws::samples::<i32>();
+
+    assert!(samples.iter().any(Polynomial::is_zero));
+    assert!(samples.iter().any(|p| p.is_constant() && !p.is_zero()));
+    assert!(samples.iter().any(|p| p.max_exp().get_value() < 0));
+}
+
+#[test]
+fn violations_are_named() {
+    // Rounding of floats breaks associativity
+    let big = Polynomial::<f64>::from_coeffs(&[1e16]);
+    let one = Polynomial::<f64>::from_coeffs(&[1.0]);
+
+    assert_eq!(
+        laws::additive_laws(&big, &one, &one),
+        Err("addition is associative")
+    );
+    assert_eq!(laws::division_laws(&big, &Polynomial::zero()), Ok(()));
+}
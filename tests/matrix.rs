@@ -0,0 +1,91 @@
+use rust_polynomial::{PolyMatrix, Polynomial};
+
+fn poly(s: &str) -> Polynomial<i64> {
+    Polynomial::try_from(s).unwrap()
+}
+
+#[test]
+fn construction() {
+    assert!(PolyMatrix::<i64>::new(vec![]).is_err());
+    assert!(PolyMatrix::new(vec![vec![poly("x")], vec![poly("1"), poly("2")]]).is_err());
+    assert!(PolyMatrix::characteristic(&[vec![1, 2]]).is_err());
+
+    let matrix = PolyMatrix::new(vec![vec![poly("x"), poly("1"), poly("x^2")]]).unwrap();
+    assert_eq!(matrix.size(), (1, 3));
+    assert_eq!(matrix.get(0, 2), Some(&poly("x^2")));
+    assert_eq!(matrix.get(1, 0), None);
+    assert_eq!(matrix.transpose().size(), (3, 1));
+    assert_eq!(matrix.transpose().get(2, 0), Some(&poly("x^2")));
+    assert_eq!(matrix.eval(2), vec![vec![2, 1, 4]]);
+}
+
+#[test]
+fn arithmetic() {
+    let a = PolyMatrix::new(vec![vec![poly("x"), poly("1")], vec![poly("0"), poly("x")]]).unwrap();
+    let b = PolyMatrix::new(vec![
+        vec![poly("1"), poly("-x")],
+        vec![poly("x"), poly("2")],
+    ])
+    .unwrap();
+    let column = PolyMatrix::new(vec![vec![poly("1")], vec![poly("x")]]).unwrap();
+
+    let sum = (a.clone() + b.clone()).unwrap();
+    assert_eq!(sum.get(0, 1), Some(&poly("-x + 1")));
+    assert!((a.clone() + column.clone()).is_err());
+
+    let product = (a.clone() * b.clone()).unwrap();
+    assert_eq!(product.get(0, 0), Some(&poly("2x")));
+    assert_eq!(product.get(0, 1), Some(&poly("-x^2 + 2")));
+    assert_eq!(product.get(1, 0), Some(&poly("x^2")));
+    assert_eq!(product.get(1, 1), Some(&poly("2x")));
+    assert_eq!((a.clone() * column.clone()).unwrap().size(), (2, 1));
+    assert!((column * a.clone()).is_err());
+
+    let identity = PolyMatrix::identity(2).unwrap();
+    assert_eq!((a.clone() * identity).unwrap(), a);
+    assert!(PolyMatrix::<i64>::identity(0).is_err());
+
+    // Evaluation commutes with the product
+    let [lhs, rhs, prod] = [&a, &b, &product].map(|m| m.eval(3));
+    let expected: Vec<Vec<i64>> = (0..2)
+        .map(|i| {
+            (0..2)
+                .map(|j| (0..2).map(|k| lhs[i][k] * rhs[k][j]).sum())
+                .collect()
+        })
+        .collect();
+    assert_eq!(prod, expected);
+}
+
+#[test]
+fn determinant() {
+    let a = PolyMatrix::new(vec![vec![poly("x"), poly("1")], vec![poly("0"), poly("x")]]).unwrap();
+    let b = PolyMatrix::new(vec![
+        vec![poly("1"), poly("-x")],
+        vec![poly("x"), poly("2")],
+    ])
+    .unwrap();
+
+    assert_eq!(a.determinant(), Ok(poly("x^2")));
+    assert_eq!(b.determinant(), Ok(poly("x^2 + 2")));
+    assert_eq!(
+        (a * b).unwrap().determinant(),
+        Ok(poly("x^2") * poly("x^2 + 2"))
+    );
+    assert_eq!(
+        PolyMatrix::<i64>::identity(4).unwrap().determinant(),
+        Ok(poly("1"))
+    );
+
+    let wide = PolyMatrix::new(vec![vec![poly("x"), poly("1")]]).unwrap();
+    assert!(wide.determinant().is_err());
+
+    // Characteristic polynomial of a companion matrix gives back the polynomial
+    let companion = vec![vec![0, 0, 6], vec![1, 0, -11], vec![0, 1, 6]];
+    let char_poly = PolyMatrix::characteristic(&companion)
+        .unwrap()
+        .determinant()
+        .unwrap();
+    assert_eq!(char_poly, poly("x^3 - 6x^2 + 11x - 6"));
+    assert_eq!(char_poly.roots(), Some(vec![1, 2, 3]));
+}
@@ -1,4 +1,4 @@
-use rust_polynomial::Monomial;
+use rust_polynomial::{Monomial, Polynomial, SumToPolynomial};
 
 #[test]
 fn check_test() {}
@@ -35,3 +35,87 @@ fn test_try_from_invalid_input() {
         assert!(Monomial::<i32>::try_from(input).is_err());
     }
 }
+
+#[test]
+fn test_operators() {
+    let a = Monomial::new(6, 2);
+    let b = Monomial::new(4, 2);
+    let c = Monomial::new(3, 1);
+
+    assert_eq!(a + b, Ok(Monomial::new(10, 2)));
+    assert_eq!(a - b, Ok(Monomial::new(2, 2)));
+    assert_eq!(a - a, Ok(Monomial::new(0, 0)));
+    assert!((a + c).is_err());
+    assert!((a - c).is_err());
+
+    assert_eq!(a * c, Monomial::new(18, 3));
+    assert_eq!(a / c, Monomial::new(2, 1));
+    assert_eq!(a * -2, Monomial::new(-12, 2));
+    assert_eq!(a / 3, Monomial::new(2, 2));
+
+    assert_eq!(c.pow(2), Monomial::new(9, 2));
+    assert_eq!(Monomial::new(0.5, -1).pow(3), Monomial::new(0.125, -3));
+}
+
+#[test]
+fn test_add_promote() {
+    let a = Monomial::new(2, 3);
+    let b = Monomial::new(-5, 0);
+
+    assert_eq!(format!("{}", a.add_promote(b)), "2x^3 - 5");
+    assert_eq!(format!("{}", a.add_promote(-a)), "0");
+    assert_eq!(
+        a.add_promote(b) + Polynomial::from(Monomial::new(1, 1)),
+        Polynomial::try_from("2x^3 + x - 5").unwrap()
+    );
+}
+
+#[test]
+fn test_calculus() {
+    #[rustfmt::skip]
+    let to_check = [
+        (Monomial::new(4.0, 3), 256.0, Monomial::new(12.0, 2), Some(Monomial::new(1.0, 4))),
+        (Monomial::new(-2.0, 0), -2.0, Monomial::default(), Some(Monomial::new(-2.0, 1))),
+        (Monomial::new(8.0, -2), 0.5, Monomial::new(-16.0, -3), Some(Monomial::new(-8.0, -1))),
+        (Monomial::new(1.0, -1), 0.25, Monomial::new(-1.0, -2), None),
+    ];
+
+    for (mono, at_4, derivative, integral) in to_check {
+        assert_eq!(mono.eval(4.0), at_4);
+        assert_eq!(mono.derivative(), derivative);
+        assert_eq!(mono.integral(), integral);
+    }
+}
+
+#[test]
+fn test_sum() {
+    let same = [
+        Monomial::new(2, 3),
+        Monomial::new(5, 3),
+        Monomial::new(0, 0),
+    ];
+    assert_eq!(same.into_iter().sum::<Monomial<i32>>(), Monomial::new(7, 3));
+    assert_eq!(
+        Vec::<Monomial<i32>>::new()
+            .into_iter()
+            .sum::<Monomial<i32>>(),
+        Monomial::default()
+    );
+
+    let mixed = [
+        Monomial::new(2, 3),
+        Monomial::new(5, 1),
+        Monomial::new(-2, 3),
+    ];
+    assert_eq!(
+        mixed.into_iter().sum_to_polynomial(),
+        Polynomial::try_from("5x").unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "same exponent")]
+fn test_sum_mixed_exponents() {
+    let mixed = [Monomial::new(2, 3), Monomial::new(5, 1)];
+    let _: Monomial<i32> = mixed.into_iter().sum();
+}
@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+use rust_polynomial::{MonomialOrder, MultiMonomial};
+
+fn mono(s: &str) -> MultiMonomial<i32> {
+    MultiMonomial::try_from(s).unwrap()
+}
+
+#[test]
+fn parse() {
+    let m = mono("3x^2y");
+    assert_eq!(m.get_value(), 3);
+    assert_eq!(m.get_exp('x'), 2);
+    assert_eq!(m.get_exp('y'), 1);
+    assert_eq!(m.get_exp('z'), 0);
+
+    assert_eq!(
+        mono("-xy^-2"),
+        MultiMonomial::new(-1, &[('x', 1), ('y', -2)])
+    );
+    assert_eq!(
+        mono("+ 2 y x"),
+        MultiMonomial::new(2, &[('x', 1), ('y', 1)])
+    );
+    assert_eq!(mono("xyx^2"), MultiMonomial::new(1, &[('x', 3), ('y', 1)]));
+    assert_eq!(mono("7"), MultiMonomial::new(7, &[]));
+
+    assert!(MultiMonomial::<i32>::try_from("3x^").is_err());
+    assert!(MultiMonomial::<i32>::try_from("a3x").is_err());
+    assert!(MultiMonomial::<i32>::try_from("x2").is_err());
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", mono("3x^2y")), "3x^2y");
+    assert_eq!(format!("{}", mono("-zx")), "-xz");
+    assert_eq!(format!("{}", mono("xy^0")), "x");
+    assert_eq!(format!("{}", mono("-1")), "-1");
+    assert_eq!(format!("{}", mono("0x^2")), "0");
+}
+
+#[test]
+fn degree() {
+    assert_eq!(mono("3x^2y").degree(), 3);
+    assert_eq!(mono("5").degree(), 0);
+    assert_eq!(mono("x^-1y^3").degree(), 2);
+}
+
+#[test]
+fn order() {
+    let a = mono("x^3");
+    let b = mono("x^2y^2");
+    let c = mono("xy^2z");
+
+    assert_eq!(a.cmp_by(&b, MonomialOrder::Lex), Ordering::Greater);
+    assert_eq!(a.cmp_by(&b, MonomialOrder::GrLex), Ordering::Less);
+    assert_eq!(b.cmp_by(&c, MonomialOrder::GrLex), Ordering::Greater);
+
+    // Same degree, grevlex penalizes the last variable
+    let d = mono("xyz^2");
+    let e = mono("y^3z");
+    assert_eq!(d.cmp_by(&e, MonomialOrder::GrLex), Ordering::Greater);
+    assert_eq!(d.cmp_by(&e, MonomialOrder::GRevLex), Ordering::Less);
+
+    assert_eq!(
+        mono("2xy").cmp_by(&mono("-yx"), MonomialOrder::GRevLex),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn mul_neg() {
+    assert_eq!(mono("3x^2y") * mono("-2xy^-1z"), mono("-6x^3z"));
+    assert_eq!(-mono("3x^2y"), mono("-3x^2y"));
+}
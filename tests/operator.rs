@@ -0,0 +1,41 @@
+use rust_polynomial::{DiffOp, LinearOperator, Polynomial, ScaleOp, ShiftOp};
+
+fn poly(expr: &str) -> Polynomial<i64> {
+    Polynomial::try_from(expr).unwrap()
+}
+
+#[test]
+fn operators_match_direct_computation() {
+    let p = poly("2x^4 - 3x^2 + x - 7");
+
+    assert_eq!(DiffOp.apply(&p).unwrap(), p.derivative());
+    assert_eq!(ShiftOp(3).apply(&p).unwrap(), p.shift(3).unwrap());
+    for x in -3..=3 {
+        assert_eq!(ScaleOp(2).apply(&p).unwrap().eval(x), p.eval(2 * x));
+    }
+
+    assert_eq!(ScaleOp(2).apply(&poly("4x^-2")).unwrap(), poly("x^-2"));
+    assert!(ScaleOp(0).apply(&poly("x^-1")).is_err());
+    assert!(ShiftOp(1).apply(&poly("x^-1")).is_err());
+}
+
+#[test]
+fn composition_order_and_linearity() {
+    let p = poly("x^3 + 5x");
+    let q = poly("-2x^2 + 4");
+
+    // Differentiating and shifting commute, scaling doesn't commute with differentiation
+    let shift_diff = ShiftOp(2).then(DiffOp);
+    assert_eq!(shift_diff.apply(&p), DiffOp.then(ShiftOp(2)).apply(&p));
+    assert_ne!(
+        ScaleOp(3).then(DiffOp).apply(&p),
+        DiffOp.then(ScaleOp(3)).apply(&p)
+    );
+
+    let pipeline = DiffOp.then(ShiftOp(-1)).then(ScaleOp(2));
+    assert_eq!(
+        pipeline.apply(&(p.clone() + q.clone())).unwrap(),
+        pipeline.apply(&p).unwrap() + pipeline.apply(&q).unwrap()
+    );
+    assert_eq!(pipeline, DiffOp.then(ShiftOp(-1)).then(ScaleOp(2)));
+}
@@ -0,0 +1,86 @@
+use rust_polynomial::{PiecewisePolynomial, Polynomial};
+
+fn piecewise<T: rust_polynomial::MonomialValue>(
+    breakpoints: Vec<T>,
+    pieces: &[&str],
+) -> PiecewisePolynomial<T> {
+    let pieces = pieces
+        .iter()
+        .map(|p| Polynomial::try_from(*p).unwrap())
+        .collect();
+
+    PiecewisePolynomial::new(breakpoints, pieces).unwrap()
+}
+
+#[test]
+fn new_errors() {
+    let pieces = vec![Polynomial::<i32>::try_from("x").unwrap()];
+    assert!(PiecewisePolynomial::new(vec![0], pieces.clone()).is_err());
+    assert!(PiecewisePolynomial::new(vec![1, 1], pieces.clone()).is_err());
+    assert!(PiecewisePolynomial::new(vec![2, 1], pieces.clone()).is_err());
+    assert!(PiecewisePolynomial::<i32>::new(vec![0], vec![]).is_err());
+    assert!(PiecewisePolynomial::new(vec![0, 1], pieces).is_ok());
+}
+
+#[test]
+fn eval() {
+    let poly = piecewise(vec![0, 2, 5, 6], &["x", "2", "x^2 - 20"]);
+
+    assert_eq!(poly.piece_index(-1), None);
+    assert_eq!(poly.piece_index(0), Some(0));
+    assert_eq!(poly.piece_index(2), Some(1));
+    assert_eq!(poly.piece_index(6), Some(2));
+    assert_eq!(poly.eval(1), Some(1));
+    assert_eq!(poly.eval(2), Some(2));
+    assert_eq!(poly.eval(5), Some(5));
+    assert_eq!(poly.eval(6), Some(16));
+    assert_eq!(poly.eval(7), None);
+}
+
+#[test]
+fn calculus() {
+    let poly = piecewise(vec![-1.0, 0.0, 1.0], &["x^2", "x^3"]);
+
+    assert_eq!(
+        poly.derivative(),
+        piecewise(vec![-1.0, 0.0, 1.0], &["2x", "3x^2"])
+    );
+
+    let integral = poly.integral().unwrap();
+    assert!(integral.is_continuous());
+    assert_eq!(integral.eval(-1.0), Some(0.0));
+    assert_eq!(integral.eval(0.0), Some(1.0 / 3.0));
+    assert_eq!(integral.eval(1.0), Some(1.0 / 3.0 + 0.25));
+    assert_eq!(integral.derivative(), poly);
+
+    assert_eq!(piecewise(vec![1, 2], &["x^-1"]).integral(), None);
+}
+
+#[test]
+fn roots() {
+    let poly = piecewise(vec![-3.0, 0.0, 3.0], &["x^2 - 4", "x^2 - 4"]);
+    assert_eq!(poly.roots(), Ok(vec![-2.0, 2.0]));
+
+    // Root on a breakpoint is reported once
+    let poly = piecewise(vec![-1, 0, 1], &["x", "-x"]);
+    assert_eq!(poly.roots(), Ok(vec![0.0]));
+
+    // Roots outside the interval of the piece are ignored
+    let poly = piecewise(vec![0, 1, 2], &["x - 5", "x - 2"]);
+    assert_eq!(poly.roots(), Ok(vec![2.0]));
+
+    assert!(piecewise(vec![0, 1], &["0"]).roots().is_err());
+}
+
+#[test]
+fn continuity() {
+    let poly = piecewise(vec![-1, 0, 1], &["-x", "x"]);
+    assert!(poly.is_continuous());
+    assert!(!poly.is_continuous_to(1));
+
+    let poly = piecewise(vec![0, 1, 2], &["x", "x + 1"]);
+    assert!(!poly.is_continuous());
+
+    let poly = piecewise(vec![0, 1], &["x^5"]);
+    assert!(poly.is_continuous_to(10));
+}
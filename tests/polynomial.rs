@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Neg};
 
-use rust_polynomial::Polynomial;
+use num::{Complex, Rational64};
+use rust_polynomial::{DivError, Monomial, Polynomial, Quadrature, RootsError, SyntaxErrorKind};
 
 #[test]
 fn check_test() {}
@@ -16,13 +17,13 @@ fn construct_by_vec() {
     ]);
 
     to_check.into_iter().for_each(|(v, expect)| {
-        let result = format!("{}", Polynomial::try_from(v).unwrap());
+        let result = format!("{}", Polynomial::from(v));
         assert_eq!(result, expect);
     });
 
     assert_eq!(
         Polynomial::try_from("0").unwrap(),
-        Polynomial::try_from(vec![0]).unwrap()
+        Polynomial::from(vec![0])
     );
 }
 
@@ -230,3 +231,2388 @@ fn roots_op_float() {
         assert_eq!(poly.roots(), expect);
     }
 }
+
+#[test]
+fn roots_without_constant_term() {
+    #[rustfmt::skip]
+    let to_check = HashMap::from([
+        ("x^3 - x", Some(vec![-1, 0, 1])),
+        ("x^3 - 4x^2 + 4x", Some(vec![0, 2])),
+        ("2x^5 - 18x^3", Some(vec![-3, 0, 3])),
+        ("x^4 + x^2", Some(vec![0])),
+        ("5x^2", Some(vec![0])),
+    ]);
+
+    for (p_str, expect) in to_check {
+        let poly = Polynomial::<i32>::try_from(p_str).unwrap();
+        assert_eq!(poly.roots(), expect, "{p_str}");
+    }
+
+    // `x^5 - x - 1` can't be solved, zero alone would hide its real root
+    let poly = Polynomial::<f64>::try_from("x^6 - x^2 - x").unwrap();
+    assert_eq!(poly.roots(), None);
+    assert_eq!(poly.try_roots(), Err(RootsError::UnsupportedDegree(6)));
+}
+
+#[test]
+fn derivative_op() {
+    #[rustfmt::skip]
+    let to_check = HashMap::from([
+        ("x^4 - 6x^2 + 8", "4x^3 - 12x"),
+        ("5x - 3", "5"),
+        ("7", "0"),
+        ("0", "0"),
+    ]);
+
+    for (p_str, expect) in to_check {
+        let poly = Polynomial::<i32>::try_from(p_str).unwrap();
+        assert_eq!(format!("{}", poly.derivative()), expect);
+    }
+}
+
+#[test]
+fn factors_op() {
+    #[rustfmt::skip]
+    let to_check = HashMap::from([
+        ("x^2 - 1", vec!["x + 1", "x - 1"]),
+        ("2x^2 + 4x - 30", vec!["2", "x + 5", "x - 3"]),
+        ("x^3 - 5x^2 - x + 5", vec!["x + 1", "x - 1", "x - 5"]),
+        ("x^2 + 1", vec!["x^2 + 1"]),
+    ]);
+
+    for (p_str, expect) in to_check {
+        let poly = Polynomial::<i32>::try_from(p_str).unwrap();
+        let factors: Vec<String> = poly.factors().iter().map(|f| format!("{f}")).collect();
+        assert_eq!(factors, expect);
+    }
+}
+
+#[test]
+fn builder_construct() {
+    let poly: Polynomial<i32> = Polynomial::builder()
+        .term(3, 2)
+        .term(-1, 0)
+        .term(2, 2)
+        .build();
+
+    assert_eq!(poly, Polynomial::try_from("5x^2 - 1").unwrap());
+
+    let poly: Polynomial<i32> = Polynomial::builder()
+        .term(1, 1)
+        .term(-1, 0)
+        .var('t')
+        .build();
+
+    assert_eq!(poly.get_var(), 't');
+    assert_eq!(format!("{}", poly.clone() * poly), "t^2 - 2t + 1");
+    assert_eq!(format!("{}", Polynomial::<i32>::builder().build()), "0");
+}
+
+#[test]
+fn predicates() {
+    let zero = Polynomial::<i32>::default();
+    assert!(zero.is_zero() && zero.is_empty() && zero.is_constant());
+    assert!(!zero.is_monic() && !zero.is_linear());
+
+    let constant = Polynomial::<i32>::try_from("1").unwrap();
+    assert!(constant.is_constant() && constant.is_monic());
+    assert!(!constant.is_zero() && !constant.is_empty() && !constant.is_linear());
+
+    let linear = Polynomial::<i32>::try_from("x - 4").unwrap();
+    assert!(linear.is_linear() && linear.is_monic() && !linear.is_constant());
+
+    let laurent: Polynomial<i32> = Polynomial::builder().term(1, 1).term(1, -1).build();
+    assert!(!laurent.is_linear());
+}
+
+#[test]
+fn canonical_zero() {
+    let poly = Polynomial::<i32>::try_from("x^2 + 3x").unwrap();
+    let zero = Polynomial::<i32>::zero();
+
+    assert_eq!(Polynomial::<i32>::default(), zero);
+    assert_eq!(Polynomial::from(vec![0, 0]), zero);
+    assert_eq!(poly.clone() + poly.clone().neg(), zero);
+    assert_eq!(poly.clone() * zero.clone(), zero);
+    assert_eq!((poly.clone() / poly.clone()).1, zero);
+    assert_eq!(Polynomial::<i32>::try_from("0x^3").unwrap(), zero);
+    assert!(zero.is_empty());
+}
+
+#[test]
+fn truncate_op() {
+    let poly = Polynomial::<i32>::try_from("5x^4 - x^3 + 2x - 7").unwrap();
+
+    for (n, expect) in [
+        (5, "5x^4 - x^3 + 2x - 7"),
+        (4, "-x^3 + 2x - 7"),
+        (1, "-7"),
+        (0, "0"),
+    ] {
+        let mut truncated = poly.clone();
+        truncated.truncate(n);
+
+        assert_eq!(format!("{truncated}"), expect);
+        assert_eq!(poly.rem_xn(n), truncated);
+    }
+}
+
+#[test]
+fn inverse_mod_xn_op() {
+    let one = Polynomial::<i32>::try_from("1").unwrap();
+
+    for p_str in ["1 - x", "1 + 3x - 2x^2", "-1 + x^3 + 5x^4", "x^7 - 1"] {
+        let poly = Polynomial::<i32>::try_from(p_str).unwrap();
+
+        for n in [1, 2, 5, 8, 13] {
+            let inverse = poly.inverse_mod_xn(n).unwrap();
+            assert_eq!((poly.clone() * inverse.clone()).rem_xn(n), one);
+            assert!(inverse.max_exp().get_exp() < n);
+        }
+    }
+
+    let poly = Polynomial::<f64>::try_from("2 + x").unwrap();
+    assert_eq!(
+        poly.inverse_mod_xn(3).unwrap(),
+        Polynomial::from(vec![0.125, -0.25, 0.5])
+    );
+
+    assert!(Polynomial::<i32>::try_from("x^2 + x")
+        .unwrap()
+        .inverse_mod_xn(3)
+        .is_err());
+}
+
+#[test]
+fn div_op_large() {
+    let dividend: Polynomial<i64> = Polynomial::builder()
+        .term(3, 150)
+        .term(-7, 97)
+        .term(1, 64)
+        .term(12, 3)
+        .term(-5, 0)
+        .build();
+    let divider = Polynomial::<i64>::try_from("x^10 - x^4 + 2x - 1").unwrap();
+
+    let (quotient, rest) = dividend.clone() / divider.clone();
+
+    assert_eq!(quotient.max_exp().get_exp(), 140);
+    assert!(rest.max_exp().get_exp() < 10);
+    assert_eq!(quotient * divider + rest, dividend);
+}
+
+#[test]
+fn mul_op_large() {
+    let mut seed = 12345i64;
+    let mut next = || {
+        seed = (seed * 1103515245 + 12345) % 2147483648;
+        seed % 2001 - 1000
+    };
+
+    let p1: Polynomial<i64> = Polynomial::from((0..40).map(|_| next()).collect::<Vec<_>>());
+    let p2: Polynomial<i64> = Polynomial::from((0..25).map(|_| next()).collect::<Vec<_>>());
+
+    let naive = Polynomial::new(
+        p1.clone()
+            .into_iter()
+            .flat_map(|m1| p2.clone().into_iter().map(move |m2| m1 * m2))
+            .collect(),
+    );
+
+    assert_eq!(p1.mul_kronecker(&p2), Some(naive.clone()));
+    assert_eq!(p1.clone() * p2.clone(), naive);
+    assert_eq!(p1.clone().neg().mul_kronecker(&p2), Some(naive.neg()));
+
+    let half = Polynomial::<f64>::try_from("0.5x + 1").unwrap();
+    assert_eq!(half.mul_kronecker(&half), None);
+
+    let high = Polynomial::<i64>::try_from("x^2000000000 + 1").unwrap();
+    assert_eq!(high.mul_kronecker(&high), None);
+}
+
+#[test]
+fn mul_op_sparse() {
+    // Enough terms for Kronecker substitution, but packing would need a digit per exponent
+    let sparse: Polynomial<i64> = Polynomial::new(
+        (0..64)
+            .map(|i| Monomial::new(i as i64 % 7 + 1, i << 20))
+            .collect(),
+    );
+
+    let naive = Polynomial::new(
+        sparse
+            .clone()
+            .into_iter()
+            .flat_map(|m1| sparse.clone().into_iter().map(move |m2| m1 * m2))
+            .collect(),
+    );
+
+    assert_eq!(sparse.clone() * sparse.clone(), naive);
+    assert_eq!(sparse.checked_mul(&sparse), Some(naive));
+}
+
+#[test]
+fn mul_op_toom3() {
+    let mut seed = 54321i64;
+    let mut next = || {
+        seed = (seed * 1103515245 + 12345) % 2147483648;
+        seed % 201 - 100
+    };
+
+    for (len1, len2) in [(30, 30), (100, 80), (81, 27), (200, 150)] {
+        let p1: Polynomial<i64> = Polynomial::from((0..len1).map(|_| next()).collect::<Vec<_>>());
+        let p2: Polynomial<i64> = Polynomial::from((0..len2).map(|_| next()).collect::<Vec<_>>());
+
+        let naive = Polynomial::new(
+            p1.clone()
+                .into_iter()
+                .flat_map(|m1| p2.clone().into_iter().map(move |m2| m1 * m2))
+                .collect(),
+        );
+
+        assert_eq!(p1.mul_toom3(&p2), Some(naive.clone()));
+        assert_eq!(p1 * p2, naive);
+    }
+}
+
+#[test]
+fn mul_op_toom3_near_overflow() {
+    // Blocks of equal signs make the evaluations at -2 grow far beyond the product
+    let coeffs =
+        |sign: i32| -> Vec<i32> { (0..60).map(|i| sign * [3000, -3000][i / 20 % 2]).collect() };
+    let p1: Polynomial<i32> = Polynomial::from(coeffs(1));
+    let p2: Polynomial<i32> = Polynomial::from(coeffs(-1));
+
+    let naive = Polynomial::new(
+        p1.clone()
+            .into_iter()
+            .flat_map(|m1| p2.clone().into_iter().map(move |m2| m1 * m2))
+            .collect(),
+    );
+
+    assert_eq!(p1.mul_toom3(&p2), Some(naive.clone()));
+    assert_eq!(p1 * p2, naive);
+
+    // A coefficient of the product out of `i32`
+    let big: Polynomial<i32> = Polynomial::from(vec![50_000; 60]);
+    assert_eq!(big.mul_toom3(&big), None);
+}
+
+#[test]
+fn parse_from_reader() {
+    for p_str in [
+        "x + 5",
+        "3x + 5 + 31x^2 - 7",
+        "-81x + 9x^9 - 6x^5 - x^90 - 6 + 8x^2",
+        "0",
+    ] {
+        let expect = Polynomial::<i32>::try_from(p_str).unwrap();
+        let reader = std::io::BufReader::with_capacity(3, p_str.as_bytes());
+
+        assert_eq!(Polynomial::parse_from_reader(reader).unwrap(), expect);
+    }
+
+    // Empty input is rejected the same way by both parsers
+    assert_eq!(
+        Polynomial::<i32>::parse_from_reader("".as_bytes()),
+        Polynomial::<i32>::try_from("")
+    );
+
+    let terms = (0..10_000).map(|i| format!("{}x^{} - x^{}", i + 1, i % 7, i % 7));
+    let text = terms.collect::<Vec<_>>().join(" + ");
+    let poly: Polynomial<i64> = Polynomial::parse_from_reader(text.as_bytes()).unwrap();
+    assert_eq!(
+        poly.find_by_exp(0).get_value(),
+        (0..10_000).step_by(7).sum::<i64>()
+    );
+
+    assert!(Polynomial::<i32>::parse_from_reader("3x + 2y".as_bytes()).is_err());
+
+    // Chars are decoded as UTF-8 even if a read splits them
+    let text = "3x\u{a0}+\u{2003}2";
+    let reader = std::io::BufReader::with_capacity(1, text.as_bytes());
+    assert_eq!(
+        Polynomial::<i32>::parse_from_reader(reader),
+        Ok(Polynomial::try_from("3x + 2").unwrap())
+    );
+    assert_eq!(
+        Polynomial::<i32>::parse_from_reader("3x + é".as_bytes()),
+        Err(Polynomial::<i32>::try_from("3x + é").unwrap_err())
+    );
+    let invalid: &[&[u8]] = &[b"3x + \xff", b"3x + \xc3", b"\xe2\x80 + 1"];
+    for bytes in invalid {
+        assert_eq!(
+            Polynomial::<i32>::parse_from_reader(*bytes),
+            Err("Not valid UTF-8")
+        );
+    }
+}
+
+#[test]
+fn parse_all() {
+    let text = "x + 5; 2x^2\n\n  ;\n3x - 2z\n-x^3 + 1;7x^2 + 2a";
+    let result = Polynomial::<i32>::parse_all(text);
+
+    assert_eq!(result.len(), 5);
+    assert_eq!(result[0], Ok(Polynomial::try_from("x + 5").unwrap()));
+    assert_eq!(result[1], Ok(Polynomial::try_from("2x^2").unwrap()));
+    assert_eq!(result[2].as_ref().unwrap_err().line, 4);
+    assert_eq!(result[3], Ok(Polynomial::try_from("-x^3 + 1").unwrap()));
+    assert_eq!(result[4].as_ref().unwrap_err().line, 5);
+}
+
+#[test]
+fn coeffs_conversion() {
+    #[rustfmt::skip]
+    let to_check = HashMap::from([
+        ("x + 5", vec![5, 1]),
+        ("-6x^4 + 20x - 8", vec![-8, 20, 0, 0, -6]),
+        ("7", vec![7]),
+        ("0", vec![]),
+    ]);
+
+    for (p_str, coeffs) in to_check {
+        let poly = Polynomial::<i32>::try_from(p_str).unwrap();
+
+        assert_eq!(poly.to_coeffs(), Ok(coeffs.clone()));
+        assert_eq!(Polynomial::from_coeffs(&coeffs), poly);
+    }
+
+    assert_eq!(
+        Polynomial::from_coeffs(&[1, 0, 0]),
+        Polynomial::try_from("1").unwrap()
+    );
+}
+
+#[test]
+fn integral_op() {
+    for p_str in ["x^4 - 6x^2 + 8", "5x - 3", "0", "-x^-3 + 2x^-2"] {
+        let poly = Polynomial::<f64>::try_from(p_str).unwrap();
+        assert_eq!(poly.integral().unwrap().derivative(), poly);
+    }
+
+    assert_eq!(
+        Polynomial::<f64>::try_from("x^-1").unwrap().integral(),
+        None
+    );
+}
+
+#[test]
+fn derivative_relations() {
+    let to_check = HashMap::from([
+        ("3x^2 - 4", "x^3 - 4x + 7"),
+        ("0", "12"),
+        ("-x^-2", "x^-1 + 1"),
+        ("6x + 1", "3x^2 + x"),
+    ]);
+
+    for (d_str, p_str) in to_check {
+        let d = Polynomial::<i32>::try_from(d_str).unwrap();
+        let p = Polynomial::<i32>::try_from(p_str).unwrap();
+
+        assert!(d.is_derivative_of(&p));
+        assert!(p.antiderivative_matches(&d));
+        assert!(!p.is_derivative_of(&d));
+    }
+
+    // The integer integral of `x` is truncated to zero
+    let p = Polynomial::<i32>::try_from("x").unwrap();
+    assert!(!p.integral().unwrap().antiderivative_matches(&p));
+}
+
+#[test]
+fn eval_via_remainder_op() {
+    let to_check = [
+        ("x^3 - 6x^2 + 11x - 6", 2, 0),
+        ("2x^4 - 3x + 5", -1, 10),
+        ("7", 4, 7),
+        ("0", 3, 0),
+        ("x^5", 2, 32),
+    ];
+
+    for (p_str, a, value) in to_check {
+        let poly = Polynomial::<i64>::try_from(p_str).unwrap();
+        let (result, quotient) = poly.eval_via_remainder(a).unwrap();
+        let divisor = Polynomial::from(vec![1, -a]);
+
+        assert_eq!(result, value);
+        assert_eq!(result, poly.eval(a));
+        assert_eq!(quotient * divisor + Polynomial::from_coeffs(&[value]), poly);
+    }
+
+    assert!(Polynomial::<i64>::try_from("x^-1 + 1")
+        .unwrap()
+        .eval_via_remainder(1)
+        .is_err());
+}
+
+#[test]
+fn shift_op() {
+    let poly = Polynomial::<i32>::try_from("x^3 - 2x + 7").unwrap();
+    for h in [-3, 0, 1, 5] {
+        let shifted = poly.shift(h).unwrap();
+        for x in -4..=4 {
+            assert_eq!(shifted.eval(x), poly.eval(x + h));
+        }
+    }
+
+    assert!(Polynomial::<i32>::try_from("x^-2")
+        .unwrap()
+        .shift(1)
+        .is_err());
+}
+
+#[test]
+fn shift_to_depressed_op() {
+    for p_str in [
+        "x^3 - 6x^2 + 11x - 6",
+        "2x^4 + 8x^3 - x",
+        "3x^2 + 6x + 1",
+        "x + 4",
+    ] {
+        let poly = Polynomial::<f64>::try_from(p_str).unwrap();
+        let (depressed, s) = poly.shift_to_depressed().unwrap();
+        let degree = poly.max_exp().get_exp();
+
+        assert_eq!(depressed.max_exp(), poly.max_exp());
+        assert_eq!(depressed.find_by_exp(degree - 1).get_value(), 0.0);
+        assert_eq!(depressed.eval(1.5), poly.eval(1.5 + s));
+    }
+
+    // Constants have no second-highest term to remove
+    let constant = Polynomial::<i32>::try_from("5").unwrap();
+    assert_eq!(constant.shift_to_depressed(), Ok((constant, 0)));
+    let constant = Polynomial::<f64>::try_from("-2.5").unwrap();
+    assert_eq!(constant.shift_to_depressed(), Ok((constant, 0.0)));
+    assert_eq!(
+        Polynomial::<i32>::zero().shift_to_depressed(),
+        Ok((Polynomial::zero(), 0))
+    );
+    assert!(Polynomial::<i32>::try_from("x^-1 + 3")
+        .unwrap()
+        .shift_to_depressed()
+        .is_err());
+}
+
+#[test]
+fn vertex_form() {
+    let to_check = [
+        ("x^2 - 4x + 1", Some((1.0, 2.0, -3.0))),
+        ("-3x^2 + 6x", Some((-3.0, 1.0, 3.0))),
+        ("x^2", Some((1.0, 0.0, 0.0))),
+        ("x^3 + x^2", None),
+        ("x + 1", None),
+    ];
+
+    for (p_str, expected) in to_check {
+        let poly = Polynomial::<f64>::try_from(p_str).unwrap();
+        assert_eq!(poly.to_vertex_form(), expected);
+    }
+}
+
+#[test]
+fn roots_with_progress() {
+    use rust_polynomial::RootProgress;
+    use std::ops::ControlFlow;
+
+    let poly = Polynomial::<i64>::try_from("x^4 - 10x^3 + 35x^2 - 50x + 24").unwrap();
+
+    let mut reports: Vec<RootProgress> = Vec::new();
+    let roots = poly.roots_with_progress(|p| {
+        reports.push(p);
+        ControlFlow::Continue(())
+    });
+
+    assert_eq!(roots, Ok(poly.roots()));
+    assert_eq!(reports[0].degree, 4);
+    assert_eq!(reports[0].total, 24);
+    assert!(reports.iter().any(|p| p.degree == 3));
+    assert!(reports.iter().all(|p| p.checked <= p.total));
+
+    // Abort once the first deflation is done
+    let roots = poly.roots_with_progress(|p| match p.degree {
+        4 => ControlFlow::Continue(()),
+        _ => ControlFlow::Break(()),
+    });
+    assert!(roots.is_err());
+
+    // Closed form equations don't report
+    let quadratic = Polynomial::<i64>::try_from("x^2 - 1").unwrap();
+    assert_eq!(
+        quadratic.roots_with_progress(|_| ControlFlow::Break(())),
+        Ok(Some(vec![-1, 1]))
+    );
+}
+
+#[test]
+fn deterministic_output() {
+    let terms = [
+        "0.1x^3", "-2x", "0.2x^3", "7", "x^-2", "0.3x^3", "-x^-2", "1.5x",
+    ];
+    let expected = format!(
+        "{}",
+        Polynomial::<f64>::try_from(terms.join(" + ").as_str()).unwrap()
+    );
+
+    for _ in 0..50 {
+        let poly = Polynomial::<f64>::try_from(terms.join(" + ").as_str()).unwrap();
+        let reader = Polynomial::<f64>::parse_from_reader(terms.join(" + ").as_bytes()).unwrap();
+
+        assert_eq!(format!("{poly}"), expected);
+        assert_eq!(format!("{reader}"), expected);
+    }
+}
+
+#[test]
+fn roots_nan_safe() {
+    // Negative discriminant
+    let poly = Polynomial::<f64>::try_from("x^2 + 2x + 5").unwrap();
+    let first = format!("{:?}", poly.roots());
+
+    for _ in 0..10 {
+        assert_eq!(format!("{:?}", poly.roots()), first);
+    }
+}
+
+#[test]
+fn try_roots_errors() {
+    use rust_polynomial::RootsError;
+
+    let to_check: [(&str, Result<Vec<i64>, RootsError>); 8] = [
+        ("x^3 - 6x^2 + 11x - 6", Ok(vec![1, 2, 3])),
+        ("2x - 8", Ok(vec![4])),
+        ("x^2 + 2x + 5", Err(RootsError::NoRealRoots)),
+        ("x^4 + 3x^2 + 5", Err(RootsError::NoRealRoots)),
+        ("x^6 + 64", Err(RootsError::NoRealRoots)),
+        ("x^3 + 2x + 7", Err(RootsError::UnsupportedDegree(3))),
+        ("12", Err(RootsError::UnsupportedDegree(0))),
+        ("x^2 + x^-1", Err(RootsError::NegativeExponent)),
+    ];
+
+    for (p_str, expected) in to_check {
+        let poly = Polynomial::<i64>::try_from(p_str).unwrap();
+        assert_eq!(poly.try_roots(), expected, "{p_str}");
+    }
+
+    // Float coefficients with negative discriminant
+    let poly = Polynomial::<f64>::try_from("x^2 + 2x + 5").unwrap();
+    assert_eq!(poly.try_roots(), Err(RootsError::NoRealRoots));
+
+    // The divisor search works with i64 coefficients
+    let poly = Polynomial::<i128>::try_from("x^3 + x - 100000000000000000000").unwrap();
+    assert_eq!(poly.try_roots(), Err(RootsError::CoefficientOverflow));
+}
+
+#[test]
+fn roots_classified() {
+    use rust_polynomial::Root;
+
+    let poly = Polynomial::<i64>::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    assert_eq!(
+        poly.roots_classified(),
+        Some(vec![Root::Exact(1), Root::Exact(2), Root::Exact(3)])
+    );
+
+    // Integer polynomial with irrational roots
+    let poly = Polynomial::<i64>::try_from("x^2 - 3x + 1").unwrap();
+    let roots = poly.roots_classified().unwrap();
+    assert_eq!(roots.len(), 2);
+    for (root, expected) in roots.iter().zip([0.381966011250105, 2.618033988749895]) {
+        let Root::Approx { value, error } = root else {
+            panic!("{root:?} should be approximated");
+        };
+        assert!((value - expected).abs() < 1e-12);
+        assert!(*error < 1e-12);
+    }
+
+    let poly = Polynomial::<f64>::try_from("x^2 - 0.25").unwrap();
+    assert_eq!(
+        poly.roots_classified(),
+        Some(vec![Root::Exact(-0.5), Root::Exact(0.5)])
+    );
+
+    let poly = Polynomial::<i64>::try_from("x^2 + 1").unwrap();
+    assert_eq!(poly.roots_classified(), None);
+}
+
+#[test]
+fn radical_roots() {
+    use rust_polynomial::QuadraticRoot;
+
+    let to_check = [
+        (
+            "23x^2 + 90x + 100",
+            Some(QuadraticRoot {
+                p: -45,
+                q: 23,
+                d: -275,
+            }),
+        ),
+        ("2x^2 - 4x - 2", Some(QuadraticRoot { p: 1, q: 1, d: 2 })),
+        ("-x^2 + 2x + 1", Some(QuadraticRoot { p: 1, q: 1, d: 2 })),
+        ("x^2 - 5x + 6", Some(QuadraticRoot { p: 5, q: 2, d: 1 })),
+        ("4x^2 + 4x + 1", Some(QuadraticRoot { p: -1, q: 2, d: 0 })),
+        ("x^2 + x + 1", Some(QuadraticRoot { p: -1, q: 2, d: -3 })),
+        ("x^3 + 1", None),
+        ("x + 1", None),
+    ];
+
+    for (p_str, expected) in to_check {
+        let poly = Polynomial::<i64>::try_from(p_str).unwrap();
+        assert_eq!(poly.radical_roots(), expected, "{p_str}");
+    }
+
+    let root = QuadraticRoot { p: 5, q: 2, d: 1 };
+    assert!(root.is_rational());
+    assert_eq!(root.to_f64(), Some((2.0, 3.0)));
+    assert_eq!(QuadraticRoot { p: -1, q: 2, d: -3 }.to_f64(), None);
+
+    let poly = Polynomial::<f64>::try_from("0.5x^2 - 1").unwrap();
+    assert_eq!(poly.radical_roots(), None);
+
+    // `2a` overflows, the discriminant doesn't
+    assert_eq!(
+        Polynomial::from_coeffs(&[0i64, 0, 1 << 62]).radical_roots(),
+        None
+    );
+    assert_eq!(
+        Polynomial::from_coeffs(&[0i64, 0, -(1 << 62)]).radical_roots(),
+        None
+    );
+    assert_eq!(
+        Polynomial::from_coeffs(&[0i64, 0, 1 << 61]).radical_roots(),
+        Some(QuadraticRoot { p: 0, q: 1, d: 0 })
+    );
+}
+
+#[test]
+fn roots_complex() {
+    use num::Complex;
+
+    let poly = Polynomial::<f64>::try_from("2x^2 - 2x + 1").unwrap();
+    assert_eq!(
+        poly.roots_complex(),
+        Some(vec![Complex::new(0.5, -0.5), Complex::new(0.5, 0.5)])
+    );
+
+    let poly = Polynomial::<i32>::try_from("x^2 - 5x + 6").unwrap();
+    assert_eq!(
+        poly.roots_complex(),
+        Some(vec![Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)])
+    );
+
+    assert_eq!(
+        Polynomial::<i32>::try_from("x^3 + 1")
+            .unwrap()
+            .roots_complex(),
+        None
+    );
+
+    // Real roots don't go through NaN anymore
+    for p_str in ["x^2 + 2x + 5", "x^2 + 4", "x^4 + 2x^2 + 1"] {
+        assert_eq!(Polynomial::<f64>::try_from(p_str).unwrap().roots(), None);
+        assert_eq!(Polynomial::<i32>::try_from(p_str).unwrap().roots(), None);
+    }
+}
+
+#[test]
+fn roots_in_interval() {
+    let poly = Polynomial::<i64>::try_from("x^4 - 10x^3 + 35x^2 - 50x + 24").unwrap();
+    assert_eq!(poly.count_roots_in(0.0, 10.0), Ok(4));
+    assert_eq!(poly.count_roots_in(1.5, 3.5), Ok(2));
+    assert_eq!(poly.count_roots_in(2.0, 3.0), Ok(2));
+    assert_eq!(poly.count_roots_in(4.5, 100.0), Ok(0));
+
+    let roots = poly.roots_in_interval(1.5, 4.0).unwrap();
+    assert_eq!(roots.len(), 3);
+    for (root, expected) in roots.iter().zip([2.0, 3.0, 4.0]) {
+        assert!((root - expected).abs() < 1e-12);
+    }
+
+    // Double irrational roots, only half of the f64 precision can be reached
+    let poly = Polynomial::<f64>::try_from("x^4 - 2x^3 - x^2 + 2x + 1").unwrap();
+    let roots = poly.roots_in_interval(-5.0, 5.0).unwrap();
+    let golden = (1.0 + 5f64.sqrt()) / 2.0;
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0] - (1.0 - golden)).abs() < 1e-7);
+    assert!((roots[1] - golden).abs() < 1e-7);
+
+    // Collision query, no roots in [0, 1]
+    let poly = Polynomial::<f64>::try_from("x^2 - 3x + 2.5").unwrap();
+    assert_eq!(poly.roots_in_interval(0.0, 1.0), Ok(vec![]));
+
+    assert!(Polynomial::<i32>::zero()
+        .roots_in_interval(0.0, 1.0)
+        .is_err());
+    assert!(Polynomial::<i32>::try_from("x^-1")
+        .unwrap()
+        .roots_in_interval(0.0, 1.0)
+        .is_err());
+}
+
+#[test]
+fn first_positive_root() {
+    let to_check: [(&str, Option<f64>); 6] = [
+        ("x^3 - 3x^2 - x + 3", Some(1.0)),
+        ("x^2 - 2", Some(2f64.sqrt())),
+        ("x^3 - x", Some(1.0)),
+        ("x^2 + 1", None),
+        ("x + 3", None),
+        ("-4.9x^2 + 10x + 2", Some((10.0 + 139.2f64.sqrt()) / 9.8)),
+    ];
+
+    for (p_str, expected) in to_check {
+        let poly = Polynomial::<f64>::try_from(p_str).unwrap();
+        match (poly.first_positive_root(), expected) {
+            (Some(root), Some(expected)) => assert!((root - expected).abs() < 1e-12, "{p_str}"),
+            (root, expected) => assert_eq!(root, expected, "{p_str}"),
+        }
+    }
+
+    let poly = Polynomial::<f64>::try_from("0.01x^5 - 100").unwrap();
+    let root = poly.first_positive_root().unwrap();
+    assert!((root - 10000f64.powf(0.2)).abs() < 1e-9);
+}
+
+#[test]
+fn common_roots() {
+    let p = Polynomial::<i64>::try_from("x^3 - 6x^2 + 11x - 6").unwrap();
+    let to_check: [(&str, bool, Vec<f64>); 5] = [
+        ("x^2 - 5x + 6", true, vec![2.0, 3.0]),
+        ("x^2 + x - 6", true, vec![2.0]),
+        ("x^2 + 1", false, vec![]),
+        ("x - 4", false, vec![]),
+        ("7", false, vec![]),
+    ];
+
+    for (q_str, common, roots) in to_check {
+        let q = Polynomial::<i64>::try_from(q_str).unwrap();
+        assert_eq!(p.has_common_root(&q), common, "{q_str}");
+        assert_eq!(q.has_common_root(&p), common, "{q_str}");
+        let result = p.common_roots(&q);
+        assert_eq!(result.len(), roots.len(), "{q_str}");
+        assert!(result.iter().zip(roots).all(|(r, e)| (r - e).abs() < 1e-12));
+    }
+
+    // Irrational common root
+    let p = Polynomial::<f64>::try_from("x^3 - 2x").unwrap();
+    let q = Polynomial::<f64>::try_from("x^2 - 0.41421356237309515x - 1.4142135623730951").unwrap();
+    let roots = p.common_roots(&q);
+    assert_eq!(roots.len(), 1);
+    assert!((roots[0] - 2f64.sqrt()).abs() < 1e-9);
+
+    // Swapping the operands of odd degrees changes the sign
+    let p = Polynomial::<i64>::try_from("x^3 + 2x - 1").unwrap();
+    let q = Polynomial::<i64>::try_from("x^2 + 3").unwrap();
+    assert_eq!(p.resultant(&q), q.resultant(&p));
+    let q = Polynomial::<i64>::try_from("x - 3").unwrap();
+    assert_eq!(p.resultant(&q), Ok(-p.eval(3)));
+    assert_eq!(q.resultant(&p), Ok(p.eval(3)));
+}
+
+#[test]
+fn intersections() {
+    let p = Polynomial::<i32>::try_from("x^3").unwrap();
+    let q = Polynomial::<i32>::try_from("x").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![(-1, -1), (0, 0), (1, 1)]));
+
+    // Tangent line at x = 1
+    let p = Polynomial::<f64>::try_from("x^2").unwrap();
+    let q = Polynomial::<f64>::try_from("2x - 1").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![(1.0, 1.0)]));
+
+    let q = Polynomial::<f64>::try_from("-1").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![]));
+    assert_eq!(p.intersections(&p), Ok(vec![]));
+    let q = Polynomial::<f64>::try_from("x^2 + 1").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![]));
+
+    // Irrational crossings at `±sqrt(2)` aren't points of both curves in `T`
+    let p = Polynomial::<i32>::try_from("x^2").unwrap();
+    let q = Polynomial::<i32>::try_from("2").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![]));
+    let q = Polynomial::<i32>::try_from("x + 1").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![]));
+    let p = Polynomial::<f64>::try_from("x^2").unwrap();
+    let q = Polynomial::<f64>::try_from("2").unwrap();
+    assert_eq!(p.intersections(&q), Ok(vec![]));
+
+    let q = Polynomial::<i32>::try_from("x^6 + x^2 + x + 1").unwrap();
+    let p = Polynomial::<i32>::try_from("x^2").unwrap();
+    assert_eq!(p.intersections(&q), Err(RootsError::UnsupportedDegree(6)));
+}
+
+#[test]
+fn tangent_normal_curvature() {
+    let poly = Polynomial::<f64>::try_from("x^3 - 2x").unwrap();
+
+    for x in [-1.5, 0.0, 0.5, 2.0] {
+        let tangent = poly.tangent_line_at(x);
+        let slope = 3.0 * x * x - 2.0;
+
+        assert_eq!(tangent.eval(x), poly.eval(x));
+        assert_eq!(tangent.derivative().eval(0.0), slope);
+
+        let normal = poly.normal_line_at(x).unwrap();
+        assert!((normal.eval(x) - poly.eval(x)).abs() < 1e-12);
+        assert!((normal.derivative().eval(0.0) * slope + 1.0).abs() < 1e-12);
+    }
+
+    // Horizontal tangent at the vertex
+    let parabola = Polynomial::<i32>::try_from("x^2 - 4x + 1").unwrap();
+    assert_eq!(format!("{}", parabola.tangent_line_at(2)), "-3");
+    assert_eq!(parabola.normal_line_at(2), None);
+
+    // Straight lines don't bend, a circle of radius 1/2 at the vertex of x^2
+    assert_eq!(
+        Polynomial::<i32>::try_from("3x + 1")
+            .unwrap()
+            .curvature_at(5),
+        Some(0.0)
+    );
+    let square = Polynomial::<f64>::try_from("x^2").unwrap();
+    assert_eq!(square.curvature_at(0.0), Some(2.0));
+    assert!((square.curvature_at(1.0).unwrap() - 2.0 / 5f64.powf(1.5)).abs() < 1e-12);
+}
+
+#[test]
+fn hurwitz_stability() {
+    let stable = |s: &str| Polynomial::<i64>::try_from(s).unwrap().is_hurwitz_stable();
+
+    assert!(stable("7"));
+    assert!(stable("2x + 1"));
+    assert!(stable("-x^2 - 3x - 2"));
+    assert!(stable("x^4 + 10x^3 + 35x^2 + 50x + 24"));
+    // Complex roots `-1 ± 2i` of `(x + 3)(x^2 + 2x + 5)`
+    assert!(stable("x^3 + 5x^2 + 11x + 15"));
+
+    assert!(!stable("0"));
+    assert!(!stable("x"));
+    assert!(!stable("x - 1"));
+    assert!(!stable("x^2 + 4"));
+    assert!(!stable("x^2 - 3x + 2"));
+    assert!(!stable("x^2 + x^-1"));
+    // Positive coefficients aren't enough, roots `0.5 ± 1.9i`
+    assert!(!stable("x^3 + x^2 + 2x + 8"));
+    // Zero in the first column of the Routh array
+    assert!(!stable("x^3 + x^2 + x + 1"));
+
+    let float = Polynomial::<f64>::try_from("x^2 + 0.1x + 10").unwrap();
+    assert!(float.is_hurwitz_stable());
+    // Same roots, the sign of the leading coefficient doesn't matter
+    assert!((-float).is_hurwitz_stable());
+}
+
+#[test]
+fn schur_stability() {
+    let stable = |s: &str| Polynomial::<f64>::try_from(s).unwrap().is_schur_stable();
+
+    assert!(stable("3"));
+    assert!(stable("x"));
+    assert!(stable("2x - 1"));
+    assert!(stable("x^2 - 0.25"));
+    assert!(stable("x^3 - 0.5x^2 + 0.25x - 0.125"));
+    // Complex roots `0.5 ± 0.5i` and `-0.9`
+    assert!(stable("x^3 - 0.1x^2 - 0.4x + 0.45"));
+    assert!(stable("-8x^3 + 1"));
+
+    assert!(!stable("0"));
+    assert!(!stable("x - 2"));
+    assert!(!stable("x + 1"));
+    assert!(!stable("x^2 + 1"));
+    assert!(!stable("x^-1 + 0.5"));
+    // `|a_0| < |a_n|` holds but a root `-2` is outside, `(x - 0.25)(x + 2)`
+    assert!(!stable("x^2 + 1.75x - 0.5"));
+    // Complex roots `±1.1i` outside
+    assert!(!stable("x^2 + 1.21"));
+
+    let integer = Polynomial::<i32>::try_from("6x^2 - 5x + 1").unwrap();
+    assert!(integer.is_schur_stable());
+    assert!(!integer.is_hurwitz_stable());
+}
+
+#[test]
+fn recurrences() {
+    let fibonacci = Polynomial::<f64>::characteristic_of_recurrence(&[1.0, 1.0]);
+    assert_eq!(fibonacci, Polynomial::try_from("x^2 - x - 1").unwrap());
+
+    let closed = fibonacci.solve_recurrence(&[0.0, 1.0]).unwrap();
+    let sqrt5 = 5f64.sqrt();
+    assert_eq!(closed.terms().len(), 2);
+    assert!((closed.terms()[1].0 - (1.0 + sqrt5) / 2.0).abs() < 1e-12);
+    assert!((closed.terms()[1].1[0] - 1.0 / sqrt5).abs() < 1e-12);
+    let (mut a, mut b) = (0f64, 1f64);
+    for n in 0..40 {
+        assert!((closed.eval(n) - a).abs() < 1e-6 * a.max(1.0));
+        (a, b) = (b, a + b);
+    }
+
+    // Repeated root, a_n = (1 + n) 2^n
+    let poly = Polynomial::<i64>::characteristic_of_recurrence(&[4, -4]);
+    let closed = poly.solve_recurrence(&[1, 4]).unwrap();
+    assert_eq!(closed.terms(), &[(2.0, vec![1.0, 1.0])]);
+    assert_eq!(closed.eval(5), 192.0);
+
+    // Triple root and a simple one, a_n = n^2 - (-1)^n
+    let poly = Polynomial::<i64>::characteristic_of_recurrence(&[2, 0, -2, 1]);
+    assert_eq!(poly, Polynomial::try_from("x^4 - 2x^3 + 2x - 1").unwrap());
+    let initial: Vec<i64> = (0..4i64).map(|n| n * n - (-1i64).pow(n as u32)).collect();
+    let closed = poly.solve_recurrence(&initial).unwrap();
+    for n in 0..20u32 {
+        let expected = (n * n) as f64 - (-1f64).powi(n as i32);
+        assert!((closed.eval(n) - expected).abs() < 1e-9);
+    }
+
+    // Wrong number of initial terms, complex roots of `a_n = -a_(n-2)`
+    assert_eq!(poly.solve_recurrence(&[1, 2]), None);
+    let rotation = Polynomial::<i64>::characteristic_of_recurrence(&[0, -1]);
+    assert_eq!(rotation.solve_recurrence(&[1, 0]), None);
+}
+
+#[test]
+fn power_sums_newton() {
+    let poly = Polynomial::<i64>::try_from("x^4 - 10x^3 + 35x^2 - 50x + 24").unwrap();
+    let expected: Vec<i64> = (1..=6u32)
+        .map(|k| (1..=4i64).map(|r| r.pow(k)).sum())
+        .collect();
+
+    assert_eq!(poly.power_sums(6), Ok(expected.clone()));
+    assert_eq!(poly.elementary_symmetric(), Ok(vec![10, 35, 50, 24]));
+    assert_eq!(Polynomial::from_power_sums(&expected[..4]), Ok(poly));
+
+    // Complex roots `±i`, and a repeated root counted twice
+    let circle = Polynomial::<i64>::try_from("x^2 + 1").unwrap();
+    assert_eq!(circle.power_sums(4), Ok(vec![0, -2, 0, 2]));
+    let square = Polynomial::<i64>::try_from("x^2 - 6x + 9").unwrap();
+    assert_eq!(square.power_sums(3), Ok(vec![6, 18, 54]));
+
+    // Non monic, roots 0.5 and -1
+    let float = Polynomial::<f64>::try_from("2x^2 + x - 1").unwrap();
+    assert_eq!(float.power_sums(3), Ok(vec![-0.5, 1.25, -0.875]));
+    assert_eq!(float.elementary_symmetric(), Ok(vec![-0.5, -0.5]));
+
+    assert_eq!(
+        Polynomial::<i64>::from_power_sums(&[]),
+        Polynomial::try_from("1")
+    );
+    assert_eq!(
+        Polynomial::<i64>::try_from("7").unwrap().power_sums(2),
+        Ok(vec![0, 0])
+    );
+    assert!(Polynomial::<i64>::zero().power_sums(2).is_err());
+    assert!(Polynomial::<i64>::try_from("x^-1")
+        .unwrap()
+        .power_sums(2)
+        .is_err());
+}
+
+#[test]
+fn xpow_mod_op() {
+    let modulus = Polynomial::<i64>::try_from("x^3 - 2x + 5").unwrap();
+    for n in 0..20u64 {
+        let power = Polynomial::builder().term(1, n as i32).build();
+        let (_, expected) = power / modulus.clone();
+        assert_eq!(Polynomial::xpow_mod(n, &modulus), Ok(expected), "n = {n}");
+    }
+
+    let fibonacci = Polynomial::<i64>::try_from("x^2 - x - 1").unwrap();
+    assert_eq!(
+        Polynomial::xpow_mod(90, &fibonacci),
+        Ok(Polynomial::from_coeffs(&[
+            1779979416004714189,
+            2880067194370816120
+        ]))
+    );
+
+    // Huge exponents on cyclic moduli
+    let cyclic = Polynomial::<f64>::try_from("x^3 - 1").unwrap();
+    assert_eq!(
+        Polynomial::xpow_mod(u64::MAX, &cyclic),
+        Ok(Polynomial::try_from("1").unwrap())
+    );
+    assert_eq!(
+        Polynomial::xpow_mod(u64::MAX - 1, &cyclic),
+        Ok(Polynomial::try_from("x^2").unwrap())
+    );
+    let halves = Polynomial::<f64>::try_from("2x^2 - 2").unwrap();
+    assert_eq!(
+        Polynomial::xpow_mod(1 << 40, &halves),
+        Ok(Polynomial::try_from("1").unwrap())
+    );
+
+    // `49 * (1 / 49)` rounds below one, but 49 is invertible: `x^5 = x / 2401`
+    let rounded = Polynomial::<f64>::try_from("49x^2 + 1").unwrap();
+    let power = Polynomial::xpow_mod(5, &rounded).unwrap();
+    assert_ne!(49f64 * (1f64 / 49f64), 1f64);
+    assert_eq!(power.max_exp().get_exp(), 1);
+    assert!((power.max_exp().get_value() * 2401f64 - 1f64).abs() < 1e-12);
+
+    assert!(Polynomial::xpow_mod(3, &Polynomial::<i64>::try_from("5").unwrap()).is_err());
+    assert!(Polynomial::xpow_mod(3, &Polynomial::<i64>::try_from("2x^2 + 1").unwrap()).is_err());
+    assert!(Polynomial::xpow_mod(3, &Polynomial::<i64>::try_from("x^2 + x^-1").unwrap()).is_err());
+}
+
+#[test]
+fn hensel_lifting() {
+    let poly = |s: &str| Polynomial::<i64>::try_from(s).unwrap();
+    let congruent = |a: &Polynomial<i64>, b: &Polynomial<i64>, m: i64| {
+        let [a, b] = [a, b].map(|p| p.to_coeffs().unwrap());
+        (0..a.len().max(b.len()))
+            .all(|i| (a.get(i).unwrap_or(&0) - b.get(i).unwrap_or(&0)).rem_euclid(m) == 0)
+    };
+
+    // x^4 - 1 ≡ (x - 1)(x^3 + x^2 + x + 1) modulo 3, lifted to 3^10
+    let f = poly("x^4 - 1");
+    let (g0, h0) = (poly("x + 2"), poly("x^3 + x^2 + x + 1"));
+    let (g, h) = f.hensel_lift(&g0, &h0, 3, 10).unwrap();
+    let modulus = 3i64.pow(10);
+    assert!(congruent(&(g.clone() * h.clone()), &f, modulus));
+    assert!(congruent(&g, &g0, 3) && congruent(&h, &h0, 3));
+    assert!(g.is_monic());
+    assert!(g
+        .to_coeffs()
+        .unwrap()
+        .iter()
+        .all(|c| (0..modulus).contains(c)));
+    // The integer factor `x - 1` is recovered exactly
+    assert_eq!(g, poly(&format!("x + {}", modulus - 1)));
+
+    // Non monic second factor, 6x^2 + 7x - 5 = (2x - 1)(3x + 5) ≡ (x + 3)(6x + 3) modulo 7
+    let f = poly("6x^2 + 7x - 5");
+    let (g, h) = f
+        .hensel_lift(&poly("x + 3"), &poly("6x + 3"), 7, 6)
+        .unwrap();
+    assert!(congruent(&(g.clone() * h.clone()), &f, 7i64.pow(6)));
+    assert_eq!(g.to_coeffs().unwrap().len(), 2);
+    assert_eq!(h.to_coeffs().unwrap().len(), 2);
+
+    // k = 1 only reduces
+    assert_eq!(
+        f.hensel_lift(&poly("x + 10"), &poly("6x - 4"), 7, 1),
+        Ok((poly("x + 3"), poly("6x + 3")))
+    );
+
+    let (g, h) = (poly("x + 3"), poly("6x + 3"));
+    assert!(f.hensel_lift(&g, &h, 6, 2).is_err());
+    assert!(f.hensel_lift(&g, &h, 7, 0).is_err());
+    assert!(f.hensel_lift(&g, &h, 7, 40).is_err());
+    assert!(f
+        .hensel_lift(&poly("2x + 6"), &poly("3x + 6"), 7, 2)
+        .is_err());
+    assert!(f.hensel_lift(&g, &poly("6x + 4"), 7, 2).is_err());
+    // x^2 ≡ x x is not coprime
+    assert!(poly("x^2 + 5")
+        .hensel_lift(&poly("x"), &poly("x"), 5, 2)
+        .is_err());
+}
+
+#[test]
+fn subresultant_gcd() {
+    let poly = |s: &str| Polynomial::<i64>::try_from(s).unwrap();
+
+    let p = poly("x^8 + x^6 - 3x^4 - 3x^3 + 8x^2 + 2x - 5");
+    let q = poly("3x^6 + 5x^4 - 4x^2 - 9x + 21");
+    let prs = q.subresultant_prs(&p).unwrap();
+    assert_eq!(prs.len(), 6);
+    assert_eq!(prs[0], p);
+    assert_eq!(prs[5], poly("260708"));
+    assert_eq!(p.gcd(&q), Ok(poly("1")));
+
+    // Common factor (x + 2)(2x + 3) with contents 6 and 4
+    let common = poly("2x^2 + 7x + 6");
+    let a = common.clone() * poly("6x - 6");
+    let b = common.clone() * poly("4x^2 + 4");
+    assert_eq!(a.gcd(&b), Ok(common.clone() * poly("2")));
+    assert_eq!(b.gcd(&a), a.gcd(&b));
+    assert_eq!((-a.clone()).gcd(&b), a.gcd(&b));
+
+    // Repeated factors and powers
+    let square = common.clone() * common.clone();
+    assert_eq!(
+        square.gcd(&(common.clone() * poly("x + 2"))),
+        Ok(common.clone() * poly("x + 2"))
+    );
+    assert_eq!(poly("x^5 - x").gcd(&poly("x^3 + x^2")), Ok(poly("x^2 + x")));
+
+    // Zero and constants
+    assert_eq!(
+        a.gcd(&Polynomial::zero()),
+        Ok(a.primitive_part() * poly("6"))
+    );
+    assert_eq!(
+        Polynomial::<i64>::zero().gcd(&Polynomial::zero()),
+        Ok(Polynomial::zero())
+    );
+    assert_eq!(a.gcd(&poly("-9")), Ok(poly("3")));
+    assert!(a.gcd(&poly("x^-1")).is_err());
+
+    assert_eq!(poly("-6x^2 + 4x - 2").content(), 2);
+    assert_eq!(
+        poly("-6x^2 + 4x - 2").primitive_part(),
+        poly("3x^2 - 2x + 1")
+    );
+    assert_eq!(Polynomial::<i64>::zero().content(), 0);
+}
+
+#[test]
+fn coefficient_size() {
+    let poly = Polynomial::<i64>::try_from("-1000x^5 + 3x^2 - 7x + 1").unwrap();
+
+    assert_eq!(poly.height(), 1000);
+    assert_eq!(poly.coeff_l1_norm(), 1011);
+    assert!((poly.coeff_l2_norm().unwrap() - 1_000_059f64.sqrt()).abs() < 1e-9);
+    assert_eq!(poly.max_coeff_bits(), 10);
+
+    // The norms bound each other, `height <= l2 <= l1`
+    assert!(poly.height() as f64 <= poly.coeff_l2_norm().unwrap());
+    assert!(poly.coeff_l2_norm().unwrap() <= poly.coeff_l1_norm() as f64);
+
+    let zero = Polynomial::<i64>::zero();
+    assert_eq!((zero.height(), zero.coeff_l1_norm()), (0, 0));
+    assert_eq!(zero.coeff_l2_norm(), Some(0.0));
+    assert_eq!(zero.max_coeff_bits(), 0);
+
+    assert_eq!(
+        Polynomial::<i64>::try_from("x").unwrap().max_coeff_bits(),
+        1
+    );
+    let big = Polynomial::<i128>::try_from("-170141183460469231731687303715884105727x").unwrap();
+    assert_eq!(big.max_coeff_bits(), 127);
+
+    let float = Polynomial::<f64>::try_from("0.5x^2 - 2.5").unwrap();
+    assert_eq!(float.height(), 2.5);
+    assert_eq!(float.coeff_l1_norm(), 3.0);
+    assert_eq!(float.max_coeff_bits(), 2);
+    assert_eq!(
+        Polynomial::<f64>::try_from("1e300x")
+            .unwrap()
+            .max_coeff_bits(),
+        997
+    );
+}
+
+#[test]
+fn mahler_and_root_bounds() {
+    let poly = |s: &str| Polynomial::<i64>::try_from(s).unwrap();
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9 * b.max(1.0);
+
+    // Cyclotomic polynomials have measure one, roots at zero don't count
+    assert!(close(
+        poly("x^4 + x^3 + x^2 + x + 1").mahler_measure().unwrap(),
+        1.0
+    ));
+    assert!(close(poly("x^3 - x").mahler_measure().unwrap(), 1.0));
+    // Roots 1/√2 inside the circle, only the leading coefficient is left
+    assert!(close(poly("2x^2 - 1").mahler_measure().unwrap(), 2.0));
+    // Roots φ² and φ⁻²
+    let golden = (1.0 + 5f64.sqrt()) / 2.0;
+    assert!(close(
+        poly("x^2 - 3x + 1").mahler_measure().unwrap(),
+        golden * golden
+    ));
+    // Complex roots `1 ± 2i` and a real root 3 with leading coefficient -2
+    let measure = poly("-2x^3 + 10x^2 - 22x + 30").mahler_measure().unwrap();
+    assert!(close(measure, 2.0 * 5.0 * 3.0));
+    assert!(close(poly("-7").mahler_measure().unwrap(), 7.0));
+    assert_eq!(Polynomial::<i64>::zero().mahler_measure(), None);
+
+    // Bounds enclose every root, including complex ones
+    for (p, smallest, largest) in [
+        (poly("x^2 - 5x + 6"), 2.0, 3.0),
+        (poly("-2x^3 + 10x^2 - 22x + 30"), 5f64.sqrt(), 3.0),
+        (poly("x^4 - 16"), 2.0, 2.0),
+        (poly("100x^2 + 1"), 0.1, 0.1),
+    ] {
+        assert!(p.max_root_magnitude_bound().unwrap() >= largest - 1e-12);
+        assert!(p.min_root_magnitude_bound().unwrap() <= smallest + 1e-12);
+    }
+
+    assert_eq!(poly("x^3 - x").min_root_magnitude_bound(), Some(0.0));
+    assert_eq!(poly("5").max_root_magnitude_bound(), None);
+    assert_eq!(poly("5").min_root_magnitude_bound(), None);
+    assert_eq!(poly("x^-1 + 1").max_root_magnitude_bound(), None);
+}
+
+#[test]
+fn balanced_root_finding() {
+    // Roots 1.1e-11 and 9.09e-14, tiny against the leading coefficient
+    let tiny = Polynomial::<f64>::from_coeffs(&[1e-24, -1.1e-11, 1.0]);
+    let roots = tiny.roots_in_interval(-1.0, 1.0).unwrap();
+    let disc = (1.1e-11f64 * 1.1e-11 - 4e-24).sqrt();
+    let expected = [(1.1e-11 - disc) / 2.0, (1.1e-11 + disc) / 2.0];
+    assert_eq!(roots.len(), 2);
+    for (root, expected) in roots.iter().zip(expected) {
+        assert!((root - expected).abs() < 1e-12 * expected);
+    }
+    assert_eq!(tiny.count_roots_in(0.0, 1e-12), Ok(1));
+
+    // Huge roots `±1e15` and `±2e15`
+    let huge = Polynomial::<f64>::from_coeffs(&[4e60, 0.0, -5e30, 0.0, 1.0]);
+    let roots = huge.roots_in_interval(-1e16, 1e16).unwrap();
+    assert_eq!(roots.len(), 4);
+    for (root, expected) in roots.iter().zip([-2e15, -1e15, 1e15, 2e15]) {
+        assert!((root - expected).abs() < 1e-12 * expected.abs());
+    }
+    assert!((huge.first_positive_root().unwrap() - 1e15).abs() < 1e3);
+
+    let odd = Polynomial::<f64>::try_from("x^13 + 150").unwrap();
+    let roots = odd.roots_in_interval(-10.0, 10.0).unwrap();
+    assert_eq!(roots, vec![-(150f64.powf(1.0 / 13.0))]);
+
+    // The measure of a tiny root scaled far from one
+    let scaled = Polynomial::<f64>::from_coeffs(&[-1e-30, 0.0, 1.0]);
+    assert!((scaled.mahler_measure().unwrap() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn eval_compensated_op() {
+    // (x - 1)^7 expanded, catastrophic cancellation near the root
+    let poly = Polynomial::<f64>::from_coeffs(&[-1.0, 7.0, -21.0, 35.0, -35.0, 21.0, -7.0, 1.0]);
+    for x in [1.001, 0.999, 1.01, 0.995] {
+        let exact = (x - 1f64).powi(7);
+        assert!(
+            (poly.eval_compensated(x) - exact).abs() <= 1e-6 * exact.abs(),
+            "x = {x}"
+        );
+    }
+
+    // Same result as `eval` away from the roots
+    let poly = Polynomial::<f64>::try_from("0.5x^3 - 2x + 7").unwrap();
+    for x in [-3.0, 0.0, 0.25, 10.0] {
+        assert_eq!(poly.eval_compensated(x), poly.eval(x));
+    }
+
+    // Negative exponents and sparse terms
+    let laurent = Polynomial::<f64>::try_from("x^3 + 2x^-2").unwrap();
+    assert_eq!(laurent.eval_compensated(2.0), 8.5);
+    assert_eq!(Polynomial::<f64>::zero().eval_compensated(3.0), 0.0);
+    assert_eq!(
+        Polynomial::<f64>::try_from("4")
+            .unwrap()
+            .eval_compensated(3.0),
+        4.0
+    );
+}
+
+#[test]
+fn shader_codegen() {
+    let poly = Polynomial::<i32>::try_from("2x^2 - x + 5").unwrap();
+    assert_eq!(poly.to_glsl().unwrap(), "5.0 + x * (-1.0 + x * 2.0)");
+    assert_eq!(poly.to_wgsl().unwrap(), "5.0f + x * (-1.0f + x * 2.0f)");
+    assert_eq!(poly.to_uniform_coeffs(), Ok(vec![[5.0, -1.0, 2.0, 0.0]]));
+
+    let constant = Polynomial::<f64>::try_from("-1.5").unwrap();
+    assert_eq!(constant.to_glsl().unwrap(), "-1.5");
+    assert_eq!(Polynomial::<f64>::zero().to_wgsl().unwrap(), "0.0f");
+    assert_eq!(Polynomial::<f64>::zero().to_uniform_coeffs(), Ok(vec![]));
+
+    let leading_negative = Polynomial::<i32>::try_from("-x^2 + 3").unwrap();
+    assert_eq!(
+        leading_negative.to_glsl().unwrap(),
+        "3.0 + x * (x * (-1.0))"
+    );
+
+    assert!(Polynomial::<i32>::try_from("x^-1")
+        .unwrap()
+        .to_glsl()
+        .is_err());
+    assert!(Polynomial::<f64>::from_coeffs(&[1e300]).to_wgsl().is_err());
+}
+
+#[test]
+fn compiled_evaluators() {
+    for poly in [
+        "0",
+        "7",
+        "3x - 1",
+        "x^2 + 1",
+        "2x^3 - x",
+        "x^6 - 3x^4 + x + 2",
+        "x^2 + 3x^-1",
+    ] {
+        let poly = Polynomial::<f64>::try_from(poly).unwrap();
+        let f = poly.compile();
+        for x in [-2.0, -0.5, 1.0, 3.0] {
+            assert_eq!(f(x), poly.eval(x), "{poly} at {x}");
+        }
+    }
+
+    let poly = Polynomial::<f64>::try_from("0.25x^3 - x").unwrap();
+    assert_eq!(
+        poly.to_rust_const_fn("cubic").unwrap(),
+        "pub const fn cubic(x: f64) -> f64 {\n    x * (-1f64 + x * (x * 0.25f64))\n}\n"
+    );
+    assert_eq!(
+        Polynomial::<i32>::zero().to_rust_const_fn("zero").unwrap(),
+        "pub const fn zero(x: i32) -> i32 {\n    0i32\n}\n"
+    );
+    assert!(Polynomial::<i32>::try_from("x^-2")
+        .unwrap()
+        .to_rust_const_fn("f")
+        .is_err());
+}
+
+#[test]
+fn footprint_introspection() {
+    let mut poly =
+        Polynomial::<i64>::try_from("x^7 + x^6 + x^5 + x^4 + x^3 + x^2 + x + 1").unwrap();
+    assert_eq!(poly.term_count(), 8);
+    assert_eq!(poly.density(), 1.0);
+
+    poly.truncate(2);
+    let before = poly.memory_bytes();
+    poly.shrink_to_fit();
+    assert_eq!(poly.term_count(), 2);
+    assert_eq!(poly.density(), 1.0);
+    assert!(poly.memory_bytes() < before);
+
+    let sparse = Polynomial::<i32>::try_from("x^99 + 1").unwrap();
+    assert_eq!(sparse.density(), 0.02);
+
+    let laurent = Polynomial::<i32>::try_from("x + x^-2").unwrap();
+    assert_eq!(laurent.density(), 0.5);
+
+    assert_eq!(Polynomial::<i32>::zero().term_count(), 0);
+    assert_eq!(Polynomial::<i32>::zero().density(), 0.0);
+}
+
+#[test]
+fn long_division_rendering() {
+    let dividend = Polynomial::<i32>::try_from("x^2 - 1").unwrap();
+    let divisor = Polynomial::<i32>::try_from("x - 1").unwrap();
+    let expected = [
+        "              x + 1",
+        "        -----------",
+        "x - 1 ) x^2     - 1",
+        "      - x^2 - x",
+        "        -------",
+        "              x - 1",
+        "      -       x - 1",
+        "              -----",
+        "                  0",
+    ];
+    assert_eq!(
+        dividend.long_division_worksheet(&divisor),
+        Ok(expected.join("\n"))
+    );
+
+    // Nothing to divide, the dividend is the remainder
+    let small = Polynomial::<i32>::try_from("x").unwrap();
+    let big = Polynomial::<i32>::try_from("x^2 + 1").unwrap();
+    assert_eq!(
+        small.long_division_worksheet(&big),
+        Ok(["          0", "          -", "x^2 + 1 ) x"].join("\n"))
+    );
+
+    assert!(small.long_division_worksheet(&Polynomial::zero()).is_err());
+}
+
+#[test]
+fn stable_content_hash() {
+    // Pinned, the hash must not change between versions
+    let poly = Polynomial::<i64>::try_from("x^2 + 3x - 1").unwrap();
+    assert_eq!(poly.content_hash(), 0xa93a_a014_7826_5bc4);
+    assert_eq!(
+        Polynomial::<i64>::zero().content_hash(),
+        0xcbf2_9ce4_8422_2325
+    );
+
+    // Same terms, different construction, type or variable
+    let built = Polynomial::<i32>::builder()
+        .term(-1, 0)
+        .term(3, 1)
+        .term(1, 2)
+        .var('t')
+        .build();
+    assert_eq!(built.content_hash(), poly.content_hash());
+    assert_eq!(
+        Polynomial::<i64>::try_from("x^2 + x + 2x - 1")
+            .unwrap()
+            .content_hash(),
+        poly.content_hash()
+    );
+
+    // Floats by their bits, NaN canonicalized
+    let half = Polynomial::<f64>::try_from("0.5x").unwrap();
+    assert_ne!(
+        half.content_hash(),
+        Polynomial::<f64>::try_from("0.25x").unwrap().content_hash()
+    );
+    let nan = Polynomial::<f64>::from_coeffs(&[f64::NAN]);
+    let other_nan = Polynomial::<f64>::from_coeffs(&[-f64::NAN]);
+    assert_eq!(nan.content_hash(), other_nan.content_hash());
+
+    assert_ne!(
+        Polynomial::<i32>::try_from("x").unwrap().content_hash(),
+        Polynomial::<i32>::try_from("x^-1").unwrap().content_hash()
+    );
+}
+
+#[test]
+fn graded_lex_order() {
+    use std::collections::BTreeMap;
+
+    let p = |s: &str| Polynomial::<i64>::try_from(s).unwrap();
+
+    assert!(p("0") < p("-7"));
+    assert!(p("-7") < p("x - 100"));
+    assert!(p("x^2 - 5") < p("x^2 + x - 9"));
+    assert!(p("x^2 + x - 9") < p("x^2 + x"));
+    assert!(p("3x^2") > p("2x^2 + x"));
+    assert!(p("x^-1") < p("1"));
+    assert_eq!(p("x^2 + 1").cmp(&p("1 + x^2")), std::cmp::Ordering::Equal);
+
+    let mut roots = BTreeMap::new();
+    for poly in ["x^2 - 1", "x - 2", "x^2 - 4"] {
+        roots.insert(p(poly), p(poly).roots());
+    }
+    let keys: Vec<String> = roots.keys().map(|k| k.to_string()).collect();
+    assert_eq!(keys, ["x - 2", "x^2 - 4", "x^2 - 1"]);
+}
+
+#[test]
+fn checked_division() {
+    let p = Polynomial::<i32>::try_from("x^3 - 1").unwrap();
+    let q = Polynomial::<i32>::try_from("x - 1").unwrap();
+    assert_eq!(p.checked_div(&q), Ok(p.clone() / q.clone()));
+    assert_eq!(
+        p.checked_div(&Polynomial::zero()),
+        Err(DivError::DivisionByZero)
+    );
+
+    // Constant divisors and zero dividends terminate
+    let two = Polynomial::<i32>::try_from("2").unwrap();
+    let (quotient, remainder) = Polynomial::<i32>::try_from("2x + 4")
+        .unwrap()
+        .checked_div(&two)
+        .unwrap();
+    assert_eq!(format!("{quotient}"), "x + 2");
+    assert!(remainder.is_zero());
+    assert_eq!(
+        Polynomial::zero().checked_div(&two),
+        Ok((Polynomial::zero(), Polynomial::zero()))
+    );
+
+    // Laurent division, the remainder has lower degree than the divisor
+    let laurent = Polynomial::<f64>::try_from("x + x^-2").unwrap();
+    let divisor = Polynomial::<f64>::try_from("x + 1").unwrap();
+    let (quotient, remainder) = laurent.checked_div(&divisor).unwrap();
+    assert_eq!(format!("{quotient}"), "1");
+    assert_eq!(format!("{remainder}"), "-1 + x^-2");
+    assert!(remainder.max_exp().get_exp() < divisor.max_exp().get_exp());
+
+    let far = Polynomial::<i32>::builder().term(1, i32::MIN + 1).build();
+    let big = Polynomial::<i32>::builder()
+        .term(1, i32::MAX)
+        .term(1, 0)
+        .build();
+    assert_eq!(far.checked_div(&big), Err(DivError::ExponentOverflow));
+
+    assert_eq!(
+        Monomial::new(4.0, 2).checked_div(Monomial::new(0.0, 1)),
+        Err(DivError::DivisionByZero)
+    );
+    assert_eq!(
+        Monomial::new(4, i32::MIN).checked_div(Monomial::new(1, 1)),
+        Err(DivError::ExponentOverflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "divide by a zero polynomial")]
+fn division_by_zero_panics() {
+    let _ = Polynomial::<f64>::try_from("x").unwrap() / Polynomial::zero();
+}
+
+#[test]
+#[should_panic(expected = "exponent overflow in the quotient")]
+fn division_exponent_overflow_panics() {
+    let p = Polynomial::<i32>::try_from("x^2147483647").unwrap();
+    let _ = p / Polynomial::try_from("x^-1").unwrap();
+}
+
+#[test]
+fn scalar_and_term_constructors() {
+    assert_eq!(
+        format!("{}", Polynomial::from(vec![1, -2, 0, 5])),
+        "x^3 - 2x^2 + 5"
+    );
+    assert_eq!(Polynomial::from(Vec::<i32>::new()), Polynomial::zero());
+    assert_eq!(Polynomial::from(vec![0, 0, 3]), Polynomial::constant(3));
+
+    assert_eq!(format!("{}", Polynomial::from(2.5)), "2.5");
+    assert_eq!(Polynomial::from(0i64), Polynomial::zero());
+    assert_eq!(Polynomial::constant(-4).max_exp(), Monomial::new(-4, 0));
+
+    assert_eq!(format!("{}", Polynomial::from((3, -2))), "3x^-2");
+    assert_eq!(Polynomial::from((0, 5)), Polynomial::<i32>::zero());
+}
+
+#[test]
+fn unchecked_canonical_construction() {
+    let mono_vec = vec![
+        Monomial::new(4, 3),
+        Monomial::new(-1, 1),
+        Monomial::new(7, -2),
+    ];
+    let poly = Polynomial::from_sorted_monomials_unchecked(mono_vec.clone());
+
+    assert_eq!(poly, Polynomial::new(mono_vec));
+    assert_eq!(format!("{poly}"), "4x^3 - x + 7x^-2");
+    assert_eq!(
+        Polynomial::<i32>::from_sorted_monomials_unchecked(Vec::new()),
+        Polynomial::zero()
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "strictly decreasing exponents")]
+fn unchecked_construction_is_checked_in_debug() {
+    Polynomial::from_sorted_monomials_unchecked(vec![Monomial::new(1, 0), Monomial::new(1, 2)]);
+}
+
+#[test]
+fn alternate_and_precision_display() {
+    let poly: Polynomial<i32> = Polynomial::try_from("-x^3 + 4x - 1 + 2x^-2").unwrap();
+    assert_eq!(format!("{poly:#}"), "-1*x^3+4*x^1-1*x^0+2*x^-2");
+    assert_eq!(Polynomial::try_from(format!("{poly:#}").as_str()), Ok(poly));
+
+    let float: Polynomial<f64> = Polynomial::try_from("0.125x^2 - 2.5").unwrap();
+    assert_eq!(format!("{float:.1}"), "0.1x^2 - 2.5");
+    assert_eq!(format!("{float:#.3}"), "0.125*x^2-2.500*x^0");
+    assert_eq!(format!("{float:6.2}"), "  0.12x^2 -   2.50");
+
+    assert_eq!(format!("{:#}", Polynomial::<i32>::zero()), "0");
+    assert_eq!(format!("{:#}", Monomial::new(-3, 0)), "-3*x^0");
+    assert_eq!(
+        format!("{}", Polynomial::<i32>::try_from("3 * x^2").unwrap()),
+        "3x^2"
+    );
+}
+
+#[test]
+fn exponent_overflow_checks() {
+    let big = Polynomial::from((1i64, i32::MAX - 1));
+    let x = Polynomial::from((1i64, 1));
+
+    assert_eq!(big.checked_mul(&x), Some(Polynomial::from((1, i32::MAX))));
+    assert_eq!(big.checked_mul(&(x.clone() * x)), None);
+    assert_eq!(
+        Polynomial::from((1i64, i32::MIN)).checked_mul(&Polynomial::from((1, -1))),
+        None
+    );
+    assert_eq!(
+        big.checked_mul(&Polynomial::zero()),
+        Some(Polynomial::zero())
+    );
+}
+
+#[test]
+#[should_panic(expected = "exponent overflow")]
+fn exponent_overflow_panics() {
+    let _ = Monomial::new(1, i32::MAX) * Monomial::new(1, 1);
+}
+
+#[test]
+fn chebyshev_degree_reduction() {
+    let max_error = |p: &Polynomial<f64>, q: &Polynomial<f64>, (a, b): (f64, f64)| {
+        (0..=1000)
+            .map(|i| a + (b - a) * i as f64 / 1000.0)
+            .map(|x| (p.eval(x) - q.eval(x)).abs())
+            .fold(0.0, f64::max)
+    };
+
+    // `x^3 = (T_3 + 3T_1) / 4`, dropping `T_3` leaves an error of 1/4
+    let cube: Polynomial<f64> = Polynomial::try_from("x^3").unwrap();
+    let linear = cube.reduce_degree(1, (-1.0, 1.0)).unwrap();
+    assert_eq!(format!("{linear}"), "0.75x");
+    assert!((max_error(&cube, &linear, (-1.0, 1.0)) - 0.25).abs() < 1e-12);
+
+    // Away from the origin the interval is mapped before economizing
+    let poly: Polynomial<f64> = Polynomial::try_from("x^5 - 3x^3 + x^2 - 2").unwrap();
+    let reduced = poly.reduce_degree(3, (1.0, 3.0)).unwrap();
+    assert_eq!(reduced.max_exp().get_exp(), 3);
+    let truncated = Polynomial::new(
+        (&poly)
+            .into_iter()
+            .filter(|m| m.get_exp() <= 3)
+            .copied()
+            .collect(),
+    );
+    assert!(
+        max_error(&poly, &reduced, (1.0, 3.0)) < max_error(&poly, &truncated, (1.0, 3.0)) / 10.0
+    );
+
+    assert_eq!(poly.reduce_degree(7, (0.0, 1.0)), Ok(poly.clone()));
+    assert!(poly.reduce_degree(2, (1.0, 1.0)).is_err());
+    assert!(poly.reduce_degree(-1, (0.0, 1.0)).is_err());
+}
+
+#[test]
+fn stationary_spectrum_matches_separate_solves() {
+    let distinct = |roots: Option<Vec<i64>>| {
+        roots.map(|mut r| {
+            r.sort();
+            r.dedup();
+            r
+        })
+    };
+
+    for expr in [
+        "x^5 - 5x^4 + 5x^3",
+        "x^4 - 4x^3",
+        "3x^2 + 6x - 1",
+        "x^6 - 3x^5",
+    ] {
+        let poly: Polynomial<i64> = Polynomial::try_from(expr).unwrap();
+        let spectrum = poly.stationary_point_spectrum();
+        let first = poly.derivative();
+
+        assert_eq!(spectrum.first, distinct(first.roots()), "{expr}");
+        assert_eq!(
+            spectrum.second,
+            distinct(first.derivative().roots()),
+            "{expr}"
+        );
+        assert_eq!(
+            spectrum.third,
+            distinct(first.derivative().derivative().roots()),
+            "{expr}"
+        );
+    }
+
+    let linear = Polynomial::<i64>::try_from("2x + 1")
+        .unwrap()
+        .stationary_point_spectrum();
+    assert_eq!(
+        (linear.first, linear.second, linear.third),
+        (None, None, None)
+    );
+}
+
+#[test]
+fn global_minimum_of_bounded_polynomials() {
+    let min = |expr: &str| Polynomial::<f64>::try_from(expr).unwrap().global_minimum();
+
+    assert_eq!(min("x^2 - 4x + 1"), Some((2.0, -3.0)));
+    assert_eq!(min("7"), Some((0.0, 7.0)));
+    assert_eq!(min("0"), Some((0.0, 0.0)));
+
+    // Two wells, the deeper one wins
+    let (x, value) = min("x^4 - 8x^2 + x").unwrap();
+    let poly: Polynomial<f64> = Polynomial::try_from("x^4 - 8x^2 + x").unwrap();
+    assert!(x < 0.0);
+    for i in -400..=400 {
+        assert!(poly.eval(i as f64 / 100.0) >= value - 1e-12);
+    }
+
+    for unbounded in ["x^3", "-x^4 + x", "2x + 1", "x^2 + x^-2"] {
+        assert_eq!(min(unbounded), None, "{unbounded}");
+    }
+}
+
+#[test]
+fn sum_of_squares_certificates() {
+    let poly = |expr: &str| Polynomial::<f64>::try_from(expr).unwrap();
+
+    for expr in [
+        "x^2 + 1",
+        "x^4 - 2x^3 + x^2",
+        "x^6 - 2x^4 + x^2 + 1",
+        "x^8 + 1",
+        "x^4 - 10x^3 + 37x^2 - 60x + 36",
+        "x^6 + x^4",
+        "3",
+    ] {
+        let p = poly(expr);
+        assert!(p.is_globally_nonnegative(), "{expr}");
+
+        let (q1, q2) = p.sos_decomposition().unwrap();
+        let sum = q1.clone() * q1 + q2.clone() * q2;
+        for x in [-2.5, -1.0, 0.0, 0.5, 3.0] {
+            assert!(
+                (sum.eval(x) - p.eval(x)).abs() <= 1e-6 * p.eval(x).abs().max(1.0),
+                "{expr}"
+            );
+        }
+    }
+
+    for expr in [
+        "x^4 - 2x^3",
+        "x^3 + 5",
+        "x^4 - x^2",
+        "-x^2 - 1",
+        "-2",
+        "x^2 - 1e-9",
+        "x^-2",
+    ] {
+        assert!(!poly(expr).is_globally_nonnegative(), "{expr}");
+        assert_eq!(poly(expr).sos_decomposition(), None, "{expr}");
+    }
+}
+
+#[test]
+fn gauss_legendre_quadrature() {
+    assert_eq!(
+        format!("{}", Polynomial::<f64>::legendre(4)),
+        "4.375x^4 - 3.75x^2 + 0.375"
+    );
+    assert_eq!(Quadrature::gauss_legendre(0), (Vec::new(), Vec::new()));
+
+    // Exact up to degree 2n - 1, compared with the antiderivative
+    let poly: Polynomial<f64> = Polynomial::try_from("3x^9 - x^8 + 2x^5 + 7x^2 - x + 4").unwrap();
+    let antiderivative = poly.integral().unwrap();
+    let exact = antiderivative.eval(1.0) - antiderivative.eval(-1.0);
+    for n in [5, 8, 64] {
+        let (nodes, weights) = Quadrature::gauss_legendre(n);
+        assert_eq!(nodes.len(), n as usize);
+        assert!(nodes.windows(2).all(|w| w[0] < w[1]));
+
+        let integral: f64 = nodes
+            .iter()
+            .zip(&weights)
+            .map(|(x, w)| w * poly.eval(*x))
+            .sum();
+        assert!((integral - exact).abs() < 1e-12, "{n}");
+    }
+
+    let (nodes, _) = Quadrature::gauss_legendre(2);
+    assert!((nodes[1] - 1.0 / 3f64.sqrt()).abs() < 1e-15);
+}
+
+#[test]
+fn root_sensitivities_predict_perturbations() {
+    let poly: Polynomial<f64> = Polynomial::try_from("x^3 - 2x^2 - x + 2").unwrap();
+    let sensitivities = poly.root_sensitivities().unwrap();
+    assert_eq!(sensitivities.len(), 3);
+
+    // Deterministic noise in [-1, 1], replayed to predict the first order shift
+    let lcg = |state: &mut u64| {
+        *state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    };
+    let (noise, mut state) = (1e-7, 7u64);
+    let mut replay = state;
+    let samples = poly.perturbed_roots(noise, 20, || lcg(&mut state)).unwrap();
+
+    for roots in samples {
+        let deltas: Vec<f64> = (0..4).map(|_| noise * lcg(&mut replay)).collect();
+        assert_eq!(roots.len(), 3);
+        for (root, sensitivity) in roots.iter().zip(&sensitivities) {
+            let predicted: f64 = sensitivity
+                .gradient
+                .iter()
+                .zip(&deltas)
+                .map(|(g, d)| g * d)
+                .sum();
+            assert!((root - sensitivity.root - predicted).abs() < 1e-12);
+        }
+    }
+
+    // The double root at 1 is left out, the simple root at 0 kept
+    let poly: Polynomial<f64> = Polynomial::try_from("x^3 - 2x^2 + x").unwrap();
+    let sensitivities = poly.root_sensitivities().unwrap();
+    assert_eq!(sensitivities.len(), 1);
+    assert_eq!(sensitivities[0].root, 0.0);
+    assert_eq!(sensitivities[0].gradient, vec![-1.0, 0.0, 0.0, 0.0]);
+
+    assert!(Polynomial::<f64>::try_from("x^-1")
+        .unwrap()
+        .root_sensitivities()
+        .is_err());
+    assert_eq!(
+        Polynomial::<f64>::zero().perturbed_roots(1.0, 2, || 1.0),
+        Ok(vec![vec![], vec![]])
+    );
+}
+
+#[test]
+fn euclidean_chain_continued_fraction() {
+    let p: Polynomial<f64> = Polynomial::try_from("2x^5 - x^4 + 3x^2 - 4x + 1").unwrap();
+    let q: Polynomial<f64> = Polynomial::try_from("x^3 + x^2 - 2").unwrap();
+    let chain = p.euclidean_chain(&q);
+    assert_eq!(format!("{}", chain[0]), "2x^2 - 3x + 3");
+
+    // The continued fraction evaluated from the tail gives back p / q
+    for x in [-1.5, 0.25, 3.0] {
+        let value = chain
+            .iter()
+            .rev()
+            .skip(1)
+            .fold(chain.last().unwrap().eval(x), |acc, q| {
+                q.eval(x) + 1.0 / acc
+            });
+        assert!((value - p.eval(x) / q.eval(x)).abs() < 1e-9, "{x}");
+    }
+
+    // Exact quotients over the integers, stopping when 2x doesn't divide x
+    let p: Polynomial<i32> = Polynomial::try_from("x^3 + 2x^2 + x + 1").unwrap();
+    let q: Polynomial<i32> = Polynomial::try_from("x^2 + x").unwrap();
+    let chain: Vec<String> = p
+        .euclidean_chain(&q)
+        .iter()
+        .map(|q| format!("{q}"))
+        .collect();
+    assert_eq!(chain, vec!["x + 1", "x^2 + x"]);
+    let q: Polynomial<i32> = Polynomial::try_from("2x").unwrap();
+    assert!(q.euclidean_chain(&p).len() == 1);
+    assert!(Polynomial::try_from("x")
+        .unwrap()
+        .euclidean_chain(&q)
+        .is_empty());
+
+    assert!(p.euclidean_chain(&Polynomial::zero()).is_empty());
+    assert!(p
+        .euclidean_chain(&Polynomial::try_from("x^-1").unwrap())
+        .is_empty());
+}
+
+#[test]
+fn wronskian_and_linear_independence() {
+    let set = |exprs: &[&str]| -> Vec<Polynomial<i64>> {
+        exprs
+            .iter()
+            .map(|p| Polynomial::try_from(*p).unwrap())
+            .collect()
+    };
+
+    assert_eq!(
+        format!("{}", Polynomial::wronskian(&set(&["x^2", "x^3"]))),
+        "x^4"
+    );
+    assert_eq!(
+        format!("{}", Polynomial::wronskian(&set(&["x^-1", "x"]))),
+        "2x^-1"
+    );
+    let in_t: Vec<Polynomial<i64>> = set(&["x^2 + 1", "x"])
+        .into_iter()
+        .map(|p| p.with_var('t'))
+        .collect();
+    assert_eq!(format!("{}", Polynomial::wronskian(&in_t)), "-t^2 + 1");
+    assert_eq!(format!("{}", Polynomial::<i64>::wronskian(&[])), "1");
+    assert!(Polynomial::<i64>::are_linearly_independent(&[]));
+
+    // A nonzero Wronskian and full rank agree for polynomials
+    for exprs in [
+        &["x^3 - x", "x^2 + 2", "x^3 + x^2 - x + 2"][..],
+        &["x^3 - x", "x^2 + 2", "x^3 + x^2 - x + 3"],
+        &["5", "x - 7", "3x + 1"],
+        &["x^4", "x^4 - x", "x", "x^2 + x^4"],
+        &["2x", "4x"],
+        &["x^2 - 1"],
+    ] {
+        let polys = set(exprs);
+        assert_eq!(
+            Polynomial::are_linearly_independent(&polys),
+            !Polynomial::wronskian(&polys).is_zero(),
+            "{exprs:?}"
+        );
+    }
+    assert!(!Polynomial::are_linearly_independent(&set(&[
+        "x^3 - x",
+        "x^2 + 2",
+        "x^3 + x^2 - x + 2"
+    ])));
+    assert!(Polynomial::are_linearly_independent(&set(&[
+        "x^4",
+        "x^4 - x",
+        "x^2 + x^4"
+    ])));
+
+    // Seven small integer polynomials, the elimination outgrows `i32`
+    let mut seed = 2024i64;
+    let mut next = || {
+        seed = (seed * 1103515245 + 12345) % 2147483648;
+        (seed % 11 - 5) as i32
+    };
+    let mut polys: Vec<Polynomial<i32>> = (0..7)
+        .map(|_| Polynomial::from((0..7).map(|_| next()).collect::<Vec<_>>()))
+        .collect();
+    assert!(Polynomial::are_linearly_independent(&polys));
+    polys[6] = polys[0].clone() + polys[1].clone() + -polys[5].clone();
+    assert!(!Polynomial::are_linearly_independent(&polys));
+}
+
+#[test]
+fn hermite_interpolation() {
+    // Three nodes determine a quintic, reproduced from its values and derivatives
+    let quintic: Polynomial<f64> = Polynomial::try_from("x^5 - 3x^4 + 2x^2 - x + 4").unwrap();
+    let slope = quintic.derivative();
+    let points: Vec<(f64, f64, f64)> = [-1.0, 0.5, 2.0]
+        .iter()
+        .map(|x| (*x, quintic.eval(*x), slope.eval(*x)))
+        .collect();
+    let poly = Polynomial::hermite_interpolate(&points).unwrap();
+    let error = (poly + -quintic)
+        .into_iter()
+        .fold(0f64, |acc, m| acc.max(m.get_value().abs()));
+    assert!(error < 1e-12);
+
+    // Position and velocity constraints of an unordered trajectory
+    let points: [(f64, f64, f64); 4] = [
+        (3.0, 1.0, -2.0),
+        (0.0, 0.0, 1.0),
+        (1.0, 2.0, 0.0),
+        (2.0, -1.0, 0.5),
+    ];
+    let poly = Polynomial::hermite_interpolate(&points).unwrap();
+    assert_eq!(poly.max_exp().get_exp(), 7);
+    for (x, value, derivative) in points {
+        assert!((poly.eval(x) - value).abs() < 1e-9);
+        assert!((poly.derivative().eval(x) - derivative).abs() < 1e-9);
+    }
+
+    let line = Polynomial::hermite_interpolate(&[(2, 5, 3)]).unwrap();
+    assert_eq!(format!("{line}"), "3x - 1");
+    assert!(Polynomial::<f64>::hermite_interpolate(&[]).is_err());
+    assert!(Polynomial::hermite_interpolate(&[(1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]).is_err());
+}
+
+#[test]
+fn root_locus_follows_branches() {
+    // Characteristic polynomial of 1 / (s (s + 1) (s + 3)) under gain k
+    let den: Polynomial<f64> = Polynomial::try_from("x^3 + 4x^2 + 3x").unwrap();
+    let family = |k: f64| den.clone() + Polynomial::constant(k);
+    let locus = Polynomial::root_locus(family, (0.0, 20.0), 400).unwrap();
+    assert_eq!(locus.len(), 401);
+    assert_eq!(locus[400].0, 20.0);
+
+    for (k, roots) in &locus {
+        assert_eq!(roots.len(), 3);
+        let coeffs = family(*k).to_coeffs().unwrap();
+        for root in roots {
+            let value = coeffs
+                .iter()
+                .rev()
+                .fold(Complex::new(0.0, 0.0), |acc, c| acc * root + c);
+            assert!(value.norm() < 1e-8, "{k} {root}");
+        }
+    }
+
+    // Small steps move every branch a little, and the branches leave the real axis once
+    for pair in locus.windows(2) {
+        for (a, b) in pair[0].1.iter().zip(&pair[1].1) {
+            assert!((a - b).norm() < 0.2, "{} {a} {b}", pair[1].0);
+        }
+    }
+    assert!(locus[0].1.iter().all(|r| r.im == 0.0));
+    assert!(locus[400].1.iter().filter(|r| r.im.abs() > 1e-6).count() == 2);
+
+    // The degree grows past k = 0
+    let locus = Polynomial::root_locus(
+        |k| Polynomial::from_coeffs(&[1.0, 0.0, 1.0, k]),
+        (0.0, 1.0),
+        4,
+    )
+    .unwrap();
+    assert_eq!(locus[0].1.len(), 2);
+    assert_eq!(locus[1].1.len(), 3);
+
+    assert!(
+        Polynomial::root_locus(|_| Polynomial::try_from("x^-1").unwrap(), (0.0, 1.0), 2).is_err()
+    );
+}
+
+#[test]
+fn probably_equal_evaluation() {
+    // (x + 1)^40 expanded by repeated products and by binomial coefficients
+    let factor: Polynomial<i64> = Polynomial::try_from("x + 1").unwrap();
+    let product = (1..40).fold(factor.clone(), |acc, _| acc * factor.clone());
+    let mut binomials = vec![1i64];
+    for k in 0..40 {
+        binomials.push(binomials[k] * (40 - k as i64) / (k as i64 + 1));
+    }
+    let expanded = Polynomial::from_coeffs(&binomials);
+    assert!(product.probably_equal(&expanded));
+    assert_eq!(product, expanded);
+
+    let mut changed = binomials.clone();
+    changed[20] += 1;
+    assert!(!product.probably_equal(&Polynomial::from_coeffs(&changed)));
+    for seed in [0, 1, 42, u64::MAX] {
+        assert!(product.probably_equal_with_seed(&expanded, seed));
+        assert!(!product.probably_equal_with_seed(&Polynomial::from_coeffs(&changed), seed));
+    }
+
+    // Fractions, negative exponents and huge exponents
+    let p: Polynomial<f64> = Polynomial::try_from("0.1x^-3 - 2.75x + 1e-300").unwrap();
+    let q: Polynomial<f64> = Polynomial::try_from("x^-3").unwrap();
+    assert!((p.clone() * q.clone())
+        .probably_equal(&Polynomial::try_from("0.1x^-6 - 2.75x^-2 + 1e-300x^-3").unwrap()));
+    assert!(!p.probably_equal(&Polynomial::try_from("0.1x^-3 - 2.75x").unwrap()));
+    let big: Polynomial<i32> = Polynomial::try_from("x^2000000000 + 1").unwrap();
+    assert!(big.probably_equal(&big.clone()));
+    assert!(!big.probably_equal(&Polynomial::try_from("x^2000000001 + 1").unwrap()));
+
+    // Non-finite coefficients fall back to comparing the terms
+    let infinite = Polynomial::from_coeffs(&[f64::INFINITY, 1.0]);
+    assert!(infinite.probably_equal(&infinite.clone()));
+    assert!(!infinite.probably_equal(&Polynomial::from_coeffs(&[1.0, 1.0])));
+    assert!(Polynomial::<i32>::zero().probably_equal(&Polynomial::zero()));
+}
+
+#[test]
+fn syntax_error_positions() {
+    let error = |expr: &str| Polynomial::<i32>::parse(expr).unwrap_err();
+
+    let missing = error("3x^ + 2");
+    assert_eq!((missing.column, missing.offset), (3, 2));
+    assert_eq!(missing.token, "^");
+    assert_eq!(missing.to_string(), "column 3: Missing exponent `^`");
+
+    let base = error("x^2 + 3.5x");
+    assert_eq!((base.column, base.token.as_str()), (7, "3.5"));
+    assert_eq!(base.message, "Not valid base");
+    assert_eq!(base.kind, SyntaxErrorKind::InvalidCoefficient);
+
+    let exponent = error("x^99999999999 - 1");
+    assert_eq!(
+        (exponent.column, exponent.token.as_str()),
+        (3, "99999999999")
+    );
+    assert_eq!(exponent.message, "Not valid exponent");
+
+    let operator = error("2x 3");
+    assert_eq!((operator.column, operator.token.as_str()), (4, "3"));
+    assert_eq!(operator.message, "Expected + or -");
+
+    let symbol = error("x + ^2");
+    assert_eq!((symbol.column, symbol.token.as_str()), (5, "^"));
+
+    // Columns count chars, offsets count bytes
+    let unicode = error("x² + 1");
+    assert_eq!((unicode.column, unicode.offset), (2, 1));
+    assert_eq!(unicode.token, "²");
+
+    // Same failures through `TryFrom`
+    assert_eq!(
+        Polynomial::<i32>::try_from("3x^ + 2"),
+        Err("Missing exponent")
+    );
+    assert_eq!(
+        Polynomial::<i32>::parse_from_reader("3x^ + 2".as_bytes()),
+        Err("Missing exponent")
+    );
+}
+
+#[test]
+fn sign_aware_tokenizer() {
+    let valid = [
+        ("x^-2 + 3", "3 + x^-2"),
+        ("x^+2 + 3", "x^2 + 3"),
+        ("x ^-2", "x^-2"),
+        ("x - -3", "x + 3"),
+        ("x + -3", "x - 3"),
+        ("x - +3", "x - 3"),
+        ("x + +3", "x + 3"),
+        ("x--3", "x + 3"),
+        ("-x", "-x"),
+        ("+x", "x"),
+        ("- -x", "x"),
+        ("-x^-1 - -x^-1", "0"),
+        ("2x^-3 - x^-3", "x^-3"),
+        ("  3 x ^ 2  -  1 ", "3x^2 - 1"),
+        ("x -\n 1", "x - 1"),
+        ("x4 - 6x2 + 8", "x^4 - 6x^2 + 8"),
+        ("x -", "x"),
+    ];
+    for (input, expected) in valid {
+        let poly = Polynomial::<i32>::try_from(input);
+        let reader = Polynomial::<i32>::parse_from_reader(input.as_bytes());
+
+        assert_eq!(
+            poly.map(|p| p.to_string()),
+            Ok(expected.to_string()),
+            "{input}"
+        );
+        assert_eq!(
+            reader.map(|p| p.to_string()),
+            Ok(expected.to_string()),
+            "{input}"
+        );
+    }
+
+    let floats = [
+        ("1e-3x + 2.5e+1", "0.001x + 25"),
+        ("-1.5E2x^-2", "-150x^-2"),
+    ];
+    for (input, expected) in floats {
+        let poly = Polynomial::<f64>::try_from(input).unwrap();
+        let reader = Polynomial::<f64>::parse_from_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(poly.to_string(), expected);
+        assert_eq!(reader, poly);
+    }
+
+    let invalid = [
+        ("x + + + 3", 7),
+        ("x - - -3", 7),
+        ("x^ -2", 2),
+        ("x^--2", 2),
+        ("x^", 2),
+        ("x^2^3", 4),
+        ("x + * 3", 5),
+        ("3 4x", 3),
+    ];
+    for (input, column) in invalid {
+        let error = Polynomial::<i32>::parse(input).unwrap_err();
+
+        assert_eq!(error.column, column, "{input}");
+        assert!(
+            Polynomial::<i32>::parse_from_reader(input.as_bytes()).is_err(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn empty_expression_policy() {
+    for input in ["", "   ", "+", " - ", "\n\t"] {
+        let error = Polynomial::<i32>::parse(input).unwrap_err();
+        assert_eq!(error.kind, SyntaxErrorKind::Empty, "{input:?}");
+        assert_eq!(error.message, "Empty expression", "{input:?}");
+        assert_eq!(error.token, "");
+        assert_eq!(error.column, input.chars().count() + 1);
+
+        assert_eq!(Polynomial::<i32>::try_from(input), Err("Empty expression"));
+        assert_eq!(
+            Polynomial::<i32>::parse_from_reader(input.as_bytes()),
+            Err("Empty expression")
+        );
+        assert_eq!(
+            Polynomial::<i32>::parse_or_zero(input),
+            Ok(Polynomial::zero())
+        );
+    }
+
+    // Explicit zero and a dangling operator after a term are fine
+    assert_eq!(Polynomial::<i32>::parse("0"), Ok(Polynomial::zero()));
+    assert_eq!(Polynomial::<i32>::parse("x +").unwrap().to_string(), "x");
+
+    // Blank lines of documents are skipped
+    assert_eq!(Polynomial::<i32>::parse_all("x\n\n  \n+\n1").len(), 3);
+}
+
+#[test]
+fn eval_over_other_rings() {
+    use num::Complex;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Mat2([[i64; 2]; 2]);
+
+    impl From<i64> for Mat2 {
+        fn from(value: i64) -> Self {
+            Mat2([[value, 0], [0, value]])
+        }
+    }
+
+    impl std::ops::Add for Mat2 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            let [[a, b], [c, d]] = self.0;
+            let [[e, f], [g, h]] = rhs.0;
+            Mat2([[a + e, b + f], [c + g, d + h]])
+        }
+    }
+
+    impl std::ops::Mul for Mat2 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            let [[a, b], [c, d]] = self.0;
+            let [[e, f], [g, h]] = rhs.0;
+            Mat2([
+                [a * e + b * g, a * f + b * h],
+                [c * e + d * g, c * f + d * h],
+            ])
+        }
+    }
+
+    // Cayley–Hamilton, a matrix is a root of its characteristic polynomial
+    let matrix = Mat2([[2, 1], [-3, 5]]);
+    let characteristic = Polynomial::<i64>::try_from("x^2 - 7x + 13").unwrap();
+    assert_eq!(characteristic.eval_as(matrix), Ok(Mat2::from(0)));
+
+    let poly = Polynomial::<f64>::try_from("x^3 - 1").unwrap();
+    let cube_root = Complex::from_polar(1.0, 2.0 * std::f64::consts::PI / 3.0);
+    assert!(poly.eval_as(cube_root).unwrap().norm() < 1e-12);
+
+    // Same as `eval` for the coefficient type itself
+    let poly = Polynomial::<i32>::try_from("2x^4 - x + 3").unwrap();
+    assert_eq!(poly.eval_as(3), Ok(poly.eval(3)));
+    assert_eq!(Polynomial::<i32>::zero().eval_as(5.0f64), Ok(0.0));
+    assert!(Polynomial::<i32>::try_from("x^-1")
+        .unwrap()
+        .eval_as(2)
+        .is_err());
+}
+
+#[test]
+fn budan_fourier_bounds_sturm_count() {
+    // (x + 1)(x - 2)^2 (x^2 + 1)
+    let poly: Polynomial<i32> = Polynomial::try_from("x^5 - 3x^4 + x^3 + x^2 + 4").unwrap();
+
+    let roots = [-1.0, 2.0, 2.0];
+    for (a, b) in [(-3.0, 3.0), (-3.0, 0.0), (0.0, 1.0), (1.5, 2.5), (2.0, 5.0)] {
+        let bound = poly.budan_fourier_count(a, b).unwrap();
+        let count = roots.iter().filter(|r| a < **r && **r <= b).count();
+
+        assert!(bound >= count, "({a}, {b}]");
+        assert_eq!((bound - count) % 2, 0, "({a}, {b}]");
+    }
+
+    assert_eq!(poly.budan_fourier_count(1.5, 2.5), Ok(2));
+    assert!(Polynomial::<i32>::zero()
+        .budan_fourier_count(0.0, 1.0)
+        .is_err());
+    assert!(Polynomial::<i32>::try_from("x^-1")
+        .unwrap()
+        .budan_fourier_count(0.0, 1.0)
+        .is_err());
+}
+
+#[test]
+fn binomial_transforms_are_inverse() {
+    // Bell numbers are the binomial transform of themselves shifted by one
+    let bell: Polynomial<i64> = Polynomial::from_coeffs(&[1, 1, 2, 5, 15, 52, 203]);
+    let shifted: Polynomial<i64> = Polynomial::from_coeffs(&[1, 2, 5, 15, 52, 203, 877]);
+    assert_eq!(bell.binomial_transform(), Ok(shifted.clone()));
+    assert_eq!(shifted.inverse_binomial_transform(), Ok(bell.clone()));
+
+    let poly: Polynomial<i64> = Polynomial::try_from("3x^5 - x^3 + 7x - 2").unwrap();
+    let round = poly
+        .binomial_transform()
+        .unwrap()
+        .inverse_binomial_transform();
+    assert_eq!(round, Ok(poly));
+    assert_eq!(
+        Polynomial::<i64>::zero().binomial_transform(),
+        Ok(Polynomial::zero())
+    );
+    assert!(Polynomial::<i64>::try_from("x^-2")
+        .unwrap()
+        .inverse_binomial_transform()
+        .is_err());
+}
+
+#[test]
+fn shift_operator_power_matches_shift() {
+    let poly: Polynomial<i64> = Polynomial::try_from("2x^6 - x^4 + 5x^3 - 9x + 4").unwrap();
+    for k in -4..=4 {
+        let shifted = poly.shift_operator_power(k).unwrap();
+        assert_eq!(shifted, poly.shift(k).unwrap(), "k = {k}");
+        for x in -3..=3 {
+            assert_eq!(shifted.eval(x), poly.eval(x + k));
+        }
+    }
+
+    let small: Polynomial<i8> = Polynomial::try_from("x + 1").unwrap();
+    assert!(small.shift_operator_power(1000).is_err());
+    let constant: Polynomial<i8> = Polynomial::try_from("5").unwrap();
+    assert_eq!(constant.shift_operator_power(3), Ok(constant.clone()));
+}
+
+#[test]
+fn coeffs_stride_reassembles() {
+    let poly: Polynomial<i32> =
+        Polynomial::try_from("7x^9 - x^7 + 3x^6 + 2x^4 - 5x^3 + x - 8 + 4x^-1 + x^-4").unwrap();
+
+    for k in 1..=4 {
+        let mut sum = Polynomial::zero();
+        for offset in 0..k {
+            let part = poly.coeffs_stride(k, offset).unwrap();
+            let spread = Polynomial::new(
+                part.into_iter()
+                    .map(|m| Monomial::new(m.get_value(), m.get_exp() * k + offset))
+                    .collect(),
+            );
+            sum = sum + spread;
+        }
+        assert_eq!(sum, poly, "k = {k}");
+    }
+
+    // A stride past the first period is the same part with lower exponents
+    let even = poly.coeffs_stride(2, 0).unwrap();
+    let shifted = poly.coeffs_stride(2, 4).unwrap();
+    assert_eq!(shifted, even.mul_mono(Monomial::new(1, -2)));
+
+    assert!(poly.coeffs_stride(0, 0).is_err());
+    assert!(poly.coeffs_stride(-2, 1).is_err());
+
+    // `2147483647 + 1` and `-2147483648 - 1` don't fit in i32
+    let wide: Polynomial<i32> = Polynomial::try_from("x^2147483647 + x^-2147483648").unwrap();
+    assert!(wide.coeffs_stride(1, -1).is_err());
+    assert!(wide.coeffs_stride(1, 1).is_err());
+    assert_eq!(
+        wide.coeffs_stride(2, 1),
+        Ok(Polynomial::try_from("x^1073741823").unwrap())
+    );
+}
+
+#[test]
+fn restrict_degrees_ranges() {
+    let poly: Polynomial<i32> = Polynomial::try_from("x^4 + 2x^3 + 3x^2 + 4x + 5 + 6x^-1").unwrap();
+
+    assert_eq!(poly.restrict_degrees(..), poly);
+    assert_eq!(format!("{}", poly.restrict_degrees(..0)), "6x^-1");
+    assert_eq!(format!("{}", poly.restrict_degrees(0..=2)), "3x^2 + 4x + 5");
+    assert!(poly.restrict_degrees(10..).is_zero());
+
+    let restricted = poly.clone().with_var('t').restrict_degrees(4..);
+    assert_eq!(format!("{restricted}"), "t^4");
+}
+
+#[test]
+fn rational_roots_are_exact() {
+    let ratio = |p: i64, q: i64| Rational64::new(p, q);
+
+    // -(2x - 1) (3x + 2)^2 (x - 5) x
+    let poly = [
+        Polynomial::<i64>::try_from("2x - 1").unwrap(),
+        Polynomial::try_from("3x + 2").unwrap(),
+        Polynomial::try_from("3x + 2").unwrap(),
+        Polynomial::try_from("x - 5").unwrap(),
+        Polynomial::try_from("-x").unwrap(),
+    ]
+    .into_iter()
+    .fold(Polynomial::try_from("1").unwrap(), |acc, f| acc * f);
+    assert_eq!(
+        poly.rational_roots(),
+        Ok(vec![
+            ratio(-2, 3),
+            ratio(-2, 3),
+            ratio(0, 1),
+            ratio(1, 2),
+            ratio(5, 1)
+        ])
+    );
+
+    // Irrational and complex roots are left out
+    let poly: Polynomial<i32> = Polynomial::try_from("2x^4 - x^3 - 4x^2 + 2x").unwrap();
+    assert_eq!(poly.rational_roots(), Ok(vec![ratio(0, 1), ratio(1, 2)]));
+    let poly: Polynomial<i32> = Polynomial::try_from("x^2 + 1").unwrap();
+    assert_eq!(poly.rational_roots(), Ok(vec![]));
+
+    // Float coefficients with integer values work, other ones don't
+    let poly: Polynomial<f64> = Polynomial::try_from("4x^2 - 1").unwrap();
+    assert_eq!(poly.rational_roots(), Ok(vec![ratio(-1, 2), ratio(1, 2)]));
+    let poly: Polynomial<f64> = Polynomial::try_from("x - 0.5").unwrap();
+    assert_eq!(poly.rational_roots(), Err(RootsError::CoefficientOverflow));
+    let poly: Polynomial<i32> = Polynomial::try_from("x + x^-1").unwrap();
+    assert_eq!(poly.rational_roots(), Err(RootsError::NegativeExponent));
+
+    assert_eq!(Polynomial::<i32>::zero().rational_roots(), Ok(vec![]));
+    assert_eq!(
+        Polynomial::<i32>::try_from("7").unwrap().rational_roots(),
+        Ok(vec![])
+    );
+
+    // The products overflow `i64` while checking the candidates
+    let poly: Polynomial<i64> =
+        Polynomial::try_from("1000000007x^4 - 3000000021x^3 + 1000000007").unwrap();
+    assert!(poly.rational_roots().unwrap().is_empty());
+}
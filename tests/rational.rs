@@ -0,0 +1,45 @@
+use rust_polynomial::{Polynomial, RationalFunction};
+
+fn poly(expr: &str) -> Polynomial<i64> {
+    Polynomial::try_from(expr).unwrap()
+}
+
+#[test]
+fn compose_rational_clears_denominators() {
+    let p = poly("x^3 - 2x + 5");
+    let (num, den) = (poly("2x + 1"), poly("x - 3"));
+    let composed = p.compose_rational(&num, &den).unwrap();
+
+    assert_eq!(
+        composed.denominator(),
+        &(den.clone() * den.clone() * den.clone())
+    );
+    for x in [-4, -1, 0, 2, 7] {
+        let (n, d) = (num.eval(x), den.eval(x));
+        let expected = n * n * n - 2 * n * d * d + 5 * d * d * d;
+        assert_eq!(composed.numerator().eval(x), expected, "x = {x}");
+    }
+
+    // Constant substitution
+    let composed = p.compose_rational(&poly("3"), &poly("1")).unwrap();
+    assert_eq!(composed.eval(0), Some(p.eval(3)));
+
+    assert!(p.compose_rational(&num, &Polynomial::zero()).is_err());
+}
+
+#[test]
+fn compose_rational_with_negative_exponents() {
+    // x + x^-1 at (x + 1) / x is ((x + 1)^2 + x^2) / (x (x + 1))
+    let composed = poly("x + x^-1")
+        .compose_rational(&poly("x + 1"), &poly("x"))
+        .unwrap();
+
+    let expected = RationalFunction::new(poly("2x^2 + 2x + 1"), poly("x^2 + x")).unwrap();
+    assert_eq!(composed, expected);
+    assert_eq!(composed.eval(-1), None);
+    assert_eq!(composed.eval(1), Some(2));
+
+    // The powers would go from `x^-1` to `x^2147483647`
+    let p: Polynomial<i64> = Polynomial::try_from("x^2147483647 + x^-1").unwrap();
+    assert!(p.compose_rational(&poly("x + 1"), &poly("x")).is_err());
+}
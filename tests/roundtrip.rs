@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use rust_polynomial::{Monomial, Polynomial};
+
+fn roundtrip<T>(terms: BTreeMap<i32, T>)
+where
+    T: rust_polynomial::MonomialValue,
+{
+    let poly = Polynomial::new(
+        terms
+            .into_iter()
+            .map(|(e, v)| Monomial::new(v, e))
+            .collect(),
+    );
+    let canonical = poly.to_canonical_string();
+
+    assert_eq!(
+        Polynomial::try_from(canonical.as_str()),
+        Ok(poly.clone()),
+        "{canonical}"
+    );
+    assert_eq!(Polynomial::try_from(format!("{poly}").as_str()), Ok(poly));
+}
+
+proptest! {
+    #[test]
+    fn roundtrip_i32(terms in prop::collection::btree_map(-30..100i32, -i32::MAX..=i32::MAX, 0..12)) {
+        roundtrip(terms);
+    }
+
+    #[test]
+    fn roundtrip_i64(terms in prop::collection::btree_map(0..100i32, -i64::MAX..=i64::MAX, 0..12)) {
+        roundtrip(terms);
+    }
+
+    #[test]
+    fn roundtrip_f64(terms in prop::collection::btree_map(-30..100i32, prop::num::f64::NORMAL, 0..12)) {
+        roundtrip(terms);
+    }
+
+    #[test]
+    fn roundtrip_f32(terms in prop::collection::btree_map(-30..100i32, prop::num::f32::NORMAL | prop::num::f32::SUBNORMAL, 0..12)) {
+        roundtrip(terms);
+    }
+}
+
+#[test]
+fn roundtrip_var() {
+    let poly: Polynomial<f32> = Polynomial::builder()
+        .term(0.25, 3)
+        .term(-1.0, 1)
+        .var('z')
+        .build();
+
+    assert_eq!(format!("{poly}"), "0.25z^3 - z");
+    assert_eq!(poly.to_canonical_string(), "0.25x^3 - x");
+    assert_eq!(
+        Polynomial::try_from(poly.to_canonical_string().as_str()),
+        Ok(poly)
+    );
+}
@@ -0,0 +1,57 @@
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    thread,
+};
+
+use rust_polynomial::{Polynomial, SharedPoly};
+
+#[test]
+fn cached_results_match_polynomial() {
+    let poly = Polynomial::<i32>::try_from("x^3 - 5x^2 - x + 5").unwrap();
+    let shared = poly.clone().into_shared();
+
+    assert_eq!(shared.degree(), 3);
+    assert_eq!(shared.roots(), poly.roots().as_ref());
+    assert_eq!(shared.content_hash(), poly.content_hash());
+    assert_eq!(shared.derivative(), poly.derivative());
+    assert_eq!(format!("{shared}"), format!("{poly}"));
+    assert_eq!(shared.into_inner(), poly);
+
+    assert_eq!(SharedPoly::from(Polynomial::<i32>::zero()).degree(), 0);
+}
+
+#[test]
+fn clones_share_the_caches_across_threads() {
+    let shared: SharedPoly<f64> = Polynomial::try_from("x^2 - 2").unwrap().into_shared();
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let shared = shared.clone();
+            thread::spawn(move || (shared.roots().cloned(), shared.eval(i as f64)))
+        })
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        let (roots, value) = handle.join().unwrap();
+        assert_eq!(roots.as_ref(), shared.roots());
+        assert_eq!(value, (i * i) as f64 - 2.0);
+    }
+
+    let copy = shared.clone();
+    assert!(copy.ptr_eq(&shared));
+    assert!(!copy.ptr_eq(&SharedPoly::new(shared.polynomial().clone())));
+    assert_eq!(copy, SharedPoly::new(shared.polynomial().clone()));
+
+    // Still shared, so the polynomial is cloned out
+    assert_eq!(copy.into_inner(), *shared.polynomial());
+
+    let hash = |p: &str| {
+        let mut hasher = DefaultHasher::new();
+        Polynomial::<i32>::try_from(p)
+            .unwrap()
+            .into_shared()
+            .hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash("x + 1"), hash("1 + x"));
+    assert_ne!(hash("x + 1"), hash("x - 1"));
+}
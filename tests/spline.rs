@@ -0,0 +1,55 @@
+use rust_polynomial::{Spline, SplineBoundary};
+
+#[test]
+fn natural() {
+    let points: [(f64, f64); 5] = [(0.0, 1.0), (1.0, 3.0), (2.5, 2.0), (4.0, -1.0), (5.0, 0.5)];
+    let spline = Spline::fit(&points).unwrap();
+
+    assert_eq!(spline.pieces().len(), 4);
+    for (x, y) in points {
+        assert!((spline.eval(x).unwrap() - y).abs() < 1e-12);
+    }
+
+    let second = spline.derivative().derivative();
+    assert!(second.eval(0.0).unwrap().abs() < 1e-12);
+    assert!(second.eval(5.0).unwrap().abs() < 1e-12);
+
+    // Continuous up to the second derivative, compared with tolerance
+    let mut current = spline.clone();
+    for _ in 0..3 {
+        for (i, &b) in spline.breakpoints()[1..4].iter().enumerate() {
+            let [left, right] = [&current.pieces()[i], &current.pieces()[i + 1]];
+            assert!((left.eval(b) - right.eval(b)).abs() < 1e-9);
+        }
+        current = current.derivative();
+    }
+}
+
+#[test]
+fn two_points_line() {
+    let spline = Spline::fit(&[(1.0, 2.0), (3.0, 6.0)]).unwrap();
+    assert_eq!(format!("{}", spline.pieces()[0]), "2x");
+}
+
+#[test]
+fn clamped() {
+    // A cubic is reproduced exactly by the clamped spline with its end slopes
+    let points: Vec<(f64, f64)> = (-2..=3)
+        .map(|x| x as f64)
+        .map(|x| (x, x.powi(3) - 2.0 * x))
+        .collect();
+    let spline = Spline::fit_with(&points, SplineBoundary::Clamped(10.0, 25.0)).unwrap();
+
+    for x in [-1.5, -0.3, 0.7, 2.2] {
+        let expected: f64 = x * x * x - 2.0 * x;
+        assert!((spline.eval(x).unwrap() - expected).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn errors() {
+    assert!(Spline::fit(&[(0.0, 1.0)]).is_err());
+    assert!(Spline::fit(&[(0.0, 1.0), (0.0, 2.0)]).is_err());
+    assert!(Spline::fit(&[(1.0, 1.0), (0.0, 2.0)]).is_err());
+    assert!(Spline::fit::<f32>(&[]).is_err());
+}
@@ -0,0 +1,92 @@
+use rust_polynomial::{DivError, Operation, Polynomial, Tracked};
+
+#[test]
+fn history_records_operations() {
+    let divisor = Polynomial::<i32>::try_from("x - 1").unwrap();
+    let mut tracked = Tracked::new(Polynomial::<i32>::try_from("x^3 - 1").unwrap());
+    tracked
+        .div(&divisor)
+        .unwrap()
+        .add(&Polynomial::try_from("2").unwrap())
+        .neg();
+
+    assert_eq!(format!("{}", tracked.polynomial()), "-x^2 - x - 3");
+    assert_eq!(
+        tracked.history(),
+        [
+            Operation::Created(Polynomial::try_from("x^3 - 1").unwrap()),
+            Operation::DividedBy(divisor.clone()),
+            Operation::Added(Polynomial::try_from("2").unwrap()),
+            Operation::Negated,
+        ]
+    );
+
+    tracked.rem(&divisor).unwrap();
+    assert_eq!(format!("{}", tracked.polynomial()), "-5");
+    assert_eq!(tracked.history()[4].to_string(), "remainder by x - 1");
+}
+
+#[test]
+fn failed_integration_is_not_recorded() {
+    let mut tracked = Tracked::<Polynomial<f64>>::parse("x^-1 + 2").unwrap();
+
+    assert!(tracked.integrate().is_err());
+    assert_eq!(tracked.history().len(), 1);
+
+    tracked.differentiate();
+    tracked.integrate().unwrap();
+    assert_eq!(format!("{}", tracked.polynomial()), "x^-1");
+}
+
+#[test]
+fn failed_division_is_not_recorded() {
+    let mut tracked = Tracked::<Polynomial<i32>>::parse("x^2 + 1").unwrap();
+
+    assert_eq!(
+        tracked.div(&Polynomial::zero()).err(),
+        Some(DivError::DivisionByZero)
+    );
+    assert_eq!(
+        tracked.rem(&Polynomial::zero()).err(),
+        Some(DivError::DivisionByZero)
+    );
+    let overflow = Polynomial::try_from("x^2147483647").unwrap();
+    assert_eq!(
+        tracked
+            .mul(&Polynomial::try_from("x^-2147483647").unwrap())
+            .div(&overflow)
+            .err(),
+        Some(DivError::ExponentOverflow)
+    );
+    assert_eq!(tracked.history().len(), 2);
+    assert_eq!(
+        format!("{}", tracked.polynomial()),
+        "x^-2147483645 + x^-2147483647"
+    );
+
+    let mut history = tracked.history().to_vec();
+    history.push(Operation::DividedBy(Polynomial::zero()));
+    assert!(Tracked::replay(&history).is_err());
+    history.pop();
+    history.push(Operation::RemainderBy(overflow));
+    assert!(Tracked::replay(&history).is_err());
+}
+
+#[test]
+fn replay_reproduces_result() {
+    let mut tracked = Tracked::<Polynomial<i64>>::parse("3x^2 + 1").unwrap();
+    tracked
+        .integrate()
+        .unwrap()
+        .mul(&Polynomial::try_from("x + 2").unwrap())
+        .differentiate();
+
+    let replayed = Tracked::replay(tracked.history()).unwrap();
+    assert_eq!(&replayed, tracked.polynomial());
+
+    let history = tracked.history();
+    assert!(Tracked::replay(&history[1..]).is_err());
+    assert!(Tracked::<Polynomial<i64>>::replay(&[]).is_err());
+    assert!(Tracked::replay(&[history[0].clone(), history[0].clone()]).is_err());
+    assert!(Tracked::<Polynomial<i64>>::replay(&[Operation::Parsed("x +* 1".into())]).is_err());
+}
@@ -0,0 +1,83 @@
+use rust_polynomial::{Polynomial, RationalFunction, TransferFunction};
+
+fn tf(num: &str, den: &str) -> TransferFunction<i64> {
+    TransferFunction::new(
+        Polynomial::try_from(num).unwrap(),
+        Polynomial::try_from(den).unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn construction() {
+    let num: Polynomial<i64> = Polynomial::try_from("1").unwrap();
+    assert!(TransferFunction::new(num.clone(), Polynomial::zero()).is_err());
+    assert!(TransferFunction::new(num, Polynomial::try_from("x^-1").unwrap()).is_err());
+
+    let g = tf("2x + 4", "x^2 + 5x + 6");
+    assert_eq!(g.numerator(), &Polynomial::try_from("2x + 4").unwrap());
+    assert_eq!(
+        g.denominator(),
+        &Polynomial::try_from("x^2 + 5x + 6").unwrap()
+    );
+
+    let rational = RationalFunction::new(g.numerator().clone(), g.denominator().clone());
+    assert_eq!(g.as_rational(), &rational.unwrap());
+    assert_eq!(g.as_rational().eval(1), g.eval(1));
+    assert_eq!(RationalFunction::from(g.clone()), g.as_rational().clone());
+}
+
+#[test]
+fn poles_zeros_gain() {
+    let g = tf("2x + 4", "x^2 + 5x + 6");
+
+    assert_eq!(g.zeros(), Some(vec![-2]));
+    assert_eq!(g.poles(), Some(vec![-3, -2]));
+    assert_eq!(g.eval(-3), None);
+    assert_eq!(g.eval(1), Some(0));
+
+    let g = TransferFunction::<f64>::new(
+        Polynomial::try_from("2x + 4").unwrap(),
+        Polynomial::try_from("x^2 + 5x + 6").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(g.dc_gain(), Some(4.0 / 6.0));
+    assert_eq!(g.eval(1.0), Some(0.5));
+
+    // Integrator
+    assert_eq!(tf("1", "x^2 + x").dc_gain(), None);
+}
+
+#[test]
+fn stability() {
+    assert!(tf("1", "x + 1").is_stable());
+    assert!(tf("1", "x^3 + 6x^2 + 11x + 6").is_stable());
+    assert!(tf("1", "x^2 + 2x + 5").is_stable());
+    assert!(tf("1", "5").is_stable());
+
+    // Roots at 1, on the imaginary axis and at the origin
+    assert!(!tf("1", "x^2 + x - 2").is_stable());
+    assert!(!tf("1", "x^2 + 1").is_stable());
+    assert!(!tf("1", "x^2 + x").is_stable());
+    // All coefficients positive, but a pair of roots `0.5 ± 1.9i` of `(x + 2)(x^2 - x + 4)`
+    assert!(!tf("1", "x^3 + x^2 + 2x + 8").is_stable());
+}
+
+#[test]
+fn composition() {
+    let g = tf("1", "x + 1");
+    let h = tf("2", "x + 3");
+
+    assert_eq!(g.series(&h), tf("2", "x^2 + 4x + 3"));
+    assert_eq!(g.parallel(&h), tf("3x + 5", "x^2 + 4x + 3"));
+    assert_eq!(g.feedback(&h), Ok(tf("x + 3", "x^2 + 4x + 5")));
+
+    // A large proportional gain destabilizes `1 / (s + 1)^3`
+    let plant = tf("1", "x^3 + 3x^2 + 3x + 1");
+    assert!(plant.feedback(&tf("7", "1")).unwrap().is_stable());
+    assert!(!plant.feedback(&tf("9", "1")).unwrap().is_stable());
+
+    // `G H = -1` cancels the denominator of the closed loop
+    assert!(tf("-1", "1").feedback(&tf("1", "1")).is_err());
+    assert!(tf("1", "x + 1").feedback(&tf("-x - 1", "1")).is_err());
+}
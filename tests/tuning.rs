@@ -0,0 +1,51 @@
+use std::sync::{Mutex, MutexGuard};
+
+use rust_polynomial::{tuning, Polynomial};
+
+/// The thresholds are global, tests changing them hold the lock and restore the defaults on
+/// drop, even if they panic
+struct Tuning {
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for Tuning {
+    fn drop(&mut self) {
+        tuning::reset();
+    }
+}
+
+fn tuning_lock() -> Tuning {
+    static LOCK: Mutex<()> = Mutex::new(());
+    let lock = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    tuning::reset();
+    Tuning { _lock: lock }
+}
+
+#[test]
+fn strategies_agree_at_any_threshold() {
+    let _tuning = tuning_lock();
+    let p1 = Polynomial::<i64>::try_from("3x^5 - x^4 + 7x^3 + 2x^2 - 9x + 4").unwrap();
+    let p2 = Polynomial::<i64>::try_from("x^3 + 5x^2 - 2x + 1").unwrap();
+
+    let product = p1.clone() * p2.clone();
+    let division = p1.clone() / p2.clone();
+
+    for (toom3, kronecker) in [(0, usize::MAX), (3, usize::MAX), (usize::MAX, 0)] {
+        tuning::set_toom3_threshold(toom3);
+        tuning::set_kronecker_threshold(kronecker);
+        assert_eq!(p1.clone() * p2.clone(), product);
+    }
+
+    tuning::set_newton_division_threshold(0);
+    assert_eq!(p1.clone() / p2.clone(), division);
+
+    tuning::reset();
+    assert_eq!(
+        tuning::kronecker_threshold(),
+        tuning::DEFAULT_KRONECKER_THRESHOLD
+    );
+    assert_eq!(
+        tuning::newton_division_threshold(),
+        tuning::DEFAULT_NEWTON_DIVISION_THRESHOLD
+    );
+}
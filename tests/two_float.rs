@@ -0,0 +1,63 @@
+#![cfg(feature = "twofloat")]
+
+use num::{NumCast, Signed, ToPrimitive, Zero};
+use rust_polynomial::{DoubleDouble, Polynomial, TwoFloat};
+
+fn tf(s: &str) -> TwoFloat {
+    s.parse().unwrap()
+}
+
+fn f(value: f64) -> TwoFloat {
+    value.into()
+}
+
+#[test]
+fn arithmetic_keeps_twice_the_precision() {
+    let third = f(1.0) / f(3.0);
+    assert_eq!(format!("{third}"), "0.33333333333333333333333333333333");
+
+    // 1 + 2^-80 is lost in f64
+    let tiny = f(2f64.powi(-80));
+    let sum = f(1.0) + tiny;
+    assert_eq!(sum - f(1.0), tiny);
+
+    // Clustered roots of (x - 1)^7 read from its expansion
+    let poly: Polynomial<TwoFloat> =
+        Polynomial::try_from("x^7 - 7x^6 + 21x^5 - 35x^4 + 35x^3 - 21x^2 + 7x - 1").unwrap();
+    let value = poly.eval(tf("1.01")).0.hi();
+    assert!((value - 1e-14).abs() < 1e-24);
+
+    let cubic: Polynomial<TwoFloat> = Polynomial::try_from("x^3 - 2").unwrap();
+    let root = cubic.newton_refine(2f64.cbrt().into(), 5);
+    assert!((root * root * root - tf("2")).abs().0.hi() < 1e-30);
+}
+
+#[test]
+fn parsing_display_and_signs() {
+    assert_eq!(
+        format!(
+            "{}",
+            Polynomial::<TwoFloat>::try_from("2.5x^2 - 0.1x + 3").unwrap()
+        ),
+        "2.5x^2 - 0.1x + 3"
+    );
+    assert_eq!(tf("1.5e-3"), tf("0.0015"));
+    assert!("0.1.2".parse::<TwoFloat>().is_err());
+
+    let zero = TwoFloat::zero();
+    assert!(!zero.is_positive() && !zero.is_negative());
+    assert_eq!(zero.signum(), zero);
+    assert_eq!(tf("-2.5").signum(), tf("-1"));
+    assert_eq!(tf("-2.5").abs(), tf("2.5"));
+
+    assert_eq!(tf("-7.9").to_i64(), Some(-7));
+    assert_eq!(
+        <TwoFloat as NumCast>::from(i64::MAX).unwrap().to_i128(),
+        Some(i64::MAX as i128)
+    );
+
+    let dd: DoubleDouble = "0.1".parse().unwrap();
+    let wrapped = <TwoFloat as From<DoubleDouble>>::from(dd);
+    assert_eq!(<DoubleDouble as From<TwoFloat>>::from(wrapped), dd);
+    assert_eq!(wrapped.0.hi(), 0.1);
+}